@@ -126,6 +126,8 @@ export default function Home() {
   const [tokenSymbol, setTokenSymbol] = useState<string>("TOKEN");
 
   // State untuk form Create Campaign
+  const [newTitle, setNewTitle] = useState<string>("");
+  const [newDescription, setNewDescription] = useState<string>("");
   const [newGoal, setNewGoal] = useState<string>("");
   const [newDeadline, setNewDeadline] = useState<string>(getDefaultDeadline());
 
@@ -215,6 +217,8 @@ export default function Home() {
             `Campaign created successfully! New Campaign ID: ${newCampaignId}`
           );
           // Reset form
+          setNewTitle("");
+          setNewDescription("");
           setNewGoal("");
           setNewDeadline(getDefaultDeadline());
           // Mungkin refresh data campaign list jika ada
@@ -282,19 +286,20 @@ export default function Home() {
         readOnlyCrowdfundContract.is_goal_reached({ campaign_id: campaignId }),
       ]);
 
-      setGoal(goalRes.result);
-      setDeadline(deadlineRes.result);
-      setTotalRaised(totalRaisedRes.result);
-      setProgress(Number(progressRes.result));
-      setIsEnded(Boolean(isEndedRes.result));
-      setIsGoalReached(Boolean(isGoalReachedRes.result));
+      // Getter kontrak mengembalikan Result, unwrap() melempar jika Err
+      setGoal(goalRes.result.unwrap());
+      setDeadline(deadlineRes.result.unwrap());
+      setTotalRaised(totalRaisedRes.result.unwrap());
+      setProgress(Number(progressRes.result.unwrap()));
+      setIsEnded(Boolean(isEndedRes.result.unwrap()));
+      setIsGoalReached(Boolean(isGoalReachedRes.result.unwrap()));
 
       if (isConnected && address) {
         const myDonationRes = await readOnlyCrowdfundContract.get_donation({
           campaign_id: campaignId,
           donor: address,
         });
-        setMyDonation(myDonationRes.result);
+        setMyDonation(myDonationRes.result.unwrap());
       } else {
         setMyDonation(BigInt(0));
       }
@@ -362,6 +367,7 @@ export default function Home() {
         campaign_id: campaignId,
         donor: address,
         amount: stroopsAmount,
+        tier: undefined, // Tanpa reward tier
       });
       console.log("Donate transaction prepared:", tx);
       await submitDonation(tx);
@@ -411,6 +417,7 @@ export default function Home() {
       !crowdfundContract ||
       !isConnected ||
       isSubmittingCreate ||
+      !newTitle.trim() ||
       !newGoal.trim() ||
       !newDeadline.trim() ||
       !address
@@ -449,6 +456,7 @@ export default function Home() {
 
       console.log("Preparing create_campaign transaction with:", {
         owner: address,
+        title: newTitle.trim(),
         goal: goalStroops,
         deadline: deadlineTimestamp,
         token: TOKEN_CONTRACT_ID,
@@ -460,6 +468,28 @@ export default function Home() {
         goal: goalStroops,
         deadline: deadlineTimestamp,
         token: TOKEN_CONTRACT_ID, // Gunakan ID Kontrak Token Anda
+        // Opsi default: all-or-nothing tanpa batas donasi
+        config: {
+          funding_mode: Crowdfund.FundingMode.AllOrNothing,
+          hard_cap: undefined,
+          lock_on_goal: false,
+          lock_window: BigInt(0),
+          max_extension: BigInt(0),
+          max_per_donor: undefined,
+          min_donation: undefined,
+          objection_bps: undefined,
+          over_limit: Crowdfund.OverLimitPolicy.Reject,
+          pledge_withdrawal: false,
+          start_time: undefined,
+        },
+        metadata: {
+          title: newTitle.trim(),
+          description: newDescription.trim(),
+          metadata_uri: "",
+          category: Crowdfund.Category.Other,
+        },
+        milestones: undefined,
+        tiers: [],
       });
 
       console.log("Create Campaign transaction prepared:", tx);
@@ -679,6 +709,24 @@ export default function Home() {
           <PlusCircle className="size-5" /> Start a New Campaign
         </p>
 
+        <Input
+          type="text"
+          placeholder="Campaign title"
+          maxLength={64}
+          onChange={(e) => setNewTitle(e.target.value)}
+          value={newTitle}
+          disabled={!isConnected || isSubmittingCreate}
+        />
+
+        <Input
+          type="text"
+          placeholder="Short description (optional)"
+          maxLength={280}
+          onChange={(e) => setNewDescription(e.target.value)}
+          value={newDescription}
+          disabled={!isConnected || isSubmittingCreate}
+        />
+
         <Input
           type="number"
           inputMode="decimal"
@@ -709,6 +757,7 @@ export default function Home() {
           disabled={
             !isConnected ||
             isSubmittingCreate ||
+            !newTitle.trim() ||
             !newGoal.trim() ||
             !newDeadline.trim()
           }
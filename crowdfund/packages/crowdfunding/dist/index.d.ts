@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
-import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions } from '@stellar/stellar-sdk/contract';
-import type { u64, i128 } from '@stellar/stellar-sdk/contract';
+import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions, Result } from '@stellar/stellar-sdk/contract';
+import type { u32, u64, i128, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -10,7 +10,245 @@ export declare const networks: {
         readonly contractId: "CA72AEQGZX4J4T6ODLQ34CCN7B74TOVYSP6KLLZBAOC23YPMTK6N2NRD";
     };
 };
+/**
+ * State escrow campaign ber-milestone
+ */
+export interface Escrow {
+    approval: Approval;
+    milestones: Array<Milestone>;
+    next: u32;
+}
+export type DataKey = {
+    tag: "Campaign";
+    values: readonly [u64];
+} | {
+    tag: "Donation";
+    values: readonly [u64, string];
+} | {
+    tag: "Metadata";
+    values: readonly [u64];
+} | {
+    tag: "Escrow";
+    values: readonly [u64];
+} | {
+    tag: "Proposal";
+    values: readonly [u64];
+} | {
+    tag: "Tiers";
+    values: readonly [u64];
+} | {
+    tag: "TierClaimed";
+    values: readonly [u64, u32];
+} | {
+    tag: "DonorTier";
+    values: readonly [u64, string];
+} | {
+    tag: "Objection";
+    values: readonly [u64, string];
+} | {
+    tag: "Vote";
+    values: readonly [u64, u32, string];
+} | {
+    tag: "OwnerCount";
+    values: readonly [string];
+} | {
+    tag: "OwnerCampaign";
+    values: readonly [string, u32];
+} | {
+    tag: "TokenCount";
+    values: readonly [string];
+} | {
+    tag: "TokenCampaign";
+    values: readonly [string, u32];
+};
+/**
+ * Siapa yang memutuskan setiap milestone
+ */
+export type Approval = {
+    tag: "Approver";
+    values: readonly [string];
+} | {
+    tag: "DonorVote";
+    values: readonly [VotingConfig];
+};
 export interface Campaign {
+    deadline: u64;
+    donors: u32;
+    extended: u64;
+    fee_bps: u32;
+    fee_paid: i128;
+    funding_mode: FundingMode;
+    goal: i128;
+    hard_cap: Option<i128>;
+    lock_on_goal: boolean;
+    lock_window: u64;
+    max_extension: u64;
+    max_per_donor: Option<i128>;
+    min_donation: Option<i128>;
+    objection_bps: Option<u32>;
+    objections: u32;
+    over_limit: OverLimitPolicy;
+    owner: string;
+    pledge_withdrawal: boolean;
+    raised: i128;
+    released: i128;
+    start_time: Option<u64>;
+    status: CampaignStatus;
+    token: string;
+}
+/**
+ * Kategori campaign, dipakai frontend untuk filter
+ */
+export declare enum Category {
+    Technology = 0,
+    Art = 1,
+    Community = 2,
+    Charity = 3,
+    Education = 4,
+    Health = 5,
+    Environment = 6,
+    Other = 7
+}
+/**
+ * Proposal pencairan milestone yang sedang di-voting
+ */
+export interface Proposal {
+    milestone: u32;
+    votes_against: i128;
+    votes_for: i128;
+    voting_end: u64;
+}
+/**
+ * Reward tier beserta sisa slot (hasil `get_tiers`)
+ */
+export interface TierInfo {
+    description: string;
+    min_amount: i128;
+    quantity: u32;
+    remaining: u32;
+}
+/**
+ * Konfigurasi fee platform (diset lewat constructor saat deploy)
+ */
+export interface FeeConfig {
+    fee_bps: u32;
+    max_fee_bps: u32;
+    treasury: string;
+}
+/**
+ * Satu tahap pencairan dana
+ */
+export interface Milestone {
+    description: string;
+    due_date: u64;
+    percentage: u32;
+}
+/**
+ * Satu halaman hasil `list_active_campaigns`
+ */
+export interface ActivePage {
+    campaigns: Array<CampaignSummary>;
+    next: Option<u64>;
+}
+/**
+ * Perubahan fee yang menunggu timelock
+ */
+export interface PendingFee {
+    effective_at: u64;
+    fee_bps: u32;
+}
+/**
+ * Reward untuk donatur dengan donasi minimal tertentu
+ */
+export interface RewardTier {
+    description: string;
+    min_amount: i128;
+    quantity: u32;
+}
+/**
+ * Jumlah token dalam satuan "manusia": `whole` + `fraction` / 10^decimals
+ */
+export interface UnitAmount {
+    fraction: i128;
+    whole: i128;
+}
+/**
+ * Apa yang terjadi dengan dana jika goal tidak tercapai saat deadline
+ */
+export declare enum FundingMode {
+    AllOrNothing = 0,
+    KeepItAll = 1
+}
+/**
+ * Parameter voting donatur. Bobot suara = jumlah donasi.
+ */
+export interface VotingConfig {
+    quorum_bps: u32;
+    threshold_bps: u32;
+    voting_period: u64;
+}
+/**
+ * Goal & raised campaign dalam satuan token (hasil `get_campaign_units`)
+ */
+export interface CampaignUnits {
+    decimals: u32;
+    goal: UnitAmount;
+    raised: UnitAmount;
+}
+/**
+ * Rencana milestone saat create_campaign
+ */
+export interface MilestonePlan {
+    approval: Approval;
+    milestones: Array<Milestone>;
+}
+/**
+ * Opsi campaign yang dipilih saat create_campaign
+ */
+export interface CampaignConfig {
+    funding_mode: FundingMode;
+    hard_cap: Option<i128>;
+    lock_on_goal: boolean;
+    lock_window: u64;
+    max_extension: u64;
+    max_per_donor: Option<i128>;
+    min_donation: Option<i128>;
+    objection_bps: Option<u32>;
+    over_limit: OverLimitPolicy;
+    pledge_withdrawal: boolean;
+    start_time: Option<u64>;
+}
+/**
+ * Status campaign. Transisi yang diizinkan:
+ * 
+ * | Dari      | Ke        | Pemicu                                  |
+ * |-----------|-----------|-----------------------------------------|
+ * | Pending   | Active    | `now >= start_time`                     |
+ * | Pending   | Cancelled | `cancel_campaign`                       |
+ * | Active    | Succeeded | `now >= deadline` dan dana cukup (*)    |
+ * | Active    | Failed    | `now >= deadline` dan dana tidak cukup (*) |
+ * | Active    | Succeeded | hard cap tercapai (sebelum deadline)    |
+ * | Active    | Cancelled | `cancel_campaign`                       |
+ * | Succeeded | Claimed   | `withdraw` / milestone terakhir dirilis |
+ * | Succeeded | Failed    | milestone ditolak atau lewat due date   |
+ * 
+ * (*) AllOrNothing: goal tercapai. KeepItAll: ada dana terkumpul (> 0).
+ * 
+ * Failed, Cancelled & Claimed adalah status akhir dan disimpan; status
+ * lain dihitung dari waktu ledger & dana.
+ */
+export declare enum CampaignStatus {
+    Pending = 0,
+    Active = 1,
+    Succeeded = 2,
+    Failed = 3,
+    Cancelled = 4,
+    Claimed = 5
+}
+/**
+ * (Legacy) Bentuk campaign di schema versi 1, termasuk map donasinya
+ */
+export interface LegacyCampaign {
     deadline: u64;
     donations: Map<string, i128>;
     goal: i128;
@@ -18,260 +256,1444 @@ export interface Campaign {
     raised: i128;
     token: string;
 }
+/**
+ * Ringkasan campaign untuk halaman daftar (hasil `list_*`)
+ */
+export interface CampaignSummary {
+    category: Category;
+    deadline: u64;
+    goal: i128;
+    id: u64;
+    owner: string;
+    raised: i128;
+    status: CampaignStatus;
+    title: string;
+    token: string;
+}
+/**
+ * Perlakuan donasi yang melewati hard cap / batas per donatur
+ */
+export declare enum OverLimitPolicy {
+    Reject = 0,
+    PartialAccept = 1
+}
+/**
+ * Metadata tampilan campaign. Disimpan terpisah dari `Campaign` supaya
+ * `donate`/`refund` tidak perlu membaca teks yang panjang.
+ */
+export interface CampaignMetadata {
+    category: Category;
+    description: string;
+    metadata_uri: string;
+    title: string;
+}
+/**
+ * Kode error kontrak. Nomor dipakai oleh binding TS dan kontrak pemanggil,
+ * jadi nomor yang sudah ada tidak boleh diubah.
+ */
+export declare const CrowdfundingError: {
+    /**
+   * Goal campaign harus lebih dari 0
+   */
+    1: {
+        message: string;
+    };
+    /**
+   * Deadline harus di masa depan
+   */
+    2: {
+        message: string;
+    };
+    /**
+   * Jumlah donasi harus lebih dari 0
+   */
+    3: {
+        message: string;
+    };
+    /**
+   * Campaign dengan ID tersebut tidak ada
+   */
+    4: {
+        message: string;
+    };
+    /**
+   * Campaign sudah berakhir, tidak menerima donasi
+   */
+    5: {
+        message: string;
+    };
+    /**
+   * Campaign belum berakhir
+   */
+    6: {
+        message: string;
+    };
+    /**
+   * Goal sudah tercapai, tidak bisa refund
+   */
+    7: {
+        message: string;
+    };
+    /**
+   * Goal belum tercapai, owner tidak bisa menarik dana
+   */
+    8: {
+        message: string;
+    };
+    /**
+   * Dana campaign sudah ditarik owner
+   */
+    9: {
+        message: string;
+    };
+    /**
+   * Donatur tidak ditemukan atau sudah refund
+   */
+    10: {
+        message: string;
+    };
+    /**
+   * Judul kosong atau terlalu panjang
+   */
+    11: {
+        message: string;
+    };
+    /**
+   * Deskripsi terlalu panjang
+   */
+    12: {
+        message: string;
+    };
+    /**
+   * URI metadata terlalu panjang atau bukan ipfs:// / https://
+   */
+    13: {
+        message: string;
+    };
+    /**
+   * Metadata tidak bisa diubah lagi (sudah ada donasi / campaign berakhir)
+   */
+    14: {
+        message: string;
+    };
+    /**
+   * Campaign sudah dibatalkan owner
+   */
+    15: {
+        message: string;
+    };
+    /**
+   * Daftar milestone tidak valid (kosong, persentase tidak 100, due date tidak urut)
+   */
+    16: {
+        message: string;
+    };
+    /**
+   * Dana campaign ber-milestone hanya bisa dirilis lewat approve_milestone
+   */
+    17: {
+        message: string;
+    };
+    /**
+   * Campaign tidak punya milestone
+   */
+    18: {
+        message: string;
+    };
+    /**
+   * Tidak ada milestone yang bisa diproses (ditolak, terlewat, atau sudah dirilis semua)
+   */
+    19: {
+        message: string;
+    };
+    /**
+   * Milestone campaign ini diputuskan dengan cara lain (approver vs voting donatur)
+   */
+    20: {
+        message: string;
+    };
+    /**
+   * Quorum / threshold / periode voting tidak valid
+   */
+    21: {
+        message: string;
+    };
+    /**
+   * Masih ada proposal yang belum difinalisasi
+   */
+    22: {
+        message: string;
+    };
+    /**
+   * Tidak ada proposal yang sedang berjalan
+   */
+    23: {
+        message: string;
+    };
+    /**
+   * Periode voting sudah berakhir
+   */
+    24: {
+        message: string;
+    };
+    /**
+   * Periode voting belum berakhir
+   */
+    25: {
+        message: string;
+    };
+    /**
+   * Donatur sudah memberikan suara untuk proposal ini
+   */
+    26: {
+        message: string;
+    };
+    /**
+   * Hanya donatur campaign yang bisa voting
+   */
+    27: {
+        message: string;
+    };
+    /**
+   * Daftar reward tier tidak valid
+   */
+    28: {
+        message: string;
+    };
+    /**
+   * Reward tier dengan index tersebut tidak ada
+   */
+    29: {
+        message: string;
+    };
+    /**
+   * Total donasi donatur di bawah minimum tier
+   */
+    30: {
+        message: string;
+    };
+    /**
+   * Kuota reward tier sudah habis
+   */
+    31: {
+        message: string;
+    };
+    /**
+   * Campaign keep-it-all: dana yang terkumpul tetap untuk owner, tidak bisa refund
+   */
+    32: {
+        message: string;
+    };
+    /**
+   * Hard cap / batas donasi tidak valid
+   */
+    33: {
+        message: string;
+    };
+    /**
+   * Donasi di bawah minimum donasi campaign
+   */
+    34: {
+        message: string;
+    };
+    /**
+   * Total donasi donatur melebihi batas per donatur
+   */
+    35: {
+        message: string;
+    };
+    /**
+   * Donasi melebihi hard cap campaign
+   */
+    36: {
+        message: string;
+    };
+    /**
+   * Waktu mulai harus sebelum deadline
+   */
+    37: {
+        message: string;
+    };
+    /**
+   * Campaign belum dibuka (sebelum start_time)
+   */
+    38: {
+        message: string;
+    };
+    /**
+   * Perpanjangan deadline tidak valid (konfigurasi, deadline baru, atau melewati batas)
+   */
+    39: {
+        message: string;
+    };
+    /**
+   * Perpanjangan ditolak karena keberatan donatur mencapai batas
+   */
+    40: {
+        message: string;
+    };
+    /**
+   * Donatur sudah menyatakan keberatan
+   */
+    41: {
+        message: string;
+    };
+    /**
+   * Campaign tidak mengizinkan penarikan donasi sebelum deadline
+   */
+    42: {
+        message: string;
+    };
+    /**
+   * Penarikan donasi dikunci (goal tercapai / mendekati deadline)
+   */
+    43: {
+        message: string;
+    };
+    /**
+   * Jumlah penarikan melebihi donasi donatur
+   */
+    44: {
+        message: string;
+    };
+    /**
+   * (Tidak dipakai lagi sejak constructor) Platform sudah diinisialisasi
+   */
+    45: {
+        message: string;
+    };
+    /**
+   * Fee melebihi batas maksimum
+   */
+    46: {
+        message: string;
+    };
+    /**
+   * Konfigurasi platform belum diinisialisasi
+   */
+    47: {
+        message: string;
+    };
+    /**
+   * Kontrak sedang di-pause oleh admin
+   */
+    48: {
+        message: string;
+    };
+    /**
+   * Tidak ada transfer admin yang menunggu
+   */
+    49: {
+        message: string;
+    };
+    /**
+   * Transfer token gagal (mis. saldo donatur kurang)
+   */
+    50: {
+        message: string;
+    };
+};
 export interface Client {
     /**
-     * Construct and simulate a create_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Membuat campaign baru.
-     * Siapapun bisa memanggil ini.
-     * Mengembalikan ID (u64) dari campaign yang baru dibuat.
-     */
-    create_campaign: ({ owner, goal, deadline, token }: {
-        owner: string;
-        goal: i128;
-        deadline: u64;
-        token: string;
+   * Construct and simulate a vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Donatur memberikan suara untuk proposal yang sedang berjalan.
+   * Bobot suara = total donasi donatur ke campaign ini.
+   */
+    vote: ({ campaign_id, donor, approve }: {
+        campaign_id: u64;
+        donor: string;
+        approve: boolean;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a donate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Donasi ke campaign tertentu menggunakan token transfer
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin menghentikan sementara `create_campaign` & `donate`.
+   * Refund, withdraw_pledge & payout tetap berjalan.
+   */
+    pause: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
      */
-    donate: ({ campaign_id, donor, amount }: {
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a donate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Donasi ke campaign tertentu menggunakan token transfer.
+   * `tier` opsional: index reward tier yang dipilih donatur.
+   * Mengembalikan jumlah yang diterima (bisa lebih kecil dari `amount`
+   * jika campaign memakai OverLimitPolicy::PartialAccept).
+   */
+    donate: ({ campaign_id, donor, amount, tier }: {
         campaign_id: u64;
         donor: string;
         amount: i128;
+        tier: Option<u32>;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
     /**
-     * Construct and simulate a refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Refund mechanism jika campaign gagal (hanya bisa dipanggil oleh donatur)
-     */
+   * Construct and simulate a refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Refund mechanism jika campaign gagal atau dibatalkan owner
+   * (hanya bisa dipanggil oleh donatur)
+   */
     refund: ({ campaign_id, donor }: {
         campaign_id: u64;
         donor: string;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
     /**
-     * Construct and simulate a get_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get data lengkap dari satu campaign (untuk frontend)
-     */
-    get_campaign: ({ id }: {
-        id: u64;
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Memindahkan maksimal `limit` campaign dari map CAMPAIGNS (schema 1)
+   * ke key per campaign. Boleh dipanggil siapa saja karena data tidak
+   * berubah; panggil berulang sampai hasilnya 0. Mengembalikan jumlah
+   * campaign lama yang belum dipindah.
+   */
+    migrate: ({ limit }: {
+        limit: u32;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Campaign>>;
+    }) => Promise<AssembledTransaction<u32>>;
     /**
-     * Construct and simulate a get_next_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get ID (u64) untuk campaign berikutnya
+   * Construct and simulate a set_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin menjadwalkan fee baru (maks `max_fee_bps`). Berlaku untuk
+   * campaign yang dibuat setelah timelock FEE_TIMELOCK lewat.
+   * Mengembalikan timestamp fee baru mulai berlaku.
+   */
+    set_fee: ({ fee_bps }: {
+        fee_bps: u32;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
      */
-    get_next_id: (options?: {
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u64>>>;
+    /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin membuka kembali kontrak
+   */
+    unpause: (options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<Result<void>>>;
     /**
-     * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get total amount yang sudah terkumpul untuk campaign tertentu
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin mengganti kode kontrak dengan WASM yang sudah di-upload.
+   * Admin ditetapkan oleh constructor saat deploy, jadi tidak bisa direbut.
+   * Storage tetap; jalankan `migrate` jika versi baru mengubah layout.
+   */
+    upgrade: ({ new_wasm_hash }: {
+        new_wasm_hash: Buffer;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
      */
-    get_total_raised: ({ campaign_id }: {
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a get_goal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get campaign goal amount
+   */
+    get_goal: ({ campaign_id }: {
         campaign_id: u64;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
     /**
-     * Construct and simulate a get_donation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get berapa banyak specific donor sudah donate ke campaign tertentu
-     */
-    get_donation: ({ campaign_id, donor }: {
+   * Construct and simulate a get_vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah donatur sudah voting untuk milestone tertentu.
+   * `None` jika belum voting, `Some(true/false)` sesuai pilihannya.
+   */
+    get_vote: ({ campaign_id, milestone, donor }: {
         campaign_id: u64;
+        milestone: u32;
         donor: string;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<Option<boolean>>>;
     /**
-     * Construct and simulate a get_goal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get campaign goal amount
-     */
-    get_goal: ({ campaign_id }: {
+   * Construct and simulate a is_ended transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah campaign sudah berakhir (deadline passed)
+   */
+    is_ended: ({ campaign_id }: {
         campaign_id: u64;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<Result<boolean>>>;
     /**
-     * Construct and simulate a get_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Get campaign deadline timestamp
-     */
-    get_deadline: ({ campaign_id }: {
+   * Construct and simulate a withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner menarik dana campaign yang berhasil (deadline lewat & goal tercapai,
+   * atau ada dana terkumpul untuk mode KeepItAll).
+   * Hanya bisa dilakukan sekali. Mengembalikan jumlah yang diterima owner
+   * (setelah dipotong fee platform).
+   */
+    withdraw: ({ campaign_id }: {
         campaign_id: u64;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
     /**
-     * Construct and simulate a is_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Check apakah campaign sudah reach goal
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get admin platform
+   */
+    get_admin: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
      */
-    is_goal_reached: ({ campaign_id }: {
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<string>>>;
+    /**
+   * Construct and simulate a get_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get daftar reward tier campaign beserta sisa slot
+   */
+    get_tiers: ({ campaign_id }: {
         campaign_id: u64;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Array<TierInfo>>>>;
+    /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cek apakah kontrak sedang di-pause
+   */
+    is_paused: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
     }) => Promise<AssembledTransaction<boolean>>;
     /**
-     * Construct and simulate a is_ended transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Check apakah campaign sudah berakhir (deadline passed)
-     */
-    is_ended: ({ campaign_id }: {
-        campaign_id: u64;
+   * Construct and simulate a get_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get escrow milestone campaign (milestone, cara approval & progres)
+   */
+    get_escrow: ({ id }: {
+        id: u64;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<boolean>>;
+    }) => Promise<AssembledTransaction<Result<Escrow>>>;
     /**
-     * Construct and simulate a get_progress_percentage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     * Calculate progress percentage dari campaign
-     */
-    get_progress_percentage: ({ campaign_id }: {
-        campaign_id: u64;
+   * Construct and simulate a get_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get status campaign saat ini (lihat tabel transisi di CampaignStatus)
+   */
+    get_status: ({ id }: {
+        id: u64;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<CampaignStatus>>>;
+    /**
+   * Construct and simulate a is_claimed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah dana campaign sudah ditarik oleh owner
+   */
+    is_claimed: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<boolean>>>;
+    /**
+   * Construct and simulate a get_next_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get ID (u64) untuk campaign berikutnya
+   */
+    get_next_id: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Langkah 2 transfer admin: calon admin menerima perannya
+   */
+    accept_admin: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a get_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get data lengkap dari satu campaign (untuk frontend)
+   */
+    get_campaign: ({ id }: {
+        id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Campaign>>>;
+    /**
+   * Construct and simulate a get_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get campaign deadline timestamp
+   */
+    get_deadline: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u64>>>;
+    /**
+   * Construct and simulate a get_donation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get berapa banyak specific donor sudah donate ke campaign tertentu
+   */
+    get_donation: ({ campaign_id, donor }: {
+        campaign_id: u64;
+        donor: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+   * Construct and simulate a get_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get metadata tampilan campaign (judul, deskripsi, URI, kategori)
+   */
+    get_metadata: ({ id }: {
+        id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<CampaignMetadata>>>;
+    /**
+   * Construct and simulate a get_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get proposal milestone yang sedang di-voting
+   */
+    get_proposal: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Proposal>>>;
+    /**
+   * Construct and simulate a is_cancelled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah campaign sudah dibatalkan owner
+   */
+    is_cancelled: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<boolean>>>;
+    /**
+   * Construct and simulate a get_donor_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get index reward tier yang dipilih donatur (None jika tidak memilih tier)
+   */
+    get_donor_tier: ({ campaign_id, donor }: {
+        campaign_id: u64;
+        donor: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Option<u32>>>>;
+    /**
+   * Construct and simulate a get_fee_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get konfigurasi fee yang berlaku saat ini
+   */
+    get_fee_config: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<FeeConfig>>>;
+    /**
+   * Construct and simulate a list_campaigns transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Daftar campaign berdasarkan urutan ID, mulai dari ID `start`.
+   * ID yang belum dipindah dari map CAMPAIGNS lama (lihat `migrate`) dilewati.
+   */
+    list_campaigns: ({ start, limit }: {
+        start: u64;
+        limit: u32;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Array<CampaignSummary>>>>;
+    /**
+   * Construct and simulate a transfer_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Langkah 1 transfer admin: admin menunjuk calon admin baru.
+   * Admin lama tetap berlaku sampai calon memanggil `accept_admin`.
+   */
+    transfer_admin: ({ new_admin }: {
+        new_admin: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a cancel_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner membatalkan campaign yang masih berjalan.
+   * Donasi baru langsung ditolak dan semua donatur bisa `refund`
+   * tanpa menunggu deadline, berapapun dana yang sudah terkumpul.
+   */
+    cancel_campaign: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a create_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Membuat campaign baru.
+   * Siapapun bisa memanggil ini.
+   * Mengembalikan ID (u64) dari campaign yang baru dibuat.
+   */
+    create_campaign: ({ owner, goal, deadline, token, config, metadata, milestones, tiers }: {
+        owner: string;
+        goal: i128;
+        deadline: u64;
+        token: string;
+        config: CampaignConfig;
+        metadata: CampaignMetadata;
+        milestones: Option<MilestonePlan>;
+        tiers: Array<RewardTier>;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u64>>>;
+    /**
+   * Construct and simulate a extend_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner memperpanjang deadline campaign yang masih Active.
+   * Total perpanjangan dibatasi `max_extension`, dan jika `objection_bps`
+   * di-set, diblokir saat persentase donatur yang keberatan mencapai batas
+   * tersebut (dihitung per donatur, bukan per jumlah donasi).
+   */
+    extend_deadline: ({ campaign_id, new_deadline }: {
+        campaign_id: u64;
+        new_deadline: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a get_pending_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get perubahan fee yang dijadwalkan (None jika tidak ada atau sudah berlaku)
+   */
+    get_pending_fee: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<PendingFee>>>;
+    /**
+   * Construct and simulate a is_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah campaign sudah reach goal
+   */
+    is_goal_reached: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<boolean>>>;
+    /**
+   * Construct and simulate a update_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner mengubah metadata campaign.
+   * Hanya bisa selama belum ada donasi dan campaign belum berakhir,
+   * supaya donatur tidak "tertipu" oleh perubahan setelah berdonasi.
+   */
+    update_metadata: ({ campaign_id, metadata }: {
+        campaign_id: u64;
+        metadata: CampaignMetadata;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a withdraw_pledge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Donatur menarik sebagian / seluruh donasinya selama campaign Active
+   * (jika diizinkan campaign). Mengembalikan sisa donasi donatur.
+   */
+    withdraw_pledge: ({ campaign_id, donor, amount }: {
+        campaign_id: u64;
+        donor: string;
+        amount: i128;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+   * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get total amount yang sudah terkumpul untuk campaign tertentu
+   */
+    get_total_raised: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+   * Construct and simulate a object_extension transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Donatur menyatakan keberatan atas perpanjangan deadline.
+   * Setiap donatur dihitung satu kali, berapapun donasinya.
+   */
+    object_extension: ({ campaign_id, donor }: {
+        campaign_id: u64;
+        donor: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a reject_milestone transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approver menolak milestone berikutnya. Campaign menjadi Failed dan
+   * donatur bisa `refund` bagian pro rata dari dana yang belum dirilis.
+   */
+    reject_milestone: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a approve_milestone transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approver menyetujui milestone berikutnya dan merilis bagiannya ke owner.
+   * Hanya untuk campaign ber-milestone yang sudah Succeeded, sebelum due date
+   * milestone tersebut. Mengembalikan jumlah yang diterima owner.
+   */
+    approve_milestone: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+   * Construct and simulate a finalize_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Menutup proposal setelah periode voting berakhir (siapapun bisa memanggil).
+   * Jika quorum & threshold terpenuhi milestone dirilis ke owner, jika tidak
+   * campaign menjadi Failed dan donatur bisa refund sisa dana.
+   * Mengembalikan `true` jika proposal disetujui.
+   */
+    finalize_proposal: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<boolean>>>;
+    /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get calon admin yang belum menerima (None jika tidak ada)
+   */
+    get_pending_admin: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+   * Construct and simulate a propose_milestone transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner mengajukan pencairan milestone berikutnya untuk di-voting donatur.
+   * Harus diajukan sebelum due date milestone. Mengembalikan timestamp akhir voting.
+   */
+    propose_milestone: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<u64>>>;
+    /**
+   * Construct and simulate a get_campaign_units transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get goal & raised campaign dalam satuan token, memakai `decimals()`
+   * dari kontrak token campaign (mis. 25_000_000 @ 7 desimal = 2.5 token)
+   */
+    get_campaign_units: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<CampaignUnits>>>;
+    /**
+   * Construct and simulate a get_schema_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get versi layout storage. Kontrak lama tanpa versi tersimpan = versi 1
+   * sampai `migrate` selesai.
+   */
+    get_schema_version: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+   * Construct and simulate a list_active_campaigns transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Daftar campaign yang masih menerima donasi, mulai dari ID `start`.
+   * Berhenti setelah `limit` campaign aktif atau MAX_SCAN ID diperiksa,
+   * jadi halaman bisa kurang dari `limit`; lanjutkan dari `next`
+   * sampai `next` bernilai None.
+   */
+    list_active_campaigns: ({ start, limit }: {
+        start: u64;
+        limit: u32;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<ActivePage>>;
+    /**
+   * Construct and simulate a get_progress_percentage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Calculate progress percentage dari campaign
+   */
+    get_progress_percentage: ({ campaign_id }: {
+        campaign_id: u64;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
+    /**
+   * Construct and simulate a list_campaigns_by_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Daftar campaign milik `owner`. `start` adalah posisi di index owner
+   * (0 = campaign pertama yang dibuat owner tersebut).
+   */
+    list_campaigns_by_owner: ({ owner, start, limit }: {
+        owner: string;
+        start: u32;
+        limit: u32;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Array<CampaignSummary>>>>;
+    /**
+   * Construct and simulate a list_campaigns_by_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Daftar campaign yang mengumpulkan `token`. `start` adalah posisi di index token.
+   */
+    list_campaigns_by_token: ({ token, start, limit }: {
+        token: string;
+        start: u32;
+        limit: u32;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<Array<CampaignSummary>>>>;
+    /**
+   * Construct and simulate a get_owner_campaign_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get jumlah campaign milik `owner` (untuk pagination)
+   */
+    get_owner_campaign_count: ({ owner }: {
+        owner: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+   * Construct and simulate a get_token_campaign_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get jumlah campaign yang mengumpulkan `token` (untuk pagination)
+   */
+    get_token_campaign_count: ({ token }: {
+        token: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<u32>>;
 }
 export declare class Client extends ContractClient {
     readonly options: ContractClientOptions;
-    static deploy<T = Client>(
-    /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
-    options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
+    static deploy<T = Client>(/** Constructor/Initialization Args for the contract's `__constructor` method */ { admin, treasury, fee_bps, max_fee_bps }: {
+        admin: string;
+        treasury: string;
+        fee_bps: u32;
+        max_fee_bps: u32;
+    }, /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */ options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
         wasmHash: Buffer | string;
         /** Salt used to generate the contract's ID. Passed through to {@link Operation.createCustomContract}. Default: random. */
@@ -281,17 +1703,57 @@ export declare class Client extends ContractClient {
     }): Promise<AssembledTransaction<T>>;
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
-        create_campaign: (json: string) => AssembledTransaction<bigint>;
-        donate: (json: string) => AssembledTransaction<null>;
-        refund: (json: string) => AssembledTransaction<bigint>;
-        get_campaign: (json: string) => AssembledTransaction<Campaign>;
+        vote: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        pause: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        donate: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        refund: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        migrate: (json: string) => AssembledTransaction<number>;
+        set_fee: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        unpause: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        upgrade: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_goal: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_vote: (json: string) => AssembledTransaction<boolean | undefined>;
+        is_ended: (json: string) => AssembledTransaction<Result<boolean, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        withdraw: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_admin: (json: string) => AssembledTransaction<Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_tiers: (json: string) => AssembledTransaction<Result<TierInfo[], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        is_paused: (json: string) => AssembledTransaction<boolean>;
+        get_escrow: (json: string) => AssembledTransaction<Result<Escrow, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_status: (json: string) => AssembledTransaction<Result<CampaignStatus, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        is_claimed: (json: string) => AssembledTransaction<Result<boolean, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         get_next_id: (json: string) => AssembledTransaction<bigint>;
-        get_total_raised: (json: string) => AssembledTransaction<bigint>;
-        get_donation: (json: string) => AssembledTransaction<bigint>;
-        get_goal: (json: string) => AssembledTransaction<bigint>;
-        get_deadline: (json: string) => AssembledTransaction<bigint>;
-        is_goal_reached: (json: string) => AssembledTransaction<boolean>;
-        is_ended: (json: string) => AssembledTransaction<boolean>;
-        get_progress_percentage: (json: string) => AssembledTransaction<bigint>;
+        accept_admin: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_campaign: (json: string) => AssembledTransaction<Result<Campaign, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_deadline: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_donation: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_metadata: (json: string) => AssembledTransaction<Result<CampaignMetadata, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_proposal: (json: string) => AssembledTransaction<Result<Proposal, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        is_cancelled: (json: string) => AssembledTransaction<Result<boolean, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_donor_tier: (json: string) => AssembledTransaction<Result<number | undefined, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_fee_config: (json: string) => AssembledTransaction<Result<FeeConfig, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        list_campaigns: (json: string) => AssembledTransaction<Result<CampaignSummary[], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        transfer_admin: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        cancel_campaign: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        create_campaign: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        extend_deadline: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_pending_fee: (json: string) => AssembledTransaction<PendingFee | undefined>;
+        is_goal_reached: (json: string) => AssembledTransaction<Result<boolean, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        update_metadata: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        withdraw_pledge: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_total_raised: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        object_extension: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        reject_milestone: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        approve_milestone: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        finalize_proposal: (json: string) => AssembledTransaction<Result<boolean, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_pending_admin: (json: string) => AssembledTransaction<string | undefined>;
+        propose_milestone: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_campaign_units: (json: string) => AssembledTransaction<Result<CampaignUnits, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_schema_version: (json: string) => AssembledTransaction<number>;
+        list_active_campaigns: (json: string) => AssembledTransaction<ActivePage>;
+        get_progress_percentage: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        list_campaigns_by_owner: (json: string) => AssembledTransaction<Result<CampaignSummary[], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        list_campaigns_by_token: (json: string) => AssembledTransaction<Result<CampaignSummary[], import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        get_owner_campaign_count: (json: string) => AssembledTransaction<number>;
+        get_token_campaign_count: (json: string) => AssembledTransaction<number>;
     };
 }
@@ -1,5 +1,5 @@
 import { Buffer } from "buffer";
-import { Client as ContractClient, Spec as ContractSpec, } from '@stellar/stellar-sdk/contract';
+import { Client as ContractClient, Spec as ContractSpec } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -10,44 +10,354 @@ if (typeof window !== 'undefined') {
 export const networks = {
     testnet: {
         networkPassphrase: "Test SDF Network ; September 2015",
-        contractId: "CA72AEQGZX4J4T6ODLQ34CCN7B74TOVYSP6KLLZBAOC23YPMTK6N2NRD",
+        contractId: "CA72AEQGZX4J4T6ODLQ34CCN7B74TOVYSP6KLLZBAOC23YPMTK6N2NRD"
+    }
+};
+export var Category = /*#__PURE__*/ function(Category) {
+    Category[Category["Technology"] = 0] = "Technology";
+    Category[Category["Art"] = 1] = "Art";
+    Category[Category["Community"] = 2] = "Community";
+    Category[Category["Charity"] = 3] = "Charity";
+    Category[Category["Education"] = 4] = "Education";
+    Category[Category["Health"] = 5] = "Health";
+    Category[Category["Environment"] = 6] = "Environment";
+    Category[Category["Other"] = 7] = "Other";
+    return Category;
+}({});
+export var FundingMode = /*#__PURE__*/ function(FundingMode) {
+    FundingMode[FundingMode["AllOrNothing"] = 0] = "AllOrNothing";
+    FundingMode[FundingMode["KeepItAll"] = 1] = "KeepItAll";
+    return FundingMode;
+}({});
+export var CampaignStatus = /*#__PURE__*/ function(CampaignStatus) {
+    CampaignStatus[CampaignStatus["Pending"] = 0] = "Pending";
+    CampaignStatus[CampaignStatus["Active"] = 1] = "Active";
+    CampaignStatus[CampaignStatus["Succeeded"] = 2] = "Succeeded";
+    CampaignStatus[CampaignStatus["Failed"] = 3] = "Failed";
+    CampaignStatus[CampaignStatus["Cancelled"] = 4] = "Cancelled";
+    CampaignStatus[CampaignStatus["Claimed"] = 5] = "Claimed";
+    return CampaignStatus;
+}({});
+export var OverLimitPolicy = /*#__PURE__*/ function(OverLimitPolicy) {
+    OverLimitPolicy[OverLimitPolicy["Reject"] = 0] = "Reject";
+    OverLimitPolicy[OverLimitPolicy["PartialAccept"] = 1] = "PartialAccept";
+    return OverLimitPolicy;
+}({});
+export const CrowdfundingError = {
+    1: {
+        message: "GoalNotPositive"
+    },
+    2: {
+        message: "DeadlineInPast"
+    },
+    3: {
+        message: "AmountNotPositive"
+    },
+    4: {
+        message: "CampaignNotFound"
+    },
+    5: {
+        message: "CampaignEnded"
+    },
+    6: {
+        message: "CampaignNotEnded"
+    },
+    7: {
+        message: "GoalReached"
+    },
+    8: {
+        message: "GoalNotReached"
+    },
+    9: {
+        message: "AlreadyClaimed"
+    },
+    10: {
+        message: "NothingToRefund"
+    },
+    11: {
+        message: "InvalidTitle"
+    },
+    12: {
+        message: "DescriptionTooLong"
+    },
+    13: {
+        message: "InvalidMetadataUri"
+    },
+    14: {
+        message: "MetadataLocked"
+    },
+    15: {
+        message: "CampaignCancelled"
+    },
+    16: {
+        message: "InvalidMilestones"
+    },
+    17: {
+        message: "FundsInEscrow"
+    },
+    18: {
+        message: "NoMilestones"
+    },
+    19: {
+        message: "MilestoneClosed"
+    },
+    20: {
+        message: "WrongApprovalMode"
+    },
+    21: {
+        message: "InvalidVotingConfig"
+    },
+    22: {
+        message: "ProposalOpen"
+    },
+    23: {
+        message: "NoProposal"
+    },
+    24: {
+        message: "VotingClosed"
+    },
+    25: {
+        message: "VotingNotEnded"
+    },
+    26: {
+        message: "AlreadyVoted"
+    },
+    27: {
+        message: "NotDonor"
+    },
+    28: {
+        message: "InvalidTiers"
+    },
+    29: {
+        message: "TierNotFound"
+    },
+    30: {
+        message: "TierBelowMinimum"
+    },
+    31: {
+        message: "TierSoldOut"
+    },
+    32: {
+        message: "FundsKept"
+    },
+    33: {
+        message: "InvalidLimits"
+    },
+    34: {
+        message: "BelowMinDonation"
+    },
+    35: {
+        message: "DonorLimitExceeded"
+    },
+    36: {
+        message: "HardCapExceeded"
+    },
+    37: {
+        message: "InvalidStartTime"
+    },
+    38: {
+        message: "CampaignNotStarted"
+    },
+    39: {
+        message: "InvalidExtension"
+    },
+    40: {
+        message: "ExtensionObjected"
+    },
+    41: {
+        message: "AlreadyObjected"
+    },
+    42: {
+        message: "PledgeWithdrawalDisabled"
+    },
+    43: {
+        message: "PledgeLocked"
+    },
+    44: {
+        message: "AmountExceedsDonation"
+    },
+    45: {
+        message: "AlreadyInitialized"
+    },
+    46: {
+        message: "InvalidFee"
+    },
+    47: {
+        message: "NotInitialized"
+    },
+    48: {
+        message: "ContractPaused"
+    },
+    49: {
+        message: "NoPendingAdmin"
+    },
+    50: {
+        message: "TokenTransferFailed"
     }
 };
 export class Client extends ContractClient {
     options;
-    static async deploy(
-    /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
-    options) {
-        return ContractClient.deploy(null, options);
+    static async deploy({ admin, treasury, fee_bps, max_fee_bps }, options) {
+        return ContractClient.deploy({
+            admin,
+            treasury,
+            fee_bps,
+            max_fee_bps
+        }, options);
     }
-    constructor(options) {
-        super(new ContractSpec(["AAAAAQAAAAAAAAAAAAAACENhbXBhaWduAAAABgAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAAlkb25hdGlvbnMAAAAAAAPsAAAAEwAAAAsAAAAAAAAABGdvYWwAAAALAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABnJhaXNlZAAAAAAACwAAAAAAAAAFdG9rZW4AAAAAAAAT",
-            "AAAAAAAAAGpNZW1idWF0IGNhbXBhaWduIGJhcnUuClNpYXBhcHVuIGJpc2EgbWVtYW5nZ2lsIGluaS4KTWVuZ2VtYmFsaWthbiBJRCAodTY0KSBkYXJpIGNhbXBhaWduIHlhbmcgYmFydSBkaWJ1YXQuAAAAAAAPY3JlYXRlX2NhbXBhaWduAAAAAAQAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAEZ29hbAAAAAsAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAY=",
-            "AAAAAAAAADZEb25hc2kga2UgY2FtcGFpZ24gdGVydGVudHUgbWVuZ2d1bmFrYW4gdG9rZW4gdHJhbnNmZXIAAAAAAAZkb25hdGUAAAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-            "AAAAAAAAAEhSZWZ1bmQgbWVjaGFuaXNtIGppa2EgY2FtcGFpZ24gZ2FnYWwgKGhhbnlhIGJpc2EgZGlwYW5nZ2lsIG9sZWggZG9uYXR1cikAAAAGcmVmdW5kAAAAAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
-            "AAAAAAAAADRHZXQgZGF0YSBsZW5na2FwIGRhcmkgc2F0dSBjYW1wYWlnbiAodW50dWsgZnJvbnRlbmQpAAAADGdldF9jYW1wYWlnbgAAAAEAAAAAAAAAAmlkAAAAAAAGAAAAAQAAB9AAAAAIQ2FtcGFpZ24=",
+    constructor(options){
+        super(new ContractSpec([
+            "AAAABQAAAD50b3BpY3MgWyJ2b3RlZCIsIGNhbXBhaWduX2lkLCBkb25vcl0sIGRhdGEgeyBhcHByb3ZlLCB3ZWlnaHQgfQAAAAAAAAAAAAVWb3RlZAAAAAAAAAEAAAAFdm90ZWQAAAAAAAAEAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAHYXBwcm92ZQAAAAABAAAAAAAAAAAAAAAGd2VpZ2h0AAAAAAALAAAAAAAAAAI=",
+            "AAAAAQAAACNTdGF0ZSBlc2Nyb3cgY2FtcGFpZ24gYmVyLW1pbGVzdG9uZQAAAAAAAAAABkVzY3JvdwAAAAAAAwAAAAAAAAAIYXBwcm92YWwAAAfQAAAACEFwcHJvdmFsAAAAAAAAAAptaWxlc3RvbmVzAAAAAAPqAAAH0AAAAAlNaWxlc3RvbmUAAAAAAAAAAAAABG5leHQAAAAE",
+            "AAAABQAAABh0b3BpY3MgWyJwYXVzZWQiLCBhZG1pbl0AAAAAAAAABlBhdXNlZAAAAAAAAQAAAAZwYXVzZWQAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAC",
+            "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAADgAAAAEAAAAAAAAACENhbXBhaWduAAAAAQAAAAYAAAABAAAAAAAAAAhEb25hdGlvbgAAAAIAAAAGAAAAEwAAAAEAAAAAAAAACE1ldGFkYXRhAAAAAQAAAAYAAAABAAAAAAAAAAZFc2Nyb3cAAAAAAAEAAAAGAAAAAQAAAAAAAAAIUHJvcG9zYWwAAAABAAAABgAAAAEAAAAAAAAABVRpZXJzAAAAAAAAAQAAAAYAAAABAAAAAAAAAAtUaWVyQ2xhaW1lZAAAAAACAAAABgAAAAQAAAABAAAAAAAAAAlEb25vclRpZXIAAAAAAAACAAAABgAAABMAAAABAAAAAAAAAAlPYmplY3Rpb24AAAAAAAACAAAABgAAABMAAAABAAAAAAAAAARWb3RlAAAAAwAAAAYAAAAEAAAAEwAAAAEAAAAAAAAACk93bmVyQ291bnQAAAAAAAEAAAATAAAAAQAAAAAAAAANT3duZXJDYW1wYWlnbgAAAAAAAAIAAAATAAAABAAAAAEAAAAAAAAAClRva2VuQ291bnQAAAAAAAEAAAATAAAAAQAAAAAAAAANVG9rZW5DYW1wYWlnbgAAAAAAAAIAAAATAAAABA==",
+            "AAAABQAAAD90b3BpY3MgWyJkb25hdGVkIiwgY2FtcGFpZ25faWQsIGRvbm9yXSwgZGF0YSB7IGFtb3VudCwgcmFpc2VkIH0AAAAAAAAAAAdEb25hdGVkAAAAAAEAAAAHZG9uYXRlZAAAAAAEAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAI=",
+            "AAAAAgAAACZTaWFwYSB5YW5nIG1lbXV0dXNrYW4gc2V0aWFwIG1pbGVzdG9uZQAAAAAAAAAAAAhBcHByb3ZhbAAAAAIAAAABAAAARFNhdHUgYWRkcmVzcyBhcHByb3ZlciBtZW1hbmdnaWwgYXBwcm92ZV9taWxlc3RvbmUgLyByZWplY3RfbWlsZXN0b25lAAAACEFwcHJvdmVyAAAAAQAAABMAAAABAAAAQU93bmVyIG1lbmdhanVrYW4gcHJvcG9zYWwsIGRvbmF0dXIgdm90aW5nLCBsYWx1IGZpbmFsaXplX3Byb3Bvc2FsAAAAAAAACURvbm9yVm90ZQAAAAAAAAEAAAfQAAAADFZvdGluZ0NvbmZpZw==",
+            "AAAAAQAAAAAAAAAAAAAACENhbXBhaWduAAAAFwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAAZkb25vcnMAAAAAAAQAAAAAAAAACGV4dGVuZGVkAAAABgAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAAAAAAhmZWVfcGFpZAAAAAsAAAAAAAAADGZ1bmRpbmdfbW9kZQAAB9AAAAALRnVuZGluZ01vZGUAAAAAAAAAAARnb2FsAAAACwAAAAAAAAAIaGFyZF9jYXAAAAPoAAAACwAAAAAAAAAMbG9ja19vbl9nb2FsAAAAAQAAAAAAAAALbG9ja193aW5kb3cAAAAABgAAAAAAAAANbWF4X2V4dGVuc2lvbgAAAAAAAAYAAAAAAAAADW1heF9wZXJfZG9ub3IAAAAAAAPoAAAACwAAAAAAAAAMbWluX2RvbmF0aW9uAAAD6AAAAAsAAAAAAAAADW9iamVjdGlvbl9icHMAAAAAAAPoAAAABAAAAAAAAAAKb2JqZWN0aW9ucwAAAAAABAAAAAAAAAAKb3Zlcl9saW1pdAAAAAAH0AAAAA9PdmVyTGltaXRQb2xpY3kAAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAEXBsZWRnZV93aXRoZHJhd2FsAAAAAAAAAQAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAhyZWxlYXNlZAAAAAsAAAAAAAAACnN0YXJ0X3RpbWUAAAAAA+gAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAOQ2FtcGFpZ25TdGF0dXMAAAAAAAAAAAAFdG9rZW4AAAAAAAAT",
+            "AAAAAwAAADBLYXRlZ29yaSBjYW1wYWlnbiwgZGlwYWthaSBmcm9udGVuZCB1bnR1ayBmaWx0ZXIAAAAAAAAACENhdGVnb3J5AAAACAAAAAAAAAAKVGVjaG5vbG9neQAAAAAAAAAAAAAAAAADQXJ0AAAAAAEAAAAAAAAACUNvbW11bml0eQAAAAAAAAIAAAAAAAAAB0NoYXJpdHkAAAAAAwAAAAAAAAAJRWR1Y2F0aW9uAAAAAAAABAAAAAAAAAAGSGVhbHRoAAAAAAAFAAAAAAAAAAtFbnZpcm9ubWVudAAAAAAGAAAAAAAAAAVPdGhlcgAAAAAAAAc=",
+            "AAAAAQAAADJQcm9wb3NhbCBwZW5jYWlyYW4gbWlsZXN0b25lIHlhbmcgc2VkYW5nIGRpLXZvdGluZwAAAAAAAAAAAAhQcm9wb3NhbAAAAAQAAAAAAAAACW1pbGVzdG9uZQAAAAAAAAQAAAAAAAAADXZvdGVzX2FnYWluc3QAAAAAAAALAAAAAAAAAAl2b3Rlc19mb3IAAAAAAAALAAAAAAAAAAp2b3RpbmdfZW5kAAAAAAAG",
+            "AAAAAQAAADFSZXdhcmQgdGllciBiZXNlcnRhIHNpc2Egc2xvdCAoaGFzaWwgYGdldF90aWVyc2ApAAAAAAAAAAAAAAhUaWVySW5mbwAAAAQAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACm1pbl9hbW91bnQAAAAAAAsAAAAAAAAACHF1YW50aXR5AAAABAAAAAAAAAAJcmVtYWluaW5nAAAAAAAABA==",
+            "AAAABQAAAEB0b3BpY3MgWyJyZWZ1bmRlZCIsIGNhbXBhaWduX2lkLCBkb25vcl0sIGRhdGEgeyBhbW91bnQsIHJhaXNlZCB9AAAAAAAAAAhSZWZ1bmRlZAAAAAEAAAAIcmVmdW5kZWQAAAAEAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAI=",
+            "AAAABQAAABp0b3BpY3MgWyJ1bnBhdXNlZCIsIGFkbWluXQAAAAAAAAAAAAhVbnBhdXNlZAAAAAEAAAAIdW5wYXVzZWQAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAg==",
+            "AAAAAQAAAD5Lb25maWd1cmFzaSBmZWUgcGxhdGZvcm0gKGRpc2V0IGxld2F0IGNvbnN0cnVjdG9yIHNhYXQgZGVwbG95KQAAAAAAAAAAAAlGZWVDb25maWcAAAAAAAADAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAAAAAAC21heF9mZWVfYnBzAAAAAAQAAAAAAAAACHRyZWFzdXJ5AAAAEw==",
+            "AAAAAQAAABlTYXR1IHRhaGFwIHBlbmNhaXJhbiBkYW5hAAAAAAAAAAAAAAlNaWxlc3RvbmUAAAAAAAADAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAhkdWVfZGF0ZQAAAAYAAAAAAAAACnBlcmNlbnRhZ2UAAAAAAAQ=",
+            "AAAABQAAADl0b3BpY3MgWyJ3aXRoZHJhd24iLCBjYW1wYWlnbl9pZCwgb3duZXJdLCBkYXRhIHsgYW1vdW50IH0AAAAAAAAAAAAACVdpdGhkcmF3bgAAAAAAAAEAAAAJd2l0aGRyYXduAAAAAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+            "AAAAAQAAACpTYXR1IGhhbGFtYW4gaGFzaWwgYGxpc3RfYWN0aXZlX2NhbXBhaWduc2AAAAAAAAAAAAAKQWN0aXZlUGFnZQAAAAAAAgAAAAAAAAAJY2FtcGFpZ25zAAAAAAAD6gAAB9AAAAAPQ2FtcGFpZ25TdW1tYXJ5AAAAAAAAAAAEbmV4dAAAA+gAAAAG",
+            "AAAAAQAAACRQZXJ1YmFoYW4gZmVlIHlhbmcgbWVudW5nZ3UgdGltZWxvY2sAAAAAAAAAClBlbmRpbmdGZWUAAAAAAAIAAAAAAAAADGVmZmVjdGl2ZV9hdAAAAAYAAAAAAAAAB2ZlZV9icHMAAAAABA==",
+            "AAAAAQAAADNSZXdhcmQgdW50dWsgZG9uYXR1ciBkZW5nYW4gZG9uYXNpIG1pbmltYWwgdGVydGVudHUAAAAAAAAAAApSZXdhcmRUaWVyAAAAAAADAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAptaW5fYW1vdW50AAAAAAALAAAAAAAAAAhxdWFudGl0eQAAAAQ=",
+            "AAAAAQAAAEdKdW1sYWggdG9rZW4gZGFsYW0gc2F0dWFuICJtYW51c2lhIjogYHdob2xlYCArIGBmcmFjdGlvbmAgLyAxMF5kZWNpbWFscwAAAAAAAAAAClVuaXRBbW91bnQAAAAAAAIAAAAAAAAACGZyYWN0aW9uAAAACwAAAAAAAAAFd2hvbGUAAAAAAAAL",
+            "AAAABQAAADp0b3BpY3MgWyJmZWVfY2hhcmdlZCIsIGNhbXBhaWduX2lkLCB0cmVhc3VyeV0sIGRhdGEgYW1vdW50AAAAAAAAAAAACkZlZUNoYXJnZWQAAAAAAAEAAAALZmVlX2NoYXJnZWQAAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAAAAAAACHRyZWFzdXJ5AAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAA",
+            "AAAAAwAAAENBcGEgeWFuZyB0ZXJqYWRpIGRlbmdhbiBkYW5hIGppa2EgZ29hbCB0aWRhayB0ZXJjYXBhaSBzYWF0IGRlYWRsaW5lAAAAAAAAAAALRnVuZGluZ01vZGUAAAAAAgAAAEFHb2FsIHRpZGFrIHRlcmNhcGFpOiBkb25hdHVyIHJlZnVuZCwgb3duZXIgdGlkYWsgbWVuZXJpbWEgYXBhLWFwYQAAAAAAAAxBbGxPck5vdGhpbmcAAAAAAAAARE93bmVyIHRldGFwIG1lbmVyaW1hIHNlbXVhIGRhbmEgeWFuZyB0ZXJrdW1wdWwgKGNvY29rIHVudHVrIGNoYXJpdHkpAAAACUtlZXBJdEFsbAAAAAAAAAE=",
+            "AAAABQAAADZ0b3BpY3MgWyJ0aWVyX2NsYWltZWQiLCBjYW1wYWlnbl9pZCwgZG9ub3JdLCBkYXRhIHRpZXIAAAAAAAAAAAALVGllckNsYWltZWQAAAAAAQAAAAx0aWVyX2NsYWltZWQAAAADAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAEdGllcgAAAAQAAAAAAAAAAA==",
+            "AAAAAQAAADZQYXJhbWV0ZXIgdm90aW5nIGRvbmF0dXIuIEJvYm90IHN1YXJhID0ganVtbGFoIGRvbmFzaS4AAAAAAAAAAAAMVm90aW5nQ29uZmlnAAAAAwAAAAAAAAAKcXVvcnVtX2JwcwAAAAAABAAAAAAAAAANdGhyZXNob2xkX2JwcwAAAAAAAAQAAAAAAAAADXZvdGluZ19wZXJpb2QAAAAAAAAG",
+            "AAAABQAAAC50b3BpY3MgWyJhZG1pbl9jaGFuZ2VkIiwgb2xkX2FkbWluLCBuZXdfYWRtaW5dAAAAAAAAAAAADEFkbWluQ2hhbmdlZAAAAAEAAAANYWRtaW5fY2hhbmdlZAAAAAAAAAIAAAAAAAAACW9sZF9hZG1pbgAAAAAAABMAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAQAAAAI=",
+            "AAAABQAAADh0b3BpY3MgWyJmZWVfc2NoZWR1bGVkIl0sIGRhdGEgeyBmZWVfYnBzLCBlZmZlY3RpdmVfYXQgfQAAAAAAAAAMRmVlU2NoZWR1bGVkAAAAAQAAAA1mZWVfc2NoZWR1bGVkAAAAAAAAAgAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAAAAAAAAAAAMZWZmZWN0aXZlX2F0AAAABgAAAAAAAAAC",
+            "AAAAAQAAAEZHb2FsICYgcmFpc2VkIGNhbXBhaWduIGRhbGFtIHNhdHVhbiB0b2tlbiAoaGFzaWwgYGdldF9jYW1wYWlnbl91bml0c2ApAAAAAAAAAAAADUNhbXBhaWduVW5pdHMAAAAAAAADAAAAAAAAAAhkZWNpbWFscwAAAAQAAAAAAAAABGdvYWwAAAfQAAAAClVuaXRBbW91bnQAAAAAAAAAAAAGcmFpc2VkAAAAAAfQAAAAClVuaXRBbW91bnQAAA==",
+            "AAAAAQAAACZSZW5jYW5hIG1pbGVzdG9uZSBzYWF0IGNyZWF0ZV9jYW1wYWlnbgAAAAAAAAAAAA1NaWxlc3RvbmVQbGFuAAAAAAAAAgAAAAAAAAAIYXBwcm92YWwAAAfQAAAACEFwcHJvdmFsAAAAAAAAAAptaWxlc3RvbmVzAAAAAAPqAAAH0AAAAAlNaWxlc3RvbmUAAAA=",
+            "AAAAAQAAAC9PcHNpIGNhbXBhaWduIHlhbmcgZGlwaWxpaCBzYWF0IGNyZWF0ZV9jYW1wYWlnbgAAAAAAAAAADkNhbXBhaWduQ29uZmlnAAAAAAALAAAAAAAAAAxmdW5kaW5nX21vZGUAAAfQAAAAC0Z1bmRpbmdNb2RlAAAAAAAAAAAIaGFyZF9jYXAAAAPoAAAACwAAAAAAAAAMbG9ja19vbl9nb2FsAAAAAQAAAAAAAAALbG9ja193aW5kb3cAAAAABgAAAAAAAAANbWF4X2V4dGVuc2lvbgAAAAAAAAYAAAAAAAAADW1heF9wZXJfZG9ub3IAAAAAAAPoAAAACwAAAAAAAAAMbWluX2RvbmF0aW9uAAAD6AAAAAsAAAAAAAAADW9iamVjdGlvbl9icHMAAAAAAAPoAAAABAAAAAAAAAAKb3Zlcl9saW1pdAAAAAAH0AAAAA9PdmVyTGltaXRQb2xpY3kAAAAAAAAAABFwbGVkZ2Vfd2l0aGRyYXdhbAAAAAAAAAEAAAAAAAAACnN0YXJ0X3RpbWUAAAAAA+gAAAAG",
+            "AAAAAwAAA4pTdGF0dXMgY2FtcGFpZ24uIFRyYW5zaXNpIHlhbmcgZGlpemlua2FuOgoKfCBEYXJpICAgICAgfCBLZSAgICAgICAgfCBQZW1pY3UgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgfAp8LS0tLS0tLS0tLS18LS0tLS0tLS0tLS18LS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS18CnwgUGVuZGluZyAgIHwgQWN0aXZlICAgIHwgYG5vdyA+PSBzdGFydF90aW1lYCAgICAgICAgICAgICAgICAgICAgIHwKfCBQZW5kaW5nICAgfCBDYW5jZWxsZWQgfCBgY2FuY2VsX2NhbXBhaWduYCAgICAgICAgICAgICAgICAgICAgICAgfAp8IEFjdGl2ZSAgICB8IFN1Y2NlZWRlZCB8IGBub3cgPj0gZGVhZGxpbmVgIGRhbiBkYW5hIGN1a3VwICgqKSAgICB8CnwgQWN0aXZlICAgIHwgRmFpbGVkICAgIHwgYG5vdyA+PSBkZWFkbGluZWAgZGFuIGRhbmEgdGlkYWsgY3VrdXAgKCopIHwKfCBBY3RpdmUgICAgfCBTdWNjZWVkZWQgfCBoYXJkIGNhcCB0ZXJjYXBhaSAoc2ViZWx1bSBkZWFkbGluZSkgICAgfAp8IEFjdGl2ZSAgICB8IENhbmNlbGxlZCB8IGBjYW5jZWxfY2FtcGFpZ25gICAgICAgICAgICAgICAgICAgICAgICB8CnwgU3VjY2VlZGVkIHwgQ2xhaW1lZCAgIHwgYHdpdGhkcmF3YCAvIG1pbGVzdG9uZSB0ZXJha2hpciBkaXJpbGlzIHwKfCBTdWNjZWVkZWQgfCBGYWlsZWQgICAgfCBtaWxlc3RvbmUgZGl0b2xhayBhdGF1IGxld2F0IGR1ZSBkYXRlICAgfAoKKCopIEFsbE9yTm90aGluZzogZ29hbCB0ZXJjYXBhaS4gS2VlcEl0QWxsOiBhZGEgZGFuYSB0ZXJrdW1wdWwgKD4gMCkuCgpGYWlsZWQsIENhbmNlbGxlZCAmIENsYWltZWQgYWRhbGFoIHN0YXR1cyBha2hpciBkYW4gZGlzaW1wYW47IHN0YXR1cwpsYWluIGRpaGl0dW5nIGRhcmkgd2FrdHUgbGVkZ2VyICYgZGFuYS4AAAAAAAAAAAAOQ2FtcGFpZ25TdGF0dXMAAAAAAAYAAAAZQmVsdW0gZGlidWthIHVudHVrIGRvbmFzaQAAAAAAAAdQZW5kaW5nAAAAAAAAAAAPTWVuZXJpbWEgZG9uYXNpAAAAAAZBY3RpdmUAAAAAAAEAAAA1RGVhZGxpbmUgbGV3YXQgJiBnb2FsIHRlcmNhcGFpLCBvd25lciBiaXNhIGB3aXRoZHJhd2AAAAAAAAAJU3VjY2VlZGVkAAAAAAAAAgAAADtEZWFkbGluZSBsZXdhdCAmIGdvYWwgdGlkYWsgdGVyY2FwYWksIGRvbmF0dXIgYmlzYSBgcmVmdW5kYAAAAAAGRmFpbGVkAAAAAAADAAAAJ0RpYmF0YWxrYW4gb3duZXIsIGRvbmF0dXIgYmlzYSBgcmVmdW5kYAAAAAAJQ2FuY2VsbGVkAAAAAAAABAAAABhEYW5hIHN1ZGFoIGRpdGFyaWsgb3duZXIAAAAHQ2xhaW1lZAAAAAAF",
+            "AAAAAQAAAEIoTGVnYWN5KSBCZW50dWsgY2FtcGFpZ24gZGkgc2NoZW1hIHZlcnNpIDEsIHRlcm1hc3VrIG1hcCBkb25hc2lueWEAAAAAAAAAAAAOTGVnYWN5Q2FtcGFpZ24AAAAAAAYAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAJZG9uYXRpb25zAAAAAAAD7AAAABMAAAALAAAAAAAAAARnb2FsAAAACwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAZyYWlzZWQAAAAAAAsAAAAAAAAABXRva2VuAAAAAAAAEw==",
+            "AAAAAQAAADhSaW5na2FzYW4gY2FtcGFpZ24gdW50dWsgaGFsYW1hbiBkYWZ0YXIgKGhhc2lsIGBsaXN0XypgKQAAAAAAAAAPQ2FtcGFpZ25TdW1tYXJ5AAAAAAkAAAAAAAAACGNhdGVnb3J5AAAH0AAAAAhDYXRlZ29yeQAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAARnb2FsAAAACwAAAAAAAAACaWQAAAAAAAYAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAOQ2FtcGFpZ25TdGF0dXMAAAAAAAAAAAAFdGl0bGUAAAAAAAAQAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+            "AAAAAwAAADtQZXJsYWt1YW4gZG9uYXNpIHlhbmcgbWVsZXdhdGkgaGFyZCBjYXAgLyBiYXRhcyBwZXIgZG9uYXR1cgAAAAAAAAAAD092ZXJMaW1pdFBvbGljeQAAAAACAAAAFFRvbGFrIHNlbHVydWggZG9uYXNpAAAABlJlamVjdAAAAAAAAAAAADZUZXJpbWEgc2ViYWdpYW4gc2FtcGFpIGJhdGFzLCBzaXNhbnlhIHRpZGFrIGRpdHJhbnNmZXIAAAAAAA1QYXJ0aWFsQWNjZXB0AAAAAAAAAQ==",
+            "AAAABQAAAE90b3BpY3MgWyJjYW1wYWlnbl9jcmVhdGVkIiwgY2FtcGFpZ25faWQsIG93bmVyXSwgZGF0YSB7IGdvYWwsIGRlYWRsaW5lLCB0b2tlbiB9AAAAAAAAAAAPQ2FtcGFpZ25DcmVhdGVkAAAAAAEAAAAQY2FtcGFpZ25fY3JlYXRlZAAAAAUAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAAAAAAAARnb2FsAAAACwAAAAAAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAC",
+            "AAAABQAAAEJ0b3BpY3MgWyJtZXRhZGF0YV91cGRhdGVkIiwgY2FtcGFpZ25faWRdLCBkYXRhIHsgdGl0bGUsIGNhdGVnb3J5IH0AAAAAAAAAAAAPTWV0YWRhdGFVcGRhdGVkAAAAAAEAAAAQbWV0YWRhdGFfdXBkYXRlZAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAAAAAAAhjYXRlZ29yeQAAB9AAAAAIQ2F0ZWdvcnkAAAAAAAAAAg==",
+            "AAAABQAAAEh0b3BpY3MgWyJwbGVkZ2Vfd2l0aGRyYXduIiwgY2FtcGFpZ25faWQsIGRvbm9yXSwgZGF0YSB7IGFtb3VudCwgcmFpc2VkIH0AAAAAAAAAD1BsZWRnZVdpdGhkcmF3bgAAAAABAAAAEHBsZWRnZV93aXRoZHJhd24AAAAEAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAI=",
+            "AAAABQAAAEh0b3BpY3MgWyJwcm9wb3NhbF9jcmVhdGVkIiwgY2FtcGFpZ25faWQsIG1pbGVzdG9uZV0sIGRhdGEgeyB2b3RpbmdfZW5kIH0AAAAAAAAAD1Byb3Bvc2FsQ3JlYXRlZAAAAAABAAAAEHByb3Bvc2FsX2NyZWF0ZWQAAAADAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAJbWlsZXN0b25lAAAAAAAABAAAAAEAAAAAAAAACnZvdGluZ19lbmQAAAAAAAYAAAAAAAAAAg==",
+            "AAAAAQAAAH1NZXRhZGF0YSB0YW1waWxhbiBjYW1wYWlnbi4gRGlzaW1wYW4gdGVycGlzYWggZGFyaSBgQ2FtcGFpZ25gIHN1cGF5YQpgZG9uYXRlYC9gcmVmdW5kYCB0aWRhayBwZXJsdSBtZW1iYWNhIHRla3MgeWFuZyBwYW5qYW5nLgAAAAAAAAAAAAAQQ2FtcGFpZ25NZXRhZGF0YQAAAAQAAAAAAAAACGNhdGVnb3J5AAAH0AAAAAhDYXRlZ29yeQAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAMbWV0YWRhdGFfdXJpAAAAEAAAAAAAAAAFdGl0bGUAAAAAAAAQ",
+            "AAAABQAAAE50b3BpY3MgWyJkZWFkbGluZV9leHRlbmRlZCIsIGNhbXBhaWduX2lkXSwgZGF0YSB7IG9sZF9kZWFkbGluZSwgbmV3X2RlYWRsaW5lIH0AAAAAAAAAAAAQRGVhZGxpbmVFeHRlbmRlZAAAAAEAAAARZGVhZGxpbmVfZXh0ZW5kZWQAAAAAAAADAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAMb2xkX2RlYWRsaW5lAAAABgAAAAAAAAAAAAAADG5ld19kZWFkbGluZQAAAAYAAAAAAAAAAg==",
+            "AAAABAAAAHZLb2RlIGVycm9yIGtvbnRyYWsuIE5vbW9yIGRpcGFrYWkgb2xlaCBiaW5kaW5nIFRTIGRhbiBrb250cmFrIHBlbWFuZ2dpbCwKamFkaSBub21vciB5YW5nIHN1ZGFoIGFkYSB0aWRhayBib2xlaCBkaXViYWguAAAAAAAAAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAAAAAAMgAAACBHb2FsIGNhbXBhaWduIGhhcnVzIGxlYmloIGRhcmkgMAAAAA9Hb2FsTm90UG9zaXRpdmUAAAAAAQAAABxEZWFkbGluZSBoYXJ1cyBkaSBtYXNhIGRlcGFuAAAADkRlYWRsaW5lSW5QYXN0AAAAAAACAAAAIEp1bWxhaCBkb25hc2kgaGFydXMgbGViaWggZGFyaSAwAAAAEUFtb3VudE5vdFBvc2l0aXZlAAAAAAAAAwAAACVDYW1wYWlnbiBkZW5nYW4gSUQgdGVyc2VidXQgdGlkYWsgYWRhAAAAAAAAEENhbXBhaWduTm90Rm91bmQAAAAEAAAALkNhbXBhaWduIHN1ZGFoIGJlcmFraGlyLCB0aWRhayBtZW5lcmltYSBkb25hc2kAAAAAAA1DYW1wYWlnbkVuZGVkAAAAAAAABQAAABdDYW1wYWlnbiBiZWx1bSBiZXJha2hpcgAAAAAQQ2FtcGFpZ25Ob3RFbmRlZAAAAAYAAAAmR29hbCBzdWRhaCB0ZXJjYXBhaSwgdGlkYWsgYmlzYSByZWZ1bmQAAAAAAAtHb2FsUmVhY2hlZAAAAAAHAAAAMkdvYWwgYmVsdW0gdGVyY2FwYWksIG93bmVyIHRpZGFrIGJpc2EgbWVuYXJpayBkYW5hAAAAAAAOR29hbE5vdFJlYWNoZWQAAAAAAAgAAAAhRGFuYSBjYW1wYWlnbiBzdWRhaCBkaXRhcmlrIG93bmVyAAAAAAAADkFscmVhZHlDbGFpbWVkAAAAAAAJAAAAKURvbmF0dXIgdGlkYWsgZGl0ZW11a2FuIGF0YXUgc3VkYWggcmVmdW5kAAAAAAAAD05vdGhpbmdUb1JlZnVuZAAAAAAKAAAAIUp1ZHVsIGtvc29uZyBhdGF1IHRlcmxhbHUgcGFuamFuZwAAAAAAAAxJbnZhbGlkVGl0bGUAAAALAAAAGURlc2tyaXBzaSB0ZXJsYWx1IHBhbmphbmcAAAAAAAASRGVzY3JpcHRpb25Ub29Mb25nAAAAAAAMAAAAOlVSSSBtZXRhZGF0YSB0ZXJsYWx1IHBhbmphbmcgYXRhdSBidWthbiBpcGZzOi8vIC8gaHR0cHM6Ly8AAAAAABJJbnZhbGlkTWV0YWRhdGFVcmkAAAAAAA0AAABGTWV0YWRhdGEgdGlkYWsgYmlzYSBkaXViYWggbGFnaSAoc3VkYWggYWRhIGRvbmFzaSAvIGNhbXBhaWduIGJlcmFraGlyKQAAAAAADk1ldGFkYXRhTG9ja2VkAAAAAAAOAAAAH0NhbXBhaWduIHN1ZGFoIGRpYmF0YWxrYW4gb3duZXIAAAAAEUNhbXBhaWduQ2FuY2VsbGVkAAAAAAAADwAAAFBEYWZ0YXIgbWlsZXN0b25lIHRpZGFrIHZhbGlkIChrb3NvbmcsIHBlcnNlbnRhc2UgdGlkYWsgMTAwLCBkdWUgZGF0ZSB0aWRhayB1cnV0KQAAABFJbnZhbGlkTWlsZXN0b25lcwAAAAAAABAAAABGRGFuYSBjYW1wYWlnbiBiZXItbWlsZXN0b25lIGhhbnlhIGJpc2EgZGlyaWxpcyBsZXdhdCBhcHByb3ZlX21pbGVzdG9uZQAAAAAADUZ1bmRzSW5Fc2Nyb3cAAAAAAAARAAAAHkNhbXBhaWduIHRpZGFrIHB1bnlhIG1pbGVzdG9uZQAAAAAADE5vTWlsZXN0b25lcwAAABIAAABUVGlkYWsgYWRhIG1pbGVzdG9uZSB5YW5nIGJpc2EgZGlwcm9zZXMgKGRpdG9sYWssIHRlcmxld2F0LCBhdGF1IHN1ZGFoIGRpcmlsaXMgc2VtdWEpAAAAD01pbGVzdG9uZUNsb3NlZAAAAAATAAAAT01pbGVzdG9uZSBjYW1wYWlnbiBpbmkgZGlwdXR1c2thbiBkZW5nYW4gY2FyYSBsYWluIChhcHByb3ZlciB2cyB2b3RpbmcgZG9uYXR1cikAAAAAEVdyb25nQXBwcm92YWxNb2RlAAAAAAAAFAAAAC9RdW9ydW0gLyB0aHJlc2hvbGQgLyBwZXJpb2RlIHZvdGluZyB0aWRhayB2YWxpZAAAAAATSW52YWxpZFZvdGluZ0NvbmZpZwAAAAAVAAAAKk1hc2loIGFkYSBwcm9wb3NhbCB5YW5nIGJlbHVtIGRpZmluYWxpc2FzaQAAAAAADFByb3Bvc2FsT3BlbgAAABYAAAAnVGlkYWsgYWRhIHByb3Bvc2FsIHlhbmcgc2VkYW5nIGJlcmphbGFuAAAAAApOb1Byb3Bvc2FsAAAAAAAXAAAAHVBlcmlvZGUgdm90aW5nIHN1ZGFoIGJlcmFraGlyAAAAAAAADFZvdGluZ0Nsb3NlZAAAABgAAAAdUGVyaW9kZSB2b3RpbmcgYmVsdW0gYmVyYWtoaXIAAAAAAAAOVm90aW5nTm90RW5kZWQAAAAAABkAAAAxRG9uYXR1ciBzdWRhaCBtZW1iZXJpa2FuIHN1YXJhIHVudHVrIHByb3Bvc2FsIGluaQAAAAAAAAxBbHJlYWR5Vm90ZWQAAAAaAAAAJ0hhbnlhIGRvbmF0dXIgY2FtcGFpZ24geWFuZyBiaXNhIHZvdGluZwAAAAAITm90RG9ub3IAAAAbAAAAHkRhZnRhciByZXdhcmQgdGllciB0aWRhayB2YWxpZAAAAAAADEludmFsaWRUaWVycwAAABwAAAArUmV3YXJkIHRpZXIgZGVuZ2FuIGluZGV4IHRlcnNlYnV0IHRpZGFrIGFkYQAAAAAMVGllck5vdEZvdW5kAAAAHQAAACpUb3RhbCBkb25hc2kgZG9uYXR1ciBkaSBiYXdhaCBtaW5pbXVtIHRpZXIAAAAAABBUaWVyQmVsb3dNaW5pbXVtAAAAHgAAAB1LdW90YSByZXdhcmQgdGllciBzdWRhaCBoYWJpcwAAAAAAAAtUaWVyU29sZE91dAAAAAAfAAAATkNhbXBhaWduIGtlZXAtaXQtYWxsOiBkYW5hIHlhbmcgdGVya3VtcHVsIHRldGFwIHVudHVrIG93bmVyLCB0aWRhayBiaXNhIHJlZnVuZAAAAAAACUZ1bmRzS2VwdAAAAAAAACAAAAAjSGFyZCBjYXAgLyBiYXRhcyBkb25hc2kgdGlkYWsgdmFsaWQAAAAADUludmFsaWRMaW1pdHMAAAAAAAAhAAAAJ0RvbmFzaSBkaSBiYXdhaCBtaW5pbXVtIGRvbmFzaSBjYW1wYWlnbgAAAAAQQmVsb3dNaW5Eb25hdGlvbgAAACIAAAAvVG90YWwgZG9uYXNpIGRvbmF0dXIgbWVsZWJpaGkgYmF0YXMgcGVyIGRvbmF0dXIAAAAAEkRvbm9yTGltaXRFeGNlZWRlZAAAAAAAIwAAACFEb25hc2kgbWVsZWJpaGkgaGFyZCBjYXAgY2FtcGFpZ24AAAAAAAAPSGFyZENhcEV4Y2VlZGVkAAAAACQAAAAiV2FrdHUgbXVsYWkgaGFydXMgc2ViZWx1bSBkZWFkbGluZQAAAAAAEEludmFsaWRTdGFydFRpbWUAAAAlAAAAKkNhbXBhaWduIGJlbHVtIGRpYnVrYSAoc2ViZWx1bSBzdGFydF90aW1lKQAAAAAAEkNhbXBhaWduTm90U3RhcnRlZAAAAAAAJgAAAFNQZXJwYW5qYW5nYW4gZGVhZGxpbmUgdGlkYWsgdmFsaWQgKGtvbmZpZ3VyYXNpLCBkZWFkbGluZSBiYXJ1LCBhdGF1IG1lbGV3YXRpIGJhdGFzKQAAAAAQSW52YWxpZEV4dGVuc2lvbgAAACcAAAA8UGVycGFuamFuZ2FuIGRpdG9sYWsga2FyZW5hIGtlYmVyYXRhbiBkb25hdHVyIG1lbmNhcGFpIGJhdGFzAAAAEUV4dGVuc2lvbk9iamVjdGVkAAAAAAAAKAAAACJEb25hdHVyIHN1ZGFoIG1lbnlhdGFrYW4ga2ViZXJhdGFuAAAAAAAPQWxyZWFkeU9iamVjdGVkAAAAACkAAAA8Q2FtcGFpZ24gdGlkYWsgbWVuZ2l6aW5rYW4gcGVuYXJpa2FuIGRvbmFzaSBzZWJlbHVtIGRlYWRsaW5lAAAAGFBsZWRnZVdpdGhkcmF3YWxEaXNhYmxlZAAAACoAAAA9UGVuYXJpa2FuIGRvbmFzaSBkaWt1bmNpIChnb2FsIHRlcmNhcGFpIC8gbWVuZGVrYXRpIGRlYWRsaW5lKQAAAAAAAAxQbGVkZ2VMb2NrZWQAAAArAAAAKEp1bWxhaCBwZW5hcmlrYW4gbWVsZWJpaGkgZG9uYXNpIGRvbmF0dXIAAAAVQW1vdW50RXhjZWVkc0RvbmF0aW9uAAAAAAAALAAAAEQoVGlkYWsgZGlwYWthaSBsYWdpIHNlamFrIGNvbnN0cnVjdG9yKSBQbGF0Zm9ybSBzdWRhaCBkaWluaXNpYWxpc2FzaQAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAC0AAAAbRmVlIG1lbGViaWhpIGJhdGFzIG1ha3NpbXVtAAAAAApJbnZhbGlkRmVlAAAAAAAuAAAAKUtvbmZpZ3VyYXNpIHBsYXRmb3JtIGJlbHVtIGRpaW5pc2lhbGlzYXNpAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAAvAAAAIktvbnRyYWsgc2VkYW5nIGRpLXBhdXNlIG9sZWggYWRtaW4AAAAAAA5Db250cmFjdFBhdXNlZAAAAAAAMAAAACZUaWRhayBhZGEgdHJhbnNmZXIgYWRtaW4geWFuZyBtZW51bmdndQAAAAAADk5vUGVuZGluZ0FkbWluAAAAAAAxAAAAMFRyYW5zZmVyIHRva2VuIGdhZ2FsIChtaXMuIHNhbGRvIGRvbmF0dXIga3VyYW5nKQAAABNUb2tlblRyYW5zZmVyRmFpbGVkAAAAADI=",
+            "AAAAAAAAAHFEb25hdHVyIG1lbWJlcmlrYW4gc3VhcmEgdW50dWsgcHJvcG9zYWwgeWFuZyBzZWRhbmcgYmVyamFsYW4uCkJvYm90IHN1YXJhID0gdG90YWwgZG9uYXNpIGRvbmF0dXIga2UgY2FtcGFpZ24gaW5pLgAAAAAAAAR2b3RlAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAdhcHByb3ZlAAAAAAEAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAABQAAAEJ0b3BpY3MgWyJjYW1wYWlnbl9jYW5jZWxsZWQiLCBjYW1wYWlnbl9pZCwgb3duZXJdLCBkYXRhIHsgcmFpc2VkIH0AAAAAAAAAAAARQ2FtcGFpZ25DYW5jZWxsZWQAAAAAAAABAAAAEmNhbXBhaWduX2NhbmNlbGxlZAAAAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAABnJhaXNlZAAAAAAACwAAAAAAAAAC",
+            "AAAABQAAAHh0b3BpY3MgWyJleHRlbnNpb25fb2JqZWN0ZWQiLCBjYW1wYWlnbl9pZCwgZG9ub3JdLCBkYXRhIG9iamVjdGlvbnMKKGp1bWxhaCBkb25hdHVyIHlhbmcga2ViZXJhdGFuIHNldGVsYWgga2ViZXJhdGFuIGluaSkAAAAAAAAAEUV4dGVuc2lvbk9iamVjdGVkAAAAAAAAAQAAABJleHRlbnNpb25fb2JqZWN0ZWQAAAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAAAAAAAApvYmplY3Rpb25zAAAAAAAEAAAAAAAAAAA=",
+            "AAAABQAAAEV0b3BpY3MgWyJtaWxlc3RvbmVfcmVqZWN0ZWQiLCBjYW1wYWlnbl9pZCwgaW5kZXhdLCBkYXRhIHsgcmVtYWluaW5nIH0AAAAAAAAAAAAAEU1pbGVzdG9uZVJlamVjdGVkAAAAAAAAAQAAABJtaWxlc3RvbmVfcmVqZWN0ZWQAAAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAAAAAAVpbmRleAAAAAAAAAQAAAABAAAAAAAAAAlyZW1haW5pbmcAAAAAAAALAAAAAAAAAAI=",
+            "AAAABQAAAEJ0b3BpY3MgWyJtaWxlc3RvbmVfcmVsZWFzZWQiLCBjYW1wYWlnbl9pZCwgaW5kZXhdLCBkYXRhIHsgYW1vdW50IH0AAAAAAAAAAAARTWlsZXN0b25lUmVsZWFzZWQAAAAAAAABAAAAEm1pbGVzdG9uZV9yZWxlYXNlZAAAAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAAAAAAABWluZGV4AAAAAAAABAAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+            "AAAABQAAAGJ0b3BpY3MgWyJwcm9wb3NhbF9maW5hbGl6ZWQiLCBjYW1wYWlnbl9pZCwgbWlsZXN0b25lXSwgZGF0YSB7IGFwcHJvdmVkLCB2b3Rlc19mb3IsIHZvdGVzX2FnYWluc3QgfQAAAAAAAAAAABFQcm9wb3NhbEZpbmFsaXplZAAAAAAAAAEAAAAScHJvcG9zYWxfZmluYWxpemVkAAAAAAAFAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAJbWlsZXN0b25lAAAAAAAABAAAAAEAAAAAAAAACGFwcHJvdmVkAAAAAQAAAAAAAAAAAAAACXZvdGVzX2ZvcgAAAAAAAAsAAAAAAAAAAAAAAA12b3Rlc19hZ2FpbnN0AAAAAAAACwAAAAAAAAAC",
+            "AAAAAAAAAGtBZG1pbiBtZW5naGVudGlrYW4gc2VtZW50YXJhIGBjcmVhdGVfY2FtcGFpZ25gICYgYGRvbmF0ZWAuClJlZnVuZCwgd2l0aGRyYXdfcGxlZGdlICYgcGF5b3V0IHRldGFwIGJlcmphbGFuLgAAAAAFcGF1c2UAAAAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAAOpEb25hc2kga2UgY2FtcGFpZ24gdGVydGVudHUgbWVuZ2d1bmFrYW4gdG9rZW4gdHJhbnNmZXIuCmB0aWVyYCBvcHNpb25hbDogaW5kZXggcmV3YXJkIHRpZXIgeWFuZyBkaXBpbGloIGRvbmF0dXIuCk1lbmdlbWJhbGlrYW4ganVtbGFoIHlhbmcgZGl0ZXJpbWEgKGJpc2EgbGViaWgga2VjaWwgZGFyaSBgYW1vdW50YApqaWthIGNhbXBhaWduIG1lbWFrYWkgT3ZlckxpbWl0UG9saWN5OjpQYXJ0aWFsQWNjZXB0KS4AAAAAAAZkb25hdGUAAAAAAAQAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAR0aWVyAAAD6AAAAAQAAAABAAAD6QAAAAsAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+            "AAAAAAAAAF5SZWZ1bmQgbWVjaGFuaXNtIGppa2EgY2FtcGFpZ24gZ2FnYWwgYXRhdSBkaWJhdGFsa2FuIG93bmVyCihoYW55YSBiaXNhIGRpcGFuZ2dpbCBvbGVoIGRvbmF0dXIpAAAAAAAGcmVmdW5kAAAAAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAD6QAAAAsAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+            "AAAAAAAAAOpNZW1pbmRhaGthbiBtYWtzaW1hbCBgbGltaXRgIGNhbXBhaWduIGRhcmkgbWFwIENBTVBBSUdOUyAoc2NoZW1hIDEpCmtlIGtleSBwZXIgY2FtcGFpZ24uIEJvbGVoIGRpcGFuZ2dpbCBzaWFwYSBzYWphIGthcmVuYSBkYXRhIHRpZGFrCmJlcnViYWg7IHBhbmdnaWwgYmVydWxhbmcgc2FtcGFpIGhhc2lsbnlhIDAuIE1lbmdlbWJhbGlrYW4ganVtbGFoCmNhbXBhaWduIGxhbWEgeWFuZyBiZWx1bSBkaXBpbmRhaC4AAAAAAAdtaWdyYXRlAAAAAAEAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAAE",
+            "AAAAAAAAAKlBZG1pbiBtZW5qYWR3YWxrYW4gZmVlIGJhcnUgKG1ha3MgYG1heF9mZWVfYnBzYCkuIEJlcmxha3UgdW50dWsKY2FtcGFpZ24geWFuZyBkaWJ1YXQgc2V0ZWxhaCB0aW1lbG9jayBGRUVfVElNRUxPQ0sgbGV3YXQuCk1lbmdlbWJhbGlrYW4gdGltZXN0YW1wIGZlZSBiYXJ1IG11bGFpIGJlcmxha3UuAAAAAAAAB3NldF9mZWUAAAAAAQAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAQAAA+kAAAAGAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAB1BZG1pbiBtZW1idWthIGtlbWJhbGkga29udHJhawAAAAAAAAd1bnBhdXNlAAAAAAAAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAMlBZG1pbiBtZW5nZ2FudGkga29kZSBrb250cmFrIGRlbmdhbiBXQVNNIHlhbmcgc3VkYWggZGktdXBsb2FkLgpBZG1pbiBkaXRldGFwa2FuIG9sZWggY29uc3RydWN0b3Igc2FhdCBkZXBsb3ksIGphZGkgdGlkYWsgYmlzYSBkaXJlYnV0LgpTdG9yYWdlIHRldGFwOyBqYWxhbmthbiBgbWlncmF0ZWAgamlrYSB2ZXJzaSBiYXJ1IG1lbmd1YmFoIGxheW91dC4AAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAABhHZXQgY2FtcGFpZ24gZ29hbCBhbW91bnQAAAAIZ2V0X2dvYWwAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAALAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAHtDaGVjayBhcGFrYWggZG9uYXR1ciBzdWRhaCB2b3RpbmcgdW50dWsgbWlsZXN0b25lIHRlcnRlbnR1LgpgTm9uZWAgamlrYSBiZWx1bSB2b3RpbmcsIGBTb21lKHRydWUvZmFsc2UpYCBzZXN1YWkgcGlsaWhhbm55YS4AAAAACGdldF92b3RlAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAAAAAAJbWlsZXN0b25lAAAAAAAABAAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAA+gAAAAB",
+            "AAAAAAAAADZDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggYmVyYWtoaXIgKGRlYWRsaW5lIHBhc3NlZCkAAAAAAAhpc19lbmRlZAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAAAEAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+            "AAAAAAAAAOBPd25lciBtZW5hcmlrIGRhbmEgY2FtcGFpZ24geWFuZyBiZXJoYXNpbCAoZGVhZGxpbmUgbGV3YXQgJiBnb2FsIHRlcmNhcGFpLAphdGF1IGFkYSBkYW5hIHRlcmt1bXB1bCB1bnR1ayBtb2RlIEtlZXBJdEFsbCkuCkhhbnlhIGJpc2EgZGlsYWt1a2FuIHNla2FsaS4gTWVuZ2VtYmFsaWthbiBqdW1sYWggeWFuZyBkaXRlcmltYSBvd25lcgooc2V0ZWxhaCBkaXBvdG9uZyBmZWUgcGxhdGZvcm0pLgAAAAh3aXRoZHJhdwAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAAAsAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+            "AAAAAAAAABJHZXQgYWRtaW4gcGxhdGZvcm0AAAAAAAlnZXRfYWRtaW4AAAAAAAAAAAAAAQAAA+kAAAATAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAADFHZXQgZGFmdGFyIHJld2FyZCB0aWVyIGNhbXBhaWduIGJlc2VydGEgc2lzYSBzbG90AAAAAAAACWdldF90aWVycwAAAAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAA+oAAAfQAAAACFRpZXJJbmZvAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAACJDZWsgYXBha2FoIGtvbnRyYWsgc2VkYW5nIGRpLXBhdXNlAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+            "AAAAAAAAAEJHZXQgZXNjcm93IG1pbGVzdG9uZSBjYW1wYWlnbiAobWlsZXN0b25lLCBjYXJhIGFwcHJvdmFsICYgcHJvZ3JlcykAAAAAAApnZXRfZXNjcm93AAAAAAABAAAAAAAAAAJpZAAAAAAABgAAAAEAAAPpAAAH0AAAAAZFc2Nyb3cAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAAEVHZXQgc3RhdHVzIGNhbXBhaWduIHNhYXQgaW5pIChsaWhhdCB0YWJlbCB0cmFuc2lzaSBkaSBDYW1wYWlnblN0YXR1cykAAAAAAAAKZ2V0X3N0YXR1cwAAAAAAAQAAAAAAAAACaWQAAAAAAAYAAAABAAAD6QAAB9AAAAAOQ2FtcGFpZ25TdGF0dXMAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAADNDaGVjayBhcGFrYWggZGFuYSBjYW1wYWlnbiBzdWRhaCBkaXRhcmlrIG9sZWggb3duZXIAAAAACmlzX2NsYWltZWQAAAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAAAEAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
             "AAAAAAAAACZHZXQgSUQgKHU2NCkgdW50dWsgY2FtcGFpZ24gYmVyaWt1dG55YQAAAAAAC2dldF9uZXh0X2lkAAAAAAAAAAABAAAABg==",
-            "AAAAAAAAAD1HZXQgdG90YWwgYW1vdW50IHlhbmcgc3VkYWggdGVya3VtcHVsIHVudHVrIGNhbXBhaWduIHRlcnRlbnR1AAAAAAAAEGdldF90b3RhbF9yYWlzZWQAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAs=",
-            "AAAAAAAAAEJHZXQgYmVyYXBhIGJhbnlhayBzcGVjaWZpYyBkb25vciBzdWRhaCBkb25hdGUga2UgY2FtcGFpZ24gdGVydGVudHUAAAAAAAxnZXRfZG9uYXRpb24AAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
-            "AAAAAAAAABhHZXQgY2FtcGFpZ24gZ29hbCBhbW91bnQAAAAIZ2V0X2dvYWwAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAs=",
-            "AAAAAAAAAB9HZXQgY2FtcGFpZ24gZGVhZGxpbmUgdGltZXN0YW1wAAAAAAxnZXRfZGVhZGxpbmUAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAY=",
-            "AAAAAAAAACZDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggcmVhY2ggZ29hbAAAAAAAD2lzX2dvYWxfcmVhY2hlZAAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAE=",
-            "AAAAAAAAADZDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggYmVyYWtoaXIgKGRlYWRsaW5lIHBhc3NlZCkAAAAAAAhpc19lbmRlZAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAQ==",
-            "AAAAAAAAACtDYWxjdWxhdGUgcHJvZ3Jlc3MgcGVyY2VudGFnZSBkYXJpIGNhbXBhaWduAAAAABdnZXRfcHJvZ3Jlc3NfcGVyY2VudGFnZQAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAs="]), options);
-        this.options = options;
+            "AAAAAAAAADdMYW5na2FoIDIgdHJhbnNmZXIgYWRtaW46IGNhbG9uIGFkbWluIG1lbmVyaW1hIHBlcmFubnlhAAAAAAxhY2NlcHRfYWRtaW4AAAAAAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAADRHZXQgZGF0YSBsZW5na2FwIGRhcmkgc2F0dSBjYW1wYWlnbiAodW50dWsgZnJvbnRlbmQpAAAADGdldF9jYW1wYWlnbgAAAAEAAAAAAAAAAmlkAAAAAAAGAAAAAQAAA+kAAAfQAAAACENhbXBhaWduAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAB9HZXQgY2FtcGFpZ24gZGVhZGxpbmUgdGltZXN0YW1wAAAAAAxnZXRfZGVhZGxpbmUAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAAGAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAEJHZXQgYmVyYXBhIGJhbnlhayBzcGVjaWZpYyBkb25vciBzdWRhaCBkb25hdGUga2UgY2FtcGFpZ24gdGVydGVudHUAAAAAAAxnZXRfZG9uYXRpb24AAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAD6QAAAAsAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+            "AAAAAAAAAEBHZXQgbWV0YWRhdGEgdGFtcGlsYW4gY2FtcGFpZ24gKGp1ZHVsLCBkZXNrcmlwc2ksIFVSSSwga2F0ZWdvcmkpAAAADGdldF9tZXRhZGF0YQAAAAEAAAAAAAAAAmlkAAAAAAAGAAAAAQAAA+kAAAfQAAAAEENhbXBhaWduTWV0YWRhdGEAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+            "AAAAAAAAACxHZXQgcHJvcG9zYWwgbWlsZXN0b25lIHlhbmcgc2VkYW5nIGRpLXZvdGluZwAAAAxnZXRfcHJvcG9zYWwAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAfQAAAACFByb3Bvc2FsAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAACxDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggZGliYXRhbGthbiBvd25lcgAAAAxpc19jYW5jZWxsZWQAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAABAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAMtDb25zdHJ1Y3Rvcjogc2V0IGFkbWluICYgZmVlIHBsYXRmb3JtIHNhYXQgZGVwbG95LiBEaWphbGFua2FuIGF0b21pawpiZXJzYW1hIGRlcGxveSwgamFkaSB0aWRhayBhZGEgY2VsYWggdW50dWsgbWVyZWJ1dCBwZXJhbiBhZG1pbi4KYG1heF9mZWVfYnBzYCBoYXJ1cyBkaSBiYXdhaCAxMDAlIHN1cGF5YSBvd25lciBzZWxhbHUgbWVuZXJpbWEgcGF5b3V0LgAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAQAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIdHJlYXN1cnkAAAATAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAAAAAAC21heF9mZWVfYnBzAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAElHZXQgaW5kZXggcmV3YXJkIHRpZXIgeWFuZyBkaXBpbGloIGRvbmF0dXIgKE5vbmUgamlrYSB0aWRhayBtZW1pbGloIHRpZXIpAAAAAAAADmdldF9kb25vcl90aWVyAAAAAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAD6QAAA+gAAAAEAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAClHZXQga29uZmlndXJhc2kgZmVlIHlhbmcgYmVybGFrdSBzYWF0IGluaQAAAAAAAA5nZXRfZmVlX2NvbmZpZwAAAAAAAAAAAAEAAAPpAAAH0AAAAAlGZWVDb25maWcAAAAAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+            "AAAAAAAAAIhEYWZ0YXIgY2FtcGFpZ24gYmVyZGFzYXJrYW4gdXJ1dGFuIElELCBtdWxhaSBkYXJpIElEIGBzdGFydGAuCklEIHlhbmcgYmVsdW0gZGlwaW5kYWggZGFyaSBtYXAgQ0FNUEFJR05TIGxhbWEgKGxpaGF0IGBtaWdyYXRlYCkgZGlsZXdhdGkuAAAADmxpc3RfY2FtcGFpZ25zAAAAAAACAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPpAAAD6gAAB9AAAAAPQ2FtcGFpZ25TdW1tYXJ5AAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAAHpMYW5na2FoIDEgdHJhbnNmZXIgYWRtaW46IGFkbWluIG1lbnVuanVrIGNhbG9uIGFkbWluIGJhcnUuCkFkbWluIGxhbWEgdGV0YXAgYmVybGFrdSBzYW1wYWkgY2Fsb24gbWVtYW5nZ2lsIGBhY2NlcHRfYWRtaW5gLgAAAAAADnRyYW5zZmVyX2FkbWluAAAAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAAKpPd25lciBtZW1iYXRhbGthbiBjYW1wYWlnbiB5YW5nIG1hc2loIGJlcmphbGFuLgpEb25hc2kgYmFydSBsYW5nc3VuZyBkaXRvbGFrIGRhbiBzZW11YSBkb25hdHVyIGJpc2EgYHJlZnVuZGAKdGFucGEgbWVudW5nZ3UgZGVhZGxpbmUsIGJlcmFwYXB1biBkYW5hIHlhbmcgc3VkYWggdGVya3VtcHVsLgAAAAAAD2NhbmNlbF9jYW1wYWlnbgAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAAGpNZW1idWF0IGNhbXBhaWduIGJhcnUuClNpYXBhcHVuIGJpc2EgbWVtYW5nZ2lsIGluaS4KTWVuZ2VtYmFsaWthbiBJRCAodTY0KSBkYXJpIGNhbXBhaWduIHlhbmcgYmFydSBkaWJ1YXQuAAAAAAAPY3JlYXRlX2NhbXBhaWduAAAAAAgAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAEZ29hbAAAAAsAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZjb25maWcAAAAAB9AAAAAOQ2FtcGFpZ25Db25maWcAAAAAAAAAAAAIbWV0YWRhdGEAAAfQAAAAEENhbXBhaWduTWV0YWRhdGEAAAAAAAAACm1pbGVzdG9uZXMAAAAAA+gAAAfQAAAADU1pbGVzdG9uZVBsYW4AAAAAAAAAAAAABXRpZXJzAAAAAAAD6gAAB9AAAAAKUmV3YXJkVGllcgAAAAAAAQAAA+kAAAAGAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAP9Pd25lciBtZW1wZXJwYW5qYW5nIGRlYWRsaW5lIGNhbXBhaWduIHlhbmcgbWFzaWggQWN0aXZlLgpUb3RhbCBwZXJwYW5qYW5nYW4gZGliYXRhc2kgYG1heF9leHRlbnNpb25gLCBkYW4gamlrYSBgb2JqZWN0aW9uX2Jwc2AKZGktc2V0LCBkaWJsb2tpciBzYWF0IHBlcnNlbnRhc2UgZG9uYXR1ciB5YW5nIGtlYmVyYXRhbiBtZW5jYXBhaSBiYXRhcwp0ZXJzZWJ1dCAoZGloaXR1bmcgcGVyIGRvbmF0dXIsIGJ1a2FuIHBlciBqdW1sYWggZG9uYXNpKS4AAAAAD2V4dGVuZF9kZWFkbGluZQAAAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAxuZXdfZGVhZGxpbmUAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAAEtHZXQgcGVydWJhaGFuIGZlZSB5YW5nIGRpamFkd2Fsa2FuIChOb25lIGppa2EgdGlkYWsgYWRhIGF0YXUgc3VkYWggYmVybGFrdSkAAAAAD2dldF9wZW5kaW5nX2ZlZQAAAAAAAAAAAQAAA+gAAAfQAAAAClBlbmRpbmdGZWUAAA==",
+            "AAAAAAAAACZDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggcmVhY2ggZ29hbAAAAAAAD2lzX2dvYWxfcmVhY2hlZAAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAABAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAKJPd25lciBtZW5ndWJhaCBtZXRhZGF0YSBjYW1wYWlnbi4KSGFueWEgYmlzYSBzZWxhbWEgYmVsdW0gYWRhIGRvbmFzaSBkYW4gY2FtcGFpZ24gYmVsdW0gYmVyYWtoaXIsCnN1cGF5YSBkb25hdHVyIHRpZGFrICJ0ZXJ0aXB1IiBvbGVoIHBlcnViYWhhbiBzZXRlbGFoIGJlcmRvbmFzaS4AAAAAAA91cGRhdGVfbWV0YWRhdGEAAAAAAgAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAAAAAAIbWV0YWRhdGEAAAfQAAAAEENhbXBhaWduTWV0YWRhdGEAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAIFEb25hdHVyIG1lbmFyaWsgc2ViYWdpYW4gLyBzZWx1cnVoIGRvbmFzaW55YSBzZWxhbWEgY2FtcGFpZ24gQWN0aXZlCihqaWthIGRpaXppbmthbiBjYW1wYWlnbikuIE1lbmdlbWJhbGlrYW4gc2lzYSBkb25hc2kgZG9uYXR1ci4AAAAAAAAPd2l0aGRyYXdfcGxlZGdlAAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAALAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAD1HZXQgdG90YWwgYW1vdW50IHlhbmcgc3VkYWggdGVya3VtcHVsIHVudHVrIGNhbXBhaWduIHRlcnRlbnR1AAAAAAAAEGdldF90b3RhbF9yYWlzZWQAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAALAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAHBEb25hdHVyIG1lbnlhdGFrYW4ga2ViZXJhdGFuIGF0YXMgcGVycGFuamFuZ2FuIGRlYWRsaW5lLgpTZXRpYXAgZG9uYXR1ciBkaWhpdHVuZyBzYXR1IGthbGksIGJlcmFwYXB1biBkb25hc2lueWEuAAAAEG9iamVjdF9leHRlbnNpb24AAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAIZBcHByb3ZlciBtZW5vbGFrIG1pbGVzdG9uZSBiZXJpa3V0bnlhLiBDYW1wYWlnbiBtZW5qYWRpIEZhaWxlZCBkYW4KZG9uYXR1ciBiaXNhIGByZWZ1bmRgIGJhZ2lhbiBwcm8gcmF0YSBkYXJpIGRhbmEgeWFuZyBiZWx1bSBkaXJpbGlzLgAAAAAAEHJlamVjdF9taWxlc3RvbmUAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAANBBcHByb3ZlciBtZW55ZXR1anVpIG1pbGVzdG9uZSBiZXJpa3V0bnlhIGRhbiBtZXJpbGlzIGJhZ2lhbm55YSBrZSBvd25lci4KSGFueWEgdW50dWsgY2FtcGFpZ24gYmVyLW1pbGVzdG9uZSB5YW5nIHN1ZGFoIFN1Y2NlZWRlZCwgc2ViZWx1bSBkdWUgZGF0ZQptaWxlc3RvbmUgdGVyc2VidXQuIE1lbmdlbWJhbGlrYW4ganVtbGFoIHlhbmcgZGl0ZXJpbWEgb3duZXIuAAAAEWFwcHJvdmVfbWlsZXN0b25lAAAAAAAAAQAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAPpAAAACwAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAAP1NZW51dHVwIHByb3Bvc2FsIHNldGVsYWggcGVyaW9kZSB2b3RpbmcgYmVyYWtoaXIgKHNpYXBhcHVuIGJpc2EgbWVtYW5nZ2lsKS4KSmlrYSBxdW9ydW0gJiB0aHJlc2hvbGQgdGVycGVudWhpIG1pbGVzdG9uZSBkaXJpbGlzIGtlIG93bmVyLCBqaWthIHRpZGFrCmNhbXBhaWduIG1lbmphZGkgRmFpbGVkIGRhbiBkb25hdHVyIGJpc2EgcmVmdW5kIHNpc2EgZGFuYS4KTWVuZ2VtYmFsaWthbiBgdHJ1ZWAgamlrYSBwcm9wb3NhbCBkaXNldHVqdWkuAAAAAAAAEWZpbmFsaXplX3Byb3Bvc2FsAAAAAAAAAQAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAPpAAAAAQAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAADlHZXQgY2Fsb24gYWRtaW4geWFuZyBiZWx1bSBtZW5lcmltYSAoTm9uZSBqaWthIHRpZGFrIGFkYSkAAAAAAAARZ2V0X3BlbmRpbmdfYWRtaW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+            "AAAAAAAAAJlPd25lciBtZW5nYWp1a2FuIHBlbmNhaXJhbiBtaWxlc3RvbmUgYmVyaWt1dG55YSB1bnR1ayBkaS12b3RpbmcgZG9uYXR1ci4KSGFydXMgZGlhanVrYW4gc2ViZWx1bSBkdWUgZGF0ZSBtaWxlc3RvbmUuIE1lbmdlbWJhbGlrYW4gdGltZXN0YW1wIGFraGlyIHZvdGluZy4AAAAAAAARcHJvcG9zZV9taWxlc3RvbmUAAAAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAAGAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAIlHZXQgZ29hbCAmIHJhaXNlZCBjYW1wYWlnbiBkYWxhbSBzYXR1YW4gdG9rZW4sIG1lbWFrYWkgYGRlY2ltYWxzKClgCmRhcmkga29udHJhayB0b2tlbiBjYW1wYWlnbiAobWlzLiAyNV8wMDBfMDAwIEAgNyBkZXNpbWFsID0gMi41IHRva2VuKQAAAAAAABJnZXRfY2FtcGFpZ25fdW5pdHMAAAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAB9AAAAANQ2FtcGFpZ25Vbml0cwAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAAGBHZXQgdmVyc2kgbGF5b3V0IHN0b3JhZ2UuIEtvbnRyYWsgbGFtYSB0YW5wYSB2ZXJzaSB0ZXJzaW1wYW4gPSB2ZXJzaSAxCnNhbXBhaSBgbWlncmF0ZWAgc2VsZXNhaS4AAAASZ2V0X3NjaGVtYV92ZXJzaW9uAAAAAAAAAAAAAQAAAAQ=",
+            "AAAAAAAAAOBEYWZ0YXIgY2FtcGFpZ24geWFuZyBtYXNpaCBtZW5lcmltYSBkb25hc2ksIG11bGFpIGRhcmkgSUQgYHN0YXJ0YC4KQmVyaGVudGkgc2V0ZWxhaCBgbGltaXRgIGNhbXBhaWduIGFrdGlmIGF0YXUgTUFYX1NDQU4gSUQgZGlwZXJpa3NhLApqYWRpIGhhbGFtYW4gYmlzYSBrdXJhbmcgZGFyaSBgbGltaXRgOyBsYW5qdXRrYW4gZGFyaSBgbmV4dGAKc2FtcGFpIGBuZXh0YCBiZXJuaWxhaSBOb25lLgAAABVsaXN0X2FjdGl2ZV9jYW1wYWlnbnMAAAAAAAACAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAfQAAAACkFjdGl2ZVBhZ2UAAA==",
+            "AAAAAAAAACtDYWxjdWxhdGUgcHJvZ3Jlc3MgcGVyY2VudGFnZSBkYXJpIGNhbXBhaWduAAAAABdnZXRfcHJvZ3Jlc3NfcGVyY2VudGFnZQAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAALAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAAHZEYWZ0YXIgY2FtcGFpZ24gbWlsaWsgYG93bmVyYC4gYHN0YXJ0YCBhZGFsYWggcG9zaXNpIGRpIGluZGV4IG93bmVyCigwID0gY2FtcGFpZ24gcGVydGFtYSB5YW5nIGRpYnVhdCBvd25lciB0ZXJzZWJ1dCkuAAAAAAAXbGlzdF9jYW1wYWlnbnNfYnlfb3duZXIAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPpAAAD6gAAB9AAAAAPQ2FtcGFpZ25TdW1tYXJ5AAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+            "AAAAAAAAAFBEYWZ0YXIgY2FtcGFpZ24geWFuZyBtZW5ndW1wdWxrYW4gYHRva2VuYC4gYHN0YXJ0YCBhZGFsYWggcG9zaXNpIGRpIGluZGV4IHRva2VuLgAAABdsaXN0X2NhbXBhaWduc19ieV90b2tlbgAAAAADAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+kAAAPqAAAH0AAAAA9DYW1wYWlnblN1bW1hcnkAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+            "AAAAAAAAADRHZXQganVtbGFoIGNhbXBhaWduIG1pbGlrIGBvd25lcmAgKHVudHVrIHBhZ2luYXRpb24pAAAAGGdldF9vd25lcl9jYW1wYWlnbl9jb3VudAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAE",
+            "AAAAAAAAAEBHZXQganVtbGFoIGNhbXBhaWduIHlhbmcgbWVuZ3VtcHVsa2FuIGB0b2tlbmAgKHVudHVrIHBhZ2luYXRpb24pAAAAGGdldF90b2tlbl9jYW1wYWlnbl9jb3VudAAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAE"
+        ]), options), this.options = options, this.fromJSON = {
+            vote: this.txFromJSON,
+            pause: this.txFromJSON,
+            donate: this.txFromJSON,
+            refund: this.txFromJSON,
+            migrate: this.txFromJSON,
+            set_fee: this.txFromJSON,
+            unpause: this.txFromJSON,
+            upgrade: this.txFromJSON,
+            get_goal: this.txFromJSON,
+            get_vote: this.txFromJSON,
+            is_ended: this.txFromJSON,
+            withdraw: this.txFromJSON,
+            get_admin: this.txFromJSON,
+            get_tiers: this.txFromJSON,
+            is_paused: this.txFromJSON,
+            get_escrow: this.txFromJSON,
+            get_status: this.txFromJSON,
+            is_claimed: this.txFromJSON,
+            get_next_id: this.txFromJSON,
+            accept_admin: this.txFromJSON,
+            get_campaign: this.txFromJSON,
+            get_deadline: this.txFromJSON,
+            get_donation: this.txFromJSON,
+            get_metadata: this.txFromJSON,
+            get_proposal: this.txFromJSON,
+            is_cancelled: this.txFromJSON,
+            get_donor_tier: this.txFromJSON,
+            get_fee_config: this.txFromJSON,
+            list_campaigns: this.txFromJSON,
+            transfer_admin: this.txFromJSON,
+            cancel_campaign: this.txFromJSON,
+            create_campaign: this.txFromJSON,
+            extend_deadline: this.txFromJSON,
+            get_pending_fee: this.txFromJSON,
+            is_goal_reached: this.txFromJSON,
+            update_metadata: this.txFromJSON,
+            withdraw_pledge: this.txFromJSON,
+            get_total_raised: this.txFromJSON,
+            object_extension: this.txFromJSON,
+            reject_milestone: this.txFromJSON,
+            approve_milestone: this.txFromJSON,
+            finalize_proposal: this.txFromJSON,
+            get_pending_admin: this.txFromJSON,
+            propose_milestone: this.txFromJSON,
+            get_campaign_units: this.txFromJSON,
+            get_schema_version: this.txFromJSON,
+            list_active_campaigns: this.txFromJSON,
+            get_progress_percentage: this.txFromJSON,
+            list_campaigns_by_owner: this.txFromJSON,
+            list_campaigns_by_token: this.txFromJSON,
+            get_owner_campaign_count: this.txFromJSON,
+            get_token_campaign_count: this.txFromJSON
+        };
     }
-    fromJSON = {
-        create_campaign: (this.txFromJSON),
-        donate: (this.txFromJSON),
-        refund: (this.txFromJSON),
-        get_campaign: (this.txFromJSON),
-        get_next_id: (this.txFromJSON),
-        get_total_raised: (this.txFromJSON),
-        get_donation: (this.txFromJSON),
-        get_goal: (this.txFromJSON),
-        get_deadline: (this.txFromJSON),
-        is_goal_reached: (this.txFromJSON),
-        is_ended: (this.txFromJSON),
-        get_progress_percentage: (this.txFromJSON)
-    };
+    fromJSON;
 }
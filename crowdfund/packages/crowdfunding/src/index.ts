@@ -39,7 +39,240 @@ export const networks = {
 } as const
 
 
+
+/**
+ * State escrow campaign ber-milestone
+ */
+export interface Escrow {
+  approval: Approval;
+  milestones: Array<Milestone>;
+  next: u32;
+}
+
+
+export type DataKey = {tag: "Campaign", values: readonly [u64]} | {tag: "Donation", values: readonly [u64, string]} | {tag: "Metadata", values: readonly [u64]} | {tag: "Escrow", values: readonly [u64]} | {tag: "Proposal", values: readonly [u64]} | {tag: "Tiers", values: readonly [u64]} | {tag: "TierClaimed", values: readonly [u64, u32]} | {tag: "DonorTier", values: readonly [u64, string]} | {tag: "Objection", values: readonly [u64, string]} | {tag: "Vote", values: readonly [u64, u32, string]} | {tag: "OwnerCount", values: readonly [string]} | {tag: "OwnerCampaign", values: readonly [string, u32]} | {tag: "TokenCount", values: readonly [string]} | {tag: "TokenCampaign", values: readonly [string, u32]};
+
+
+/**
+ * Siapa yang memutuskan setiap milestone
+ */
+export type Approval = {tag: "Approver", values: readonly [string]} | {tag: "DonorVote", values: readonly [VotingConfig]};
+
+
 export interface Campaign {
+  deadline: u64;
+  donors: u32;
+  extended: u64;
+  fee_bps: u32;
+  fee_paid: i128;
+  funding_mode: FundingMode;
+  goal: i128;
+  hard_cap: Option<i128>;
+  lock_on_goal: boolean;
+  lock_window: u64;
+  max_extension: u64;
+  max_per_donor: Option<i128>;
+  min_donation: Option<i128>;
+  objection_bps: Option<u32>;
+  objections: u32;
+  over_limit: OverLimitPolicy;
+  owner: string;
+  pledge_withdrawal: boolean;
+  raised: i128;
+  released: i128;
+  start_time: Option<u64>;
+  status: CampaignStatus;
+  token: string;
+}
+
+/**
+ * Kategori campaign, dipakai frontend untuk filter
+ */
+export enum Category {
+  Technology = 0,
+  Art = 1,
+  Community = 2,
+  Charity = 3,
+  Education = 4,
+  Health = 5,
+  Environment = 6,
+  Other = 7,
+}
+
+
+/**
+ * Proposal pencairan milestone yang sedang di-voting
+ */
+export interface Proposal {
+  milestone: u32;
+  votes_against: i128;
+  votes_for: i128;
+  voting_end: u64;
+}
+
+
+/**
+ * Reward tier beserta sisa slot (hasil `get_tiers`)
+ */
+export interface TierInfo {
+  description: string;
+  min_amount: i128;
+  quantity: u32;
+  remaining: u32;
+}
+
+
+
+
+/**
+ * Konfigurasi fee platform (diset lewat constructor saat deploy)
+ */
+export interface FeeConfig {
+  fee_bps: u32;
+  max_fee_bps: u32;
+  treasury: string;
+}
+
+
+/**
+ * Satu tahap pencairan dana
+ */
+export interface Milestone {
+  description: string;
+  due_date: u64;
+  percentage: u32;
+}
+
+
+
+/**
+ * Satu halaman hasil `list_active_campaigns`
+ */
+export interface ActivePage {
+  campaigns: Array<CampaignSummary>;
+  next: Option<u64>;
+}
+
+
+/**
+ * Perubahan fee yang menunggu timelock
+ */
+export interface PendingFee {
+  effective_at: u64;
+  fee_bps: u32;
+}
+
+
+/**
+ * Reward untuk donatur dengan donasi minimal tertentu
+ */
+export interface RewardTier {
+  description: string;
+  min_amount: i128;
+  quantity: u32;
+}
+
+
+/**
+ * Jumlah token dalam satuan "manusia": `whole` + `fraction` / 10^decimals
+ */
+export interface UnitAmount {
+  fraction: i128;
+  whole: i128;
+}
+
+
+/**
+ * Apa yang terjadi dengan dana jika goal tidak tercapai saat deadline
+ */
+export enum FundingMode {
+  AllOrNothing = 0,
+  KeepItAll = 1,
+}
+
+
+
+/**
+ * Parameter voting donatur. Bobot suara = jumlah donasi.
+ */
+export interface VotingConfig {
+  quorum_bps: u32;
+  threshold_bps: u32;
+  voting_period: u64;
+}
+
+
+
+
+/**
+ * Goal & raised campaign dalam satuan token (hasil `get_campaign_units`)
+ */
+export interface CampaignUnits {
+  decimals: u32;
+  goal: UnitAmount;
+  raised: UnitAmount;
+}
+
+
+/**
+ * Rencana milestone saat create_campaign
+ */
+export interface MilestonePlan {
+  approval: Approval;
+  milestones: Array<Milestone>;
+}
+
+
+/**
+ * Opsi campaign yang dipilih saat create_campaign
+ */
+export interface CampaignConfig {
+  funding_mode: FundingMode;
+  hard_cap: Option<i128>;
+  lock_on_goal: boolean;
+  lock_window: u64;
+  max_extension: u64;
+  max_per_donor: Option<i128>;
+  min_donation: Option<i128>;
+  objection_bps: Option<u32>;
+  over_limit: OverLimitPolicy;
+  pledge_withdrawal: boolean;
+  start_time: Option<u64>;
+}
+
+/**
+ * Status campaign. Transisi yang diizinkan:
+ * 
+ * | Dari      | Ke        | Pemicu                                  |
+ * |-----------|-----------|-----------------------------------------|
+ * | Pending   | Active    | `now >= start_time`                     |
+ * | Pending   | Cancelled | `cancel_campaign`                       |
+ * | Active    | Succeeded | `now >= deadline` dan dana cukup (*)    |
+ * | Active    | Failed    | `now >= deadline` dan dana tidak cukup (*) |
+ * | Active    | Succeeded | hard cap tercapai (sebelum deadline)    |
+ * | Active    | Cancelled | `cancel_campaign`                       |
+ * | Succeeded | Claimed   | `withdraw` / milestone terakhir dirilis |
+ * | Succeeded | Failed    | milestone ditolak atau lewat due date   |
+ * 
+ * (*) AllOrNothing: goal tercapai. KeepItAll: ada dana terkumpul (> 0).
+ * 
+ * Failed, Cancelled & Claimed adalah status akhir dan disimpan; status
+ * lain dihitung dari waktu ledger & dana.
+ */
+export enum CampaignStatus {
+  Pending = 0,
+  Active = 1,
+  Succeeded = 2,
+  Failed = 3,
+  Cancelled = 4,
+  Claimed = 5,
+}
+
+
+/**
+ * (Legacy) Bentuk campaign di schema versi 1, termasuk map donasinya
+ */
+export interface LegacyCampaign {
   deadline: u64;
   donations: Map<string, i128>;
   goal: i128;
@@ -48,14 +281,266 @@ export interface Campaign {
   token: string;
 }
 
+
+/**
+ * Ringkasan campaign untuk halaman daftar (hasil `list_*`)
+ */
+export interface CampaignSummary {
+  category: Category;
+  deadline: u64;
+  goal: i128;
+  id: u64;
+  owner: string;
+  raised: i128;
+  status: CampaignStatus;
+  title: string;
+  token: string;
+}
+
+/**
+ * Perlakuan donasi yang melewati hard cap / batas per donatur
+ */
+export enum OverLimitPolicy {
+  Reject = 0,
+  PartialAccept = 1,
+}
+
+
+
+
+
+
+/**
+ * Metadata tampilan campaign. Disimpan terpisah dari `Campaign` supaya
+ * `donate`/`refund` tidak perlu membaca teks yang panjang.
+ */
+export interface CampaignMetadata {
+  category: Category;
+  description: string;
+  metadata_uri: string;
+  title: string;
+}
+
+
+/**
+ * Kode error kontrak. Nomor dipakai oleh binding TS dan kontrak pemanggil,
+ * jadi nomor yang sudah ada tidak boleh diubah.
+ */
+export const CrowdfundingError = {
+  /**
+   * Goal campaign harus lebih dari 0
+   */
+  1: {message:"GoalNotPositive"},
+  /**
+   * Deadline harus di masa depan
+   */
+  2: {message:"DeadlineInPast"},
+  /**
+   * Jumlah donasi harus lebih dari 0
+   */
+  3: {message:"AmountNotPositive"},
+  /**
+   * Campaign dengan ID tersebut tidak ada
+   */
+  4: {message:"CampaignNotFound"},
+  /**
+   * Campaign sudah berakhir, tidak menerima donasi
+   */
+  5: {message:"CampaignEnded"},
+  /**
+   * Campaign belum berakhir
+   */
+  6: {message:"CampaignNotEnded"},
+  /**
+   * Goal sudah tercapai, tidak bisa refund
+   */
+  7: {message:"GoalReached"},
+  /**
+   * Goal belum tercapai, owner tidak bisa menarik dana
+   */
+  8: {message:"GoalNotReached"},
+  /**
+   * Dana campaign sudah ditarik owner
+   */
+  9: {message:"AlreadyClaimed"},
+  /**
+   * Donatur tidak ditemukan atau sudah refund
+   */
+  10: {message:"NothingToRefund"},
+  /**
+   * Judul kosong atau terlalu panjang
+   */
+  11: {message:"InvalidTitle"},
+  /**
+   * Deskripsi terlalu panjang
+   */
+  12: {message:"DescriptionTooLong"},
+  /**
+   * URI metadata terlalu panjang atau bukan ipfs:// / https://
+   */
+  13: {message:"InvalidMetadataUri"},
+  /**
+   * Metadata tidak bisa diubah lagi (sudah ada donasi / campaign berakhir)
+   */
+  14: {message:"MetadataLocked"},
+  /**
+   * Campaign sudah dibatalkan owner
+   */
+  15: {message:"CampaignCancelled"},
+  /**
+   * Daftar milestone tidak valid (kosong, persentase tidak 100, due date tidak urut)
+   */
+  16: {message:"InvalidMilestones"},
+  /**
+   * Dana campaign ber-milestone hanya bisa dirilis lewat approve_milestone
+   */
+  17: {message:"FundsInEscrow"},
+  /**
+   * Campaign tidak punya milestone
+   */
+  18: {message:"NoMilestones"},
+  /**
+   * Tidak ada milestone yang bisa diproses (ditolak, terlewat, atau sudah dirilis semua)
+   */
+  19: {message:"MilestoneClosed"},
+  /**
+   * Milestone campaign ini diputuskan dengan cara lain (approver vs voting donatur)
+   */
+  20: {message:"WrongApprovalMode"},
+  /**
+   * Quorum / threshold / periode voting tidak valid
+   */
+  21: {message:"InvalidVotingConfig"},
+  /**
+   * Masih ada proposal yang belum difinalisasi
+   */
+  22: {message:"ProposalOpen"},
+  /**
+   * Tidak ada proposal yang sedang berjalan
+   */
+  23: {message:"NoProposal"},
+  /**
+   * Periode voting sudah berakhir
+   */
+  24: {message:"VotingClosed"},
+  /**
+   * Periode voting belum berakhir
+   */
+  25: {message:"VotingNotEnded"},
+  /**
+   * Donatur sudah memberikan suara untuk proposal ini
+   */
+  26: {message:"AlreadyVoted"},
+  /**
+   * Hanya donatur campaign yang bisa voting
+   */
+  27: {message:"NotDonor"},
+  /**
+   * Daftar reward tier tidak valid
+   */
+  28: {message:"InvalidTiers"},
+  /**
+   * Reward tier dengan index tersebut tidak ada
+   */
+  29: {message:"TierNotFound"},
+  /**
+   * Total donasi donatur di bawah minimum tier
+   */
+  30: {message:"TierBelowMinimum"},
+  /**
+   * Kuota reward tier sudah habis
+   */
+  31: {message:"TierSoldOut"},
+  /**
+   * Campaign keep-it-all: dana yang terkumpul tetap untuk owner, tidak bisa refund
+   */
+  32: {message:"FundsKept"},
+  /**
+   * Hard cap / batas donasi tidak valid
+   */
+  33: {message:"InvalidLimits"},
+  /**
+   * Donasi di bawah minimum donasi campaign
+   */
+  34: {message:"BelowMinDonation"},
+  /**
+   * Total donasi donatur melebihi batas per donatur
+   */
+  35: {message:"DonorLimitExceeded"},
+  /**
+   * Donasi melebihi hard cap campaign
+   */
+  36: {message:"HardCapExceeded"},
+  /**
+   * Waktu mulai harus sebelum deadline
+   */
+  37: {message:"InvalidStartTime"},
+  /**
+   * Campaign belum dibuka (sebelum start_time)
+   */
+  38: {message:"CampaignNotStarted"},
+  /**
+   * Perpanjangan deadline tidak valid (konfigurasi, deadline baru, atau melewati batas)
+   */
+  39: {message:"InvalidExtension"},
+  /**
+   * Perpanjangan ditolak karena keberatan donatur mencapai batas
+   */
+  40: {message:"ExtensionObjected"},
+  /**
+   * Donatur sudah menyatakan keberatan
+   */
+  41: {message:"AlreadyObjected"},
+  /**
+   * Campaign tidak mengizinkan penarikan donasi sebelum deadline
+   */
+  42: {message:"PledgeWithdrawalDisabled"},
+  /**
+   * Penarikan donasi dikunci (goal tercapai / mendekati deadline)
+   */
+  43: {message:"PledgeLocked"},
+  /**
+   * Jumlah penarikan melebihi donasi donatur
+   */
+  44: {message:"AmountExceedsDonation"},
+  /**
+   * (Tidak dipakai lagi sejak constructor) Platform sudah diinisialisasi
+   */
+  45: {message:"AlreadyInitialized"},
+  /**
+   * Fee melebihi batas maksimum
+   */
+  46: {message:"InvalidFee"},
+  /**
+   * Konfigurasi platform belum diinisialisasi
+   */
+  47: {message:"NotInitialized"},
+  /**
+   * Kontrak sedang di-pause oleh admin
+   */
+  48: {message:"ContractPaused"},
+  /**
+   * Tidak ada transfer admin yang menunggu
+   */
+  49: {message:"NoPendingAdmin"},
+  /**
+   * Transfer token gagal (mis. saldo donatur kurang)
+   */
+  50: {message:"TokenTransferFailed"}
+}
+
+
+
+
+
+
 export interface Client {
   /**
-   * Construct and simulate a create_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Membuat campaign baru.
-   * Siapapun bisa memanggil ini.
-   * Mengembalikan ID (u64) dari campaign yang baru dibuat.
+   * Construct and simulate a vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Donatur memberikan suara untuk proposal yang sedang berjalan.
+   * Bobot suara = total donasi donatur ke campaign ini.
    */
-  create_campaign: ({owner, goal, deadline, token}: {owner: string, goal: i128, deadline: u64, token: string}, options?: {
+  vote: ({campaign_id, donor, approve}: {campaign_id: u64, donor: string, approve: boolean}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -70,13 +555,38 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin menghentikan sementara `create_campaign` & `donate`.
+   * Refund, withdraw_pledge & payout tetap berjalan.
+   */
+  pause: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a donate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Donasi ke campaign tertentu menggunakan token transfer
+   * Donasi ke campaign tertentu menggunakan token transfer.
+   * `tier` opsional: index reward tier yang dipilih donatur.
+   * Mengembalikan jumlah yang diterima (bisa lebih kecil dari `amount`
+   * jika campaign memakai OverLimitPolicy::PartialAccept).
    */
-  donate: ({campaign_id, donor, amount}: {campaign_id: u64, donor: string, amount: i128}, options?: {
+  donate: ({campaign_id, donor, amount, tier}: {campaign_id: u64, donor: string, amount: i128, tier: Option<u32>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -91,11 +601,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
    * Construct and simulate a refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Refund mechanism jika campaign gagal (hanya bisa dipanggil oleh donatur)
+   * Refund mechanism jika campaign gagal atau dibatalkan owner
+   * (hanya bisa dipanggil oleh donatur)
    */
   refund: ({campaign_id, donor}: {campaign_id: u64, donor: string}, options?: {
     /**
@@ -112,13 +623,16 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a get_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get data lengkap dari satu campaign (untuk frontend)
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Memindahkan maksimal `limit` campaign dari map CAMPAIGNS (schema 1)
+   * ke key per campaign. Boleh dipanggil siapa saja karena data tidak
+   * berubah; panggil berulang sampai hasilnya 0. Mengembalikan jumlah
+   * campaign lama yang belum dipindah.
    */
-  get_campaign: ({id}: {id: u64}, options?: {
+  migrate: ({limit}: {limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -133,13 +647,15 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Campaign>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a get_next_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get ID (u64) untuk campaign berikutnya
+   * Construct and simulate a set_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin menjadwalkan fee baru (maks `max_fee_bps`). Berlaku untuk
+   * campaign yang dibuat setelah timelock FEE_TIMELOCK lewat.
+   * Mengembalikan timestamp fee baru mulai berlaku.
    */
-  get_next_id: (options?: {
+  set_fee: ({fee_bps}: {fee_bps: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -154,13 +670,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
-   * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get total amount yang sudah terkumpul untuk campaign tertentu
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin membuka kembali kontrak
    */
-  get_total_raised: ({campaign_id}: {campaign_id: u64}, options?: {
+  unpause: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -175,13 +691,15 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_donation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get berapa banyak specific donor sudah donate ke campaign tertentu
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin mengganti kode kontrak dengan WASM yang sudah di-upload.
+   * Admin ditetapkan oleh constructor saat deploy, jadi tidak bisa direbut.
+   * Storage tetap; jalankan `migrate` jika versi baru mengubah layout.
    */
-  get_donation: ({campaign_id, donor}: {campaign_id: u64, donor: string}, options?: {
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -196,7 +714,7 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a get_goal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -217,13 +735,14 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a get_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get campaign deadline timestamp
+   * Construct and simulate a get_vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah donatur sudah voting untuk milestone tertentu.
+   * `None` jika belum voting, `Some(true/false)` sesuai pilihannya.
    */
-  get_deadline: ({campaign_id}: {campaign_id: u64}, options?: {
+  get_vote: ({campaign_id, milestone, donor}: {campaign_id: u64, milestone: u32, donor: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -238,13 +757,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Option<boolean>>>
 
   /**
-   * Construct and simulate a is_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Check apakah campaign sudah reach goal
+   * Construct and simulate a is_ended transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah campaign sudah berakhir (deadline passed)
    */
-  is_goal_reached: ({campaign_id}: {campaign_id: u64}, options?: {
+  is_ended: ({campaign_id}: {campaign_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -259,13 +778,16 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<Result<boolean>>>
 
   /**
-   * Construct and simulate a is_ended transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Check apakah campaign sudah berakhir (deadline passed)
+   * Construct and simulate a withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner menarik dana campaign yang berhasil (deadline lewat & goal tercapai,
+   * atau ada dana terkumpul untuk mode KeepItAll).
+   * Hanya bisa dilakukan sekali. Mengembalikan jumlah yang diterima owner
+   * (setelah dipotong fee platform).
    */
-  is_ended: ({campaign_id}: {campaign_id: u64}, options?: {
+  withdraw: ({campaign_id}: {campaign_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -280,13 +802,858 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
-   * Construct and simulate a get_progress_percentage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Calculate progress percentage dari campaign
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get admin platform
    */
-  get_progress_percentage: ({campaign_id}: {campaign_id: u64}, options?: {
+  get_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_tiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get daftar reward tier campaign beserta sisa slot
+   */
+  get_tiers: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<TierInfo>>>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cek apakah kontrak sedang di-pause
+   */
+  is_paused: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get escrow milestone campaign (milestone, cara approval & progres)
+   */
+  get_escrow: ({id}: {id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Escrow>>>
+
+  /**
+   * Construct and simulate a get_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get status campaign saat ini (lihat tabel transisi di CampaignStatus)
+   */
+  get_status: ({id}: {id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<CampaignStatus>>>
+
+  /**
+   * Construct and simulate a is_claimed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah dana campaign sudah ditarik oleh owner
+   */
+  is_claimed: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a get_next_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get ID (u64) untuk campaign berikutnya
+   */
+  get_next_id: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Langkah 2 transfer admin: calon admin menerima perannya
+   */
+  accept_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get data lengkap dari satu campaign (untuk frontend)
+   */
+  get_campaign: ({id}: {id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Campaign>>>
+
+  /**
+   * Construct and simulate a get_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get campaign deadline timestamp
+   */
+  get_deadline: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_donation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get berapa banyak specific donor sudah donate ke campaign tertentu
+   */
+  get_donation: ({campaign_id, donor}: {campaign_id: u64, donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get metadata tampilan campaign (judul, deskripsi, URI, kategori)
+   */
+  get_metadata: ({id}: {id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<CampaignMetadata>>>
+
+  /**
+   * Construct and simulate a get_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get proposal milestone yang sedang di-voting
+   */
+  get_proposal: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Proposal>>>
+
+  /**
+   * Construct and simulate a is_cancelled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah campaign sudah dibatalkan owner
+   */
+  is_cancelled: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a get_donor_tier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get index reward tier yang dipilih donatur (None jika tidak memilih tier)
+   */
+  get_donor_tier: ({campaign_id, donor}: {campaign_id: u64, donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Option<u32>>>>
+
+  /**
+   * Construct and simulate a get_fee_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get konfigurasi fee yang berlaku saat ini
+   */
+  get_fee_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<FeeConfig>>>
+
+  /**
+   * Construct and simulate a list_campaigns transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Daftar campaign berdasarkan urutan ID, mulai dari ID `start`.
+   * ID yang belum dipindah dari map CAMPAIGNS lama (lihat `migrate`) dilewati.
+   */
+  list_campaigns: ({start, limit}: {start: u64, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<CampaignSummary>>>>
+
+  /**
+   * Construct and simulate a transfer_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Langkah 1 transfer admin: admin menunjuk calon admin baru.
+   * Admin lama tetap berlaku sampai calon memanggil `accept_admin`.
+   */
+  transfer_admin: ({new_admin}: {new_admin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner membatalkan campaign yang masih berjalan.
+   * Donasi baru langsung ditolak dan semua donatur bisa `refund`
+   * tanpa menunggu deadline, berapapun dana yang sudah terkumpul.
+   */
+  cancel_campaign: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a create_campaign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Membuat campaign baru.
+   * Siapapun bisa memanggil ini.
+   * Mengembalikan ID (u64) dari campaign yang baru dibuat.
+   */
+  create_campaign: ({owner, goal, deadline, token, config, metadata, milestones, tiers}: {owner: string, goal: i128, deadline: u64, token: string, config: CampaignConfig, metadata: CampaignMetadata, milestones: Option<MilestonePlan>, tiers: Array<RewardTier>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a extend_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner memperpanjang deadline campaign yang masih Active.
+   * Total perpanjangan dibatasi `max_extension`, dan jika `objection_bps`
+   * di-set, diblokir saat persentase donatur yang keberatan mencapai batas
+   * tersebut (dihitung per donatur, bukan per jumlah donasi).
+   */
+  extend_deadline: ({campaign_id, new_deadline}: {campaign_id: u64, new_deadline: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_pending_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get perubahan fee yang dijadwalkan (None jika tidak ada atau sudah berlaku)
+   */
+  get_pending_fee: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<PendingFee>>>
+
+  /**
+   * Construct and simulate a is_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check apakah campaign sudah reach goal
+   */
+  is_goal_reached: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a update_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner mengubah metadata campaign.
+   * Hanya bisa selama belum ada donasi dan campaign belum berakhir,
+   * supaya donatur tidak "tertipu" oleh perubahan setelah berdonasi.
+   */
+  update_metadata: ({campaign_id, metadata}: {campaign_id: u64, metadata: CampaignMetadata}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a withdraw_pledge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Donatur menarik sebagian / seluruh donasinya selama campaign Active
+   * (jika diizinkan campaign). Mengembalikan sisa donasi donatur.
+   */
+  withdraw_pledge: ({campaign_id, donor, amount}: {campaign_id: u64, donor: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get total amount yang sudah terkumpul untuk campaign tertentu
+   */
+  get_total_raised: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a object_extension transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Donatur menyatakan keberatan atas perpanjangan deadline.
+   * Setiap donatur dihitung satu kali, berapapun donasinya.
+   */
+  object_extension: ({campaign_id, donor}: {campaign_id: u64, donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reject_milestone transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approver menolak milestone berikutnya. Campaign menjadi Failed dan
+   * donatur bisa `refund` bagian pro rata dari dana yang belum dirilis.
+   */
+  reject_milestone: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a approve_milestone transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approver menyetujui milestone berikutnya dan merilis bagiannya ke owner.
+   * Hanya untuk campaign ber-milestone yang sudah Succeeded, sebelum due date
+   * milestone tersebut. Mengembalikan jumlah yang diterima owner.
+   */
+  approve_milestone: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a finalize_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Menutup proposal setelah periode voting berakhir (siapapun bisa memanggil).
+   * Jika quorum & threshold terpenuhi milestone dirilis ke owner, jika tidak
+   * campaign menjadi Failed dan donatur bisa refund sisa dana.
+   * Mengembalikan `true` jika proposal disetujui.
+   */
+  finalize_proposal: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get calon admin yang belum menerima (None jika tidak ada)
+   */
+  get_pending_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a propose_milestone transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner mengajukan pencairan milestone berikutnya untuk di-voting donatur.
+   * Harus diajukan sebelum due date milestone. Mengembalikan timestamp akhir voting.
+   */
+  propose_milestone: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_campaign_units transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get goal & raised campaign dalam satuan token, memakai `decimals()`
+   * dari kontrak token campaign (mis. 25_000_000 @ 7 desimal = 2.5 token)
+   */
+  get_campaign_units: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<CampaignUnits>>>
+
+  /**
+   * Construct and simulate a get_schema_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get versi layout storage. Kontrak lama tanpa versi tersimpan = versi 1
+   * sampai `migrate` selesai.
+   */
+  get_schema_version: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a list_active_campaigns transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Daftar campaign yang masih menerima donasi, mulai dari ID `start`.
+   * Berhenti setelah `limit` campaign aktif atau MAX_SCAN ID diperiksa,
+   * jadi halaman bisa kurang dari `limit`; lanjutkan dari `next`
+   * sampai `next` bernilai None.
+   */
+  list_active_campaigns: ({start, limit}: {start: u64, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<ActivePage>>
+
+  /**
+   * Construct and simulate a get_progress_percentage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Calculate progress percentage dari campaign
+   */
+  get_progress_percentage: ({campaign_id}: {campaign_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a list_campaigns_by_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Daftar campaign milik `owner`. `start` adalah posisi di index owner
+   * (0 = campaign pertama yang dibuat owner tersebut).
+   */
+  list_campaigns_by_owner: ({owner, start, limit}: {owner: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<CampaignSummary>>>>
+
+  /**
+   * Construct and simulate a list_campaigns_by_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Daftar campaign yang mengumpulkan `token`. `start` adalah posisi di index token.
+   */
+  list_campaigns_by_token: ({token, start, limit}: {token: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<Array<CampaignSummary>>>>
+
+  /**
+   * Construct and simulate a get_owner_campaign_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get jumlah campaign milik `owner` (untuk pagination)
+   */
+  get_owner_campaign_count: ({owner}: {owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_token_campaign_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get jumlah campaign yang mengumpulkan `token` (untuk pagination)
+   */
+  get_token_campaign_count: ({token}: {token: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -301,11 +1668,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<u32>>
 
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+        /** Constructor/Initialization Args for the contract's `__constructor` method */
+        {admin, treasury, fee_bps, max_fee_bps}: {admin: string, treasury: string, fee_bps: u32, max_fee_bps: u32},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -317,38 +1686,162 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options)
+    return ContractClient.deploy({admin, treasury, fee_bps, max_fee_bps}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAACENhbXBhaWduAAAABgAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAAlkb25hdGlvbnMAAAAAAAPsAAAAEwAAAAsAAAAAAAAABGdvYWwAAAALAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABnJhaXNlZAAAAAAACwAAAAAAAAAFdG9rZW4AAAAAAAAT",
-        "AAAAAAAAAGpNZW1idWF0IGNhbXBhaWduIGJhcnUuClNpYXBhcHVuIGJpc2EgbWVtYW5nZ2lsIGluaS4KTWVuZ2VtYmFsaWthbiBJRCAodTY0KSBkYXJpIGNhbXBhaWduIHlhbmcgYmFydSBkaWJ1YXQuAAAAAAAPY3JlYXRlX2NhbXBhaWduAAAAAAQAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAEZ29hbAAAAAsAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAY=",
-        "AAAAAAAAADZEb25hc2kga2UgY2FtcGFpZ24gdGVydGVudHUgbWVuZ2d1bmFrYW4gdG9rZW4gdHJhbnNmZXIAAAAAAAZkb25hdGUAAAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-        "AAAAAAAAAEhSZWZ1bmQgbWVjaGFuaXNtIGppa2EgY2FtcGFpZ24gZ2FnYWwgKGhhbnlhIGJpc2EgZGlwYW5nZ2lsIG9sZWggZG9uYXR1cikAAAAGcmVmdW5kAAAAAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
-        "AAAAAAAAADRHZXQgZGF0YSBsZW5na2FwIGRhcmkgc2F0dSBjYW1wYWlnbiAodW50dWsgZnJvbnRlbmQpAAAADGdldF9jYW1wYWlnbgAAAAEAAAAAAAAAAmlkAAAAAAAGAAAAAQAAB9AAAAAIQ2FtcGFpZ24=",
+      new ContractSpec([ "AAAABQAAAD50b3BpY3MgWyJ2b3RlZCIsIGNhbXBhaWduX2lkLCBkb25vcl0sIGRhdGEgeyBhcHByb3ZlLCB3ZWlnaHQgfQAAAAAAAAAAAAVWb3RlZAAAAAAAAAEAAAAFdm90ZWQAAAAAAAAEAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAHYXBwcm92ZQAAAAABAAAAAAAAAAAAAAAGd2VpZ2h0AAAAAAALAAAAAAAAAAI=",
+        "AAAAAQAAACNTdGF0ZSBlc2Nyb3cgY2FtcGFpZ24gYmVyLW1pbGVzdG9uZQAAAAAAAAAABkVzY3JvdwAAAAAAAwAAAAAAAAAIYXBwcm92YWwAAAfQAAAACEFwcHJvdmFsAAAAAAAAAAptaWxlc3RvbmVzAAAAAAPqAAAH0AAAAAlNaWxlc3RvbmUAAAAAAAAAAAAABG5leHQAAAAE",
+        "AAAABQAAABh0b3BpY3MgWyJwYXVzZWQiLCBhZG1pbl0AAAAAAAAABlBhdXNlZAAAAAAAAQAAAAZwYXVzZWQAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAC",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAADgAAAAEAAAAAAAAACENhbXBhaWduAAAAAQAAAAYAAAABAAAAAAAAAAhEb25hdGlvbgAAAAIAAAAGAAAAEwAAAAEAAAAAAAAACE1ldGFkYXRhAAAAAQAAAAYAAAABAAAAAAAAAAZFc2Nyb3cAAAAAAAEAAAAGAAAAAQAAAAAAAAAIUHJvcG9zYWwAAAABAAAABgAAAAEAAAAAAAAABVRpZXJzAAAAAAAAAQAAAAYAAAABAAAAAAAAAAtUaWVyQ2xhaW1lZAAAAAACAAAABgAAAAQAAAABAAAAAAAAAAlEb25vclRpZXIAAAAAAAACAAAABgAAABMAAAABAAAAAAAAAAlPYmplY3Rpb24AAAAAAAACAAAABgAAABMAAAABAAAAAAAAAARWb3RlAAAAAwAAAAYAAAAEAAAAEwAAAAEAAAAAAAAACk93bmVyQ291bnQAAAAAAAEAAAATAAAAAQAAAAAAAAANT3duZXJDYW1wYWlnbgAAAAAAAAIAAAATAAAABAAAAAEAAAAAAAAAClRva2VuQ291bnQAAAAAAAEAAAATAAAAAQAAAAAAAAANVG9rZW5DYW1wYWlnbgAAAAAAAAIAAAATAAAABA==",
+        "AAAABQAAAD90b3BpY3MgWyJkb25hdGVkIiwgY2FtcGFpZ25faWQsIGRvbm9yXSwgZGF0YSB7IGFtb3VudCwgcmFpc2VkIH0AAAAAAAAAAAdEb25hdGVkAAAAAAEAAAAHZG9uYXRlZAAAAAAEAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAI=",
+        "AAAAAgAAACZTaWFwYSB5YW5nIG1lbXV0dXNrYW4gc2V0aWFwIG1pbGVzdG9uZQAAAAAAAAAAAAhBcHByb3ZhbAAAAAIAAAABAAAARFNhdHUgYWRkcmVzcyBhcHByb3ZlciBtZW1hbmdnaWwgYXBwcm92ZV9taWxlc3RvbmUgLyByZWplY3RfbWlsZXN0b25lAAAACEFwcHJvdmVyAAAAAQAAABMAAAABAAAAQU93bmVyIG1lbmdhanVrYW4gcHJvcG9zYWwsIGRvbmF0dXIgdm90aW5nLCBsYWx1IGZpbmFsaXplX3Byb3Bvc2FsAAAAAAAACURvbm9yVm90ZQAAAAAAAAEAAAfQAAAADFZvdGluZ0NvbmZpZw==",
+        "AAAAAQAAAAAAAAAAAAAACENhbXBhaWduAAAAFwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAAZkb25vcnMAAAAAAAQAAAAAAAAACGV4dGVuZGVkAAAABgAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAAAAAAhmZWVfcGFpZAAAAAsAAAAAAAAADGZ1bmRpbmdfbW9kZQAAB9AAAAALRnVuZGluZ01vZGUAAAAAAAAAAARnb2FsAAAACwAAAAAAAAAIaGFyZF9jYXAAAAPoAAAACwAAAAAAAAAMbG9ja19vbl9nb2FsAAAAAQAAAAAAAAALbG9ja193aW5kb3cAAAAABgAAAAAAAAANbWF4X2V4dGVuc2lvbgAAAAAAAAYAAAAAAAAADW1heF9wZXJfZG9ub3IAAAAAAAPoAAAACwAAAAAAAAAMbWluX2RvbmF0aW9uAAAD6AAAAAsAAAAAAAAADW9iamVjdGlvbl9icHMAAAAAAAPoAAAABAAAAAAAAAAKb2JqZWN0aW9ucwAAAAAABAAAAAAAAAAKb3Zlcl9saW1pdAAAAAAH0AAAAA9PdmVyTGltaXRQb2xpY3kAAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAEXBsZWRnZV93aXRoZHJhd2FsAAAAAAAAAQAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAhyZWxlYXNlZAAAAAsAAAAAAAAACnN0YXJ0X3RpbWUAAAAAA+gAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAOQ2FtcGFpZ25TdGF0dXMAAAAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAwAAADBLYXRlZ29yaSBjYW1wYWlnbiwgZGlwYWthaSBmcm9udGVuZCB1bnR1ayBmaWx0ZXIAAAAAAAAACENhdGVnb3J5AAAACAAAAAAAAAAKVGVjaG5vbG9neQAAAAAAAAAAAAAAAAADQXJ0AAAAAAEAAAAAAAAACUNvbW11bml0eQAAAAAAAAIAAAAAAAAAB0NoYXJpdHkAAAAAAwAAAAAAAAAJRWR1Y2F0aW9uAAAAAAAABAAAAAAAAAAGSGVhbHRoAAAAAAAFAAAAAAAAAAtFbnZpcm9ubWVudAAAAAAGAAAAAAAAAAVPdGhlcgAAAAAAAAc=",
+        "AAAAAQAAADJQcm9wb3NhbCBwZW5jYWlyYW4gbWlsZXN0b25lIHlhbmcgc2VkYW5nIGRpLXZvdGluZwAAAAAAAAAAAAhQcm9wb3NhbAAAAAQAAAAAAAAACW1pbGVzdG9uZQAAAAAAAAQAAAAAAAAADXZvdGVzX2FnYWluc3QAAAAAAAALAAAAAAAAAAl2b3Rlc19mb3IAAAAAAAALAAAAAAAAAAp2b3RpbmdfZW5kAAAAAAAG",
+        "AAAAAQAAADFSZXdhcmQgdGllciBiZXNlcnRhIHNpc2Egc2xvdCAoaGFzaWwgYGdldF90aWVyc2ApAAAAAAAAAAAAAAhUaWVySW5mbwAAAAQAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACm1pbl9hbW91bnQAAAAAAAsAAAAAAAAACHF1YW50aXR5AAAABAAAAAAAAAAJcmVtYWluaW5nAAAAAAAABA==",
+        "AAAABQAAAEB0b3BpY3MgWyJyZWZ1bmRlZCIsIGNhbXBhaWduX2lkLCBkb25vcl0sIGRhdGEgeyBhbW91bnQsIHJhaXNlZCB9AAAAAAAAAAhSZWZ1bmRlZAAAAAEAAAAIcmVmdW5kZWQAAAAEAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAABp0b3BpY3MgWyJ1bnBhdXNlZCIsIGFkbWluXQAAAAAAAAAAAAhVbnBhdXNlZAAAAAEAAAAIdW5wYXVzZWQAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAg==",
+        "AAAAAQAAAD5Lb25maWd1cmFzaSBmZWUgcGxhdGZvcm0gKGRpc2V0IGxld2F0IGNvbnN0cnVjdG9yIHNhYXQgZGVwbG95KQAAAAAAAAAAAAlGZWVDb25maWcAAAAAAAADAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAAAAAAC21heF9mZWVfYnBzAAAAAAQAAAAAAAAACHRyZWFzdXJ5AAAAEw==",
+        "AAAAAQAAABlTYXR1IHRhaGFwIHBlbmNhaXJhbiBkYW5hAAAAAAAAAAAAAAlNaWxlc3RvbmUAAAAAAAADAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAhkdWVfZGF0ZQAAAAYAAAAAAAAACnBlcmNlbnRhZ2UAAAAAAAQ=",
+        "AAAABQAAADl0b3BpY3MgWyJ3aXRoZHJhd24iLCBjYW1wYWlnbl9pZCwgb3duZXJdLCBkYXRhIHsgYW1vdW50IH0AAAAAAAAAAAAACVdpdGhkcmF3bgAAAAAAAAEAAAAJd2l0aGRyYXduAAAAAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAAAQAAACpTYXR1IGhhbGFtYW4gaGFzaWwgYGxpc3RfYWN0aXZlX2NhbXBhaWduc2AAAAAAAAAAAAAKQWN0aXZlUGFnZQAAAAAAAgAAAAAAAAAJY2FtcGFpZ25zAAAAAAAD6gAAB9AAAAAPQ2FtcGFpZ25TdW1tYXJ5AAAAAAAAAAAEbmV4dAAAA+gAAAAG",
+        "AAAAAQAAACRQZXJ1YmFoYW4gZmVlIHlhbmcgbWVudW5nZ3UgdGltZWxvY2sAAAAAAAAAClBlbmRpbmdGZWUAAAAAAAIAAAAAAAAADGVmZmVjdGl2ZV9hdAAAAAYAAAAAAAAAB2ZlZV9icHMAAAAABA==",
+        "AAAAAQAAADNSZXdhcmQgdW50dWsgZG9uYXR1ciBkZW5nYW4gZG9uYXNpIG1pbmltYWwgdGVydGVudHUAAAAAAAAAAApSZXdhcmRUaWVyAAAAAAADAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAptaW5fYW1vdW50AAAAAAALAAAAAAAAAAhxdWFudGl0eQAAAAQ=",
+        "AAAAAQAAAEdKdW1sYWggdG9rZW4gZGFsYW0gc2F0dWFuICJtYW51c2lhIjogYHdob2xlYCArIGBmcmFjdGlvbmAgLyAxMF5kZWNpbWFscwAAAAAAAAAAClVuaXRBbW91bnQAAAAAAAIAAAAAAAAACGZyYWN0aW9uAAAACwAAAAAAAAAFd2hvbGUAAAAAAAAL",
+        "AAAABQAAADp0b3BpY3MgWyJmZWVfY2hhcmdlZCIsIGNhbXBhaWduX2lkLCB0cmVhc3VyeV0sIGRhdGEgYW1vdW50AAAAAAAAAAAACkZlZUNoYXJnZWQAAAAAAAEAAAALZmVlX2NoYXJnZWQAAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAAAAAAACHRyZWFzdXJ5AAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAA",
+        "AAAAAwAAAENBcGEgeWFuZyB0ZXJqYWRpIGRlbmdhbiBkYW5hIGppa2EgZ29hbCB0aWRhayB0ZXJjYXBhaSBzYWF0IGRlYWRsaW5lAAAAAAAAAAALRnVuZGluZ01vZGUAAAAAAgAAAEFHb2FsIHRpZGFrIHRlcmNhcGFpOiBkb25hdHVyIHJlZnVuZCwgb3duZXIgdGlkYWsgbWVuZXJpbWEgYXBhLWFwYQAAAAAAAAxBbGxPck5vdGhpbmcAAAAAAAAARE93bmVyIHRldGFwIG1lbmVyaW1hIHNlbXVhIGRhbmEgeWFuZyB0ZXJrdW1wdWwgKGNvY29rIHVudHVrIGNoYXJpdHkpAAAACUtlZXBJdEFsbAAAAAAAAAE=",
+        "AAAABQAAADZ0b3BpY3MgWyJ0aWVyX2NsYWltZWQiLCBjYW1wYWlnbl9pZCwgZG9ub3JdLCBkYXRhIHRpZXIAAAAAAAAAAAALVGllckNsYWltZWQAAAAAAQAAAAx0aWVyX2NsYWltZWQAAAADAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAEdGllcgAAAAQAAAAAAAAAAA==",
+        "AAAAAQAAADZQYXJhbWV0ZXIgdm90aW5nIGRvbmF0dXIuIEJvYm90IHN1YXJhID0ganVtbGFoIGRvbmFzaS4AAAAAAAAAAAAMVm90aW5nQ29uZmlnAAAAAwAAAAAAAAAKcXVvcnVtX2JwcwAAAAAABAAAAAAAAAANdGhyZXNob2xkX2JwcwAAAAAAAAQAAAAAAAAADXZvdGluZ19wZXJpb2QAAAAAAAAG",
+        "AAAABQAAAC50b3BpY3MgWyJhZG1pbl9jaGFuZ2VkIiwgb2xkX2FkbWluLCBuZXdfYWRtaW5dAAAAAAAAAAAADEFkbWluQ2hhbmdlZAAAAAEAAAANYWRtaW5fY2hhbmdlZAAAAAAAAAIAAAAAAAAACW9sZF9hZG1pbgAAAAAAABMAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAQAAAAI=",
+        "AAAABQAAADh0b3BpY3MgWyJmZWVfc2NoZWR1bGVkIl0sIGRhdGEgeyBmZWVfYnBzLCBlZmZlY3RpdmVfYXQgfQAAAAAAAAAMRmVlU2NoZWR1bGVkAAAAAQAAAA1mZWVfc2NoZWR1bGVkAAAAAAAAAgAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAAAAAAAAAAAMZWZmZWN0aXZlX2F0AAAABgAAAAAAAAAC",
+        "AAAAAQAAAEZHb2FsICYgcmFpc2VkIGNhbXBhaWduIGRhbGFtIHNhdHVhbiB0b2tlbiAoaGFzaWwgYGdldF9jYW1wYWlnbl91bml0c2ApAAAAAAAAAAAADUNhbXBhaWduVW5pdHMAAAAAAAADAAAAAAAAAAhkZWNpbWFscwAAAAQAAAAAAAAABGdvYWwAAAfQAAAAClVuaXRBbW91bnQAAAAAAAAAAAAGcmFpc2VkAAAAAAfQAAAAClVuaXRBbW91bnQAAA==",
+        "AAAAAQAAACZSZW5jYW5hIG1pbGVzdG9uZSBzYWF0IGNyZWF0ZV9jYW1wYWlnbgAAAAAAAAAAAA1NaWxlc3RvbmVQbGFuAAAAAAAAAgAAAAAAAAAIYXBwcm92YWwAAAfQAAAACEFwcHJvdmFsAAAAAAAAAAptaWxlc3RvbmVzAAAAAAPqAAAH0AAAAAlNaWxlc3RvbmUAAAA=",
+        "AAAAAQAAAC9PcHNpIGNhbXBhaWduIHlhbmcgZGlwaWxpaCBzYWF0IGNyZWF0ZV9jYW1wYWlnbgAAAAAAAAAADkNhbXBhaWduQ29uZmlnAAAAAAALAAAAAAAAAAxmdW5kaW5nX21vZGUAAAfQAAAAC0Z1bmRpbmdNb2RlAAAAAAAAAAAIaGFyZF9jYXAAAAPoAAAACwAAAAAAAAAMbG9ja19vbl9nb2FsAAAAAQAAAAAAAAALbG9ja193aW5kb3cAAAAABgAAAAAAAAANbWF4X2V4dGVuc2lvbgAAAAAAAAYAAAAAAAAADW1heF9wZXJfZG9ub3IAAAAAAAPoAAAACwAAAAAAAAAMbWluX2RvbmF0aW9uAAAD6AAAAAsAAAAAAAAADW9iamVjdGlvbl9icHMAAAAAAAPoAAAABAAAAAAAAAAKb3Zlcl9saW1pdAAAAAAH0AAAAA9PdmVyTGltaXRQb2xpY3kAAAAAAAAAABFwbGVkZ2Vfd2l0aGRyYXdhbAAAAAAAAAEAAAAAAAAACnN0YXJ0X3RpbWUAAAAAA+gAAAAG",
+        "AAAAAwAAA4pTdGF0dXMgY2FtcGFpZ24uIFRyYW5zaXNpIHlhbmcgZGlpemlua2FuOgoKfCBEYXJpICAgICAgfCBLZSAgICAgICAgfCBQZW1pY3UgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgfAp8LS0tLS0tLS0tLS18LS0tLS0tLS0tLS18LS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS0tLS18CnwgUGVuZGluZyAgIHwgQWN0aXZlICAgIHwgYG5vdyA+PSBzdGFydF90aW1lYCAgICAgICAgICAgICAgICAgICAgIHwKfCBQZW5kaW5nICAgfCBDYW5jZWxsZWQgfCBgY2FuY2VsX2NhbXBhaWduYCAgICAgICAgICAgICAgICAgICAgICAgfAp8IEFjdGl2ZSAgICB8IFN1Y2NlZWRlZCB8IGBub3cgPj0gZGVhZGxpbmVgIGRhbiBkYW5hIGN1a3VwICgqKSAgICB8CnwgQWN0aXZlICAgIHwgRmFpbGVkICAgIHwgYG5vdyA+PSBkZWFkbGluZWAgZGFuIGRhbmEgdGlkYWsgY3VrdXAgKCopIHwKfCBBY3RpdmUgICAgfCBTdWNjZWVkZWQgfCBoYXJkIGNhcCB0ZXJjYXBhaSAoc2ViZWx1bSBkZWFkbGluZSkgICAgfAp8IEFjdGl2ZSAgICB8IENhbmNlbGxlZCB8IGBjYW5jZWxfY2FtcGFpZ25gICAgICAgICAgICAgICAgICAgICAgICB8CnwgU3VjY2VlZGVkIHwgQ2xhaW1lZCAgIHwgYHdpdGhkcmF3YCAvIG1pbGVzdG9uZSB0ZXJha2hpciBkaXJpbGlzIHwKfCBTdWNjZWVkZWQgfCBGYWlsZWQgICAgfCBtaWxlc3RvbmUgZGl0b2xhayBhdGF1IGxld2F0IGR1ZSBkYXRlICAgfAoKKCopIEFsbE9yTm90aGluZzogZ29hbCB0ZXJjYXBhaS4gS2VlcEl0QWxsOiBhZGEgZGFuYSB0ZXJrdW1wdWwgKD4gMCkuCgpGYWlsZWQsIENhbmNlbGxlZCAmIENsYWltZWQgYWRhbGFoIHN0YXR1cyBha2hpciBkYW4gZGlzaW1wYW47IHN0YXR1cwpsYWluIGRpaGl0dW5nIGRhcmkgd2FrdHUgbGVkZ2VyICYgZGFuYS4AAAAAAAAAAAAOQ2FtcGFpZ25TdGF0dXMAAAAAAAYAAAAZQmVsdW0gZGlidWthIHVudHVrIGRvbmFzaQAAAAAAAAdQZW5kaW5nAAAAAAAAAAAPTWVuZXJpbWEgZG9uYXNpAAAAAAZBY3RpdmUAAAAAAAEAAAA1RGVhZGxpbmUgbGV3YXQgJiBnb2FsIHRlcmNhcGFpLCBvd25lciBiaXNhIGB3aXRoZHJhd2AAAAAAAAAJU3VjY2VlZGVkAAAAAAAAAgAAADtEZWFkbGluZSBsZXdhdCAmIGdvYWwgdGlkYWsgdGVyY2FwYWksIGRvbmF0dXIgYmlzYSBgcmVmdW5kYAAAAAAGRmFpbGVkAAAAAAADAAAAJ0RpYmF0YWxrYW4gb3duZXIsIGRvbmF0dXIgYmlzYSBgcmVmdW5kYAAAAAAJQ2FuY2VsbGVkAAAAAAAABAAAABhEYW5hIHN1ZGFoIGRpdGFyaWsgb3duZXIAAAAHQ2xhaW1lZAAAAAAF",
+        "AAAAAQAAAEIoTGVnYWN5KSBCZW50dWsgY2FtcGFpZ24gZGkgc2NoZW1hIHZlcnNpIDEsIHRlcm1hc3VrIG1hcCBkb25hc2lueWEAAAAAAAAAAAAOTGVnYWN5Q2FtcGFpZ24AAAAAAAYAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAJZG9uYXRpb25zAAAAAAAD7AAAABMAAAALAAAAAAAAAARnb2FsAAAACwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAZyYWlzZWQAAAAAAAsAAAAAAAAABXRva2VuAAAAAAAAEw==",
+        "AAAAAQAAADhSaW5na2FzYW4gY2FtcGFpZ24gdW50dWsgaGFsYW1hbiBkYWZ0YXIgKGhhc2lsIGBsaXN0XypgKQAAAAAAAAAPQ2FtcGFpZ25TdW1tYXJ5AAAAAAkAAAAAAAAACGNhdGVnb3J5AAAH0AAAAAhDYXRlZ29yeQAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAARnb2FsAAAACwAAAAAAAAACaWQAAAAAAAYAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAOQ2FtcGFpZ25TdGF0dXMAAAAAAAAAAAAFdGl0bGUAAAAAAAAQAAAAAAAAAAV0b2tlbgAAAAAAABM=",
+        "AAAAAwAAADtQZXJsYWt1YW4gZG9uYXNpIHlhbmcgbWVsZXdhdGkgaGFyZCBjYXAgLyBiYXRhcyBwZXIgZG9uYXR1cgAAAAAAAAAAD092ZXJMaW1pdFBvbGljeQAAAAACAAAAFFRvbGFrIHNlbHVydWggZG9uYXNpAAAABlJlamVjdAAAAAAAAAAAADZUZXJpbWEgc2ViYWdpYW4gc2FtcGFpIGJhdGFzLCBzaXNhbnlhIHRpZGFrIGRpdHJhbnNmZXIAAAAAAA1QYXJ0aWFsQWNjZXB0AAAAAAAAAQ==",
+        "AAAABQAAAE90b3BpY3MgWyJjYW1wYWlnbl9jcmVhdGVkIiwgY2FtcGFpZ25faWQsIG93bmVyXSwgZGF0YSB7IGdvYWwsIGRlYWRsaW5lLCB0b2tlbiB9AAAAAAAAAAAPQ2FtcGFpZ25DcmVhdGVkAAAAAAEAAAAQY2FtcGFpZ25fY3JlYXRlZAAAAAUAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAAAAAAAARnb2FsAAAACwAAAAAAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAEJ0b3BpY3MgWyJtZXRhZGF0YV91cGRhdGVkIiwgY2FtcGFpZ25faWRdLCBkYXRhIHsgdGl0bGUsIGNhdGVnb3J5IH0AAAAAAAAAAAAPTWV0YWRhdGFVcGRhdGVkAAAAAAEAAAAQbWV0YWRhdGFfdXBkYXRlZAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAAAAAAAhjYXRlZ29yeQAAB9AAAAAIQ2F0ZWdvcnkAAAAAAAAAAg==",
+        "AAAABQAAAEh0b3BpY3MgWyJwbGVkZ2Vfd2l0aGRyYXduIiwgY2FtcGFpZ25faWQsIGRvbm9yXSwgZGF0YSB7IGFtb3VudCwgcmFpc2VkIH0AAAAAAAAAD1BsZWRnZVdpdGhkcmF3bgAAAAABAAAAEHBsZWRnZV93aXRoZHJhd24AAAAEAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAGcmFpc2VkAAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAEh0b3BpY3MgWyJwcm9wb3NhbF9jcmVhdGVkIiwgY2FtcGFpZ25faWQsIG1pbGVzdG9uZV0sIGRhdGEgeyB2b3RpbmdfZW5kIH0AAAAAAAAAD1Byb3Bvc2FsQ3JlYXRlZAAAAAABAAAAEHByb3Bvc2FsX2NyZWF0ZWQAAAADAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAJbWlsZXN0b25lAAAAAAAABAAAAAEAAAAAAAAACnZvdGluZ19lbmQAAAAAAAYAAAAAAAAAAg==",
+        "AAAAAQAAAH1NZXRhZGF0YSB0YW1waWxhbiBjYW1wYWlnbi4gRGlzaW1wYW4gdGVycGlzYWggZGFyaSBgQ2FtcGFpZ25gIHN1cGF5YQpgZG9uYXRlYC9gcmVmdW5kYCB0aWRhayBwZXJsdSBtZW1iYWNhIHRla3MgeWFuZyBwYW5qYW5nLgAAAAAAAAAAAAAQQ2FtcGFpZ25NZXRhZGF0YQAAAAQAAAAAAAAACGNhdGVnb3J5AAAH0AAAAAhDYXRlZ29yeQAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAMbWV0YWRhdGFfdXJpAAAAEAAAAAAAAAAFdGl0bGUAAAAAAAAQ",
+        "AAAABQAAAE50b3BpY3MgWyJkZWFkbGluZV9leHRlbmRlZCIsIGNhbXBhaWduX2lkXSwgZGF0YSB7IG9sZF9kZWFkbGluZSwgbmV3X2RlYWRsaW5lIH0AAAAAAAAAAAAQRGVhZGxpbmVFeHRlbmRlZAAAAAEAAAARZGVhZGxpbmVfZXh0ZW5kZWQAAAAAAAADAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAMb2xkX2RlYWRsaW5lAAAABgAAAAAAAAAAAAAADG5ld19kZWFkbGluZQAAAAYAAAAAAAAAAg==",
+        "AAAABAAAAHZLb2RlIGVycm9yIGtvbnRyYWsuIE5vbW9yIGRpcGFrYWkgb2xlaCBiaW5kaW5nIFRTIGRhbiBrb250cmFrIHBlbWFuZ2dpbCwKamFkaSBub21vciB5YW5nIHN1ZGFoIGFkYSB0aWRhayBib2xlaCBkaXViYWguAAAAAAAAAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAAAAAAMgAAACBHb2FsIGNhbXBhaWduIGhhcnVzIGxlYmloIGRhcmkgMAAAAA9Hb2FsTm90UG9zaXRpdmUAAAAAAQAAABxEZWFkbGluZSBoYXJ1cyBkaSBtYXNhIGRlcGFuAAAADkRlYWRsaW5lSW5QYXN0AAAAAAACAAAAIEp1bWxhaCBkb25hc2kgaGFydXMgbGViaWggZGFyaSAwAAAAEUFtb3VudE5vdFBvc2l0aXZlAAAAAAAAAwAAACVDYW1wYWlnbiBkZW5nYW4gSUQgdGVyc2VidXQgdGlkYWsgYWRhAAAAAAAAEENhbXBhaWduTm90Rm91bmQAAAAEAAAALkNhbXBhaWduIHN1ZGFoIGJlcmFraGlyLCB0aWRhayBtZW5lcmltYSBkb25hc2kAAAAAAA1DYW1wYWlnbkVuZGVkAAAAAAAABQAAABdDYW1wYWlnbiBiZWx1bSBiZXJha2hpcgAAAAAQQ2FtcGFpZ25Ob3RFbmRlZAAAAAYAAAAmR29hbCBzdWRhaCB0ZXJjYXBhaSwgdGlkYWsgYmlzYSByZWZ1bmQAAAAAAAtHb2FsUmVhY2hlZAAAAAAHAAAAMkdvYWwgYmVsdW0gdGVyY2FwYWksIG93bmVyIHRpZGFrIGJpc2EgbWVuYXJpayBkYW5hAAAAAAAOR29hbE5vdFJlYWNoZWQAAAAAAAgAAAAhRGFuYSBjYW1wYWlnbiBzdWRhaCBkaXRhcmlrIG93bmVyAAAAAAAADkFscmVhZHlDbGFpbWVkAAAAAAAJAAAAKURvbmF0dXIgdGlkYWsgZGl0ZW11a2FuIGF0YXUgc3VkYWggcmVmdW5kAAAAAAAAD05vdGhpbmdUb1JlZnVuZAAAAAAKAAAAIUp1ZHVsIGtvc29uZyBhdGF1IHRlcmxhbHUgcGFuamFuZwAAAAAAAAxJbnZhbGlkVGl0bGUAAAALAAAAGURlc2tyaXBzaSB0ZXJsYWx1IHBhbmphbmcAAAAAAAASRGVzY3JpcHRpb25Ub29Mb25nAAAAAAAMAAAAOlVSSSBtZXRhZGF0YSB0ZXJsYWx1IHBhbmphbmcgYXRhdSBidWthbiBpcGZzOi8vIC8gaHR0cHM6Ly8AAAAAABJJbnZhbGlkTWV0YWRhdGFVcmkAAAAAAA0AAABGTWV0YWRhdGEgdGlkYWsgYmlzYSBkaXViYWggbGFnaSAoc3VkYWggYWRhIGRvbmFzaSAvIGNhbXBhaWduIGJlcmFraGlyKQAAAAAADk1ldGFkYXRhTG9ja2VkAAAAAAAOAAAAH0NhbXBhaWduIHN1ZGFoIGRpYmF0YWxrYW4gb3duZXIAAAAAEUNhbXBhaWduQ2FuY2VsbGVkAAAAAAAADwAAAFBEYWZ0YXIgbWlsZXN0b25lIHRpZGFrIHZhbGlkIChrb3NvbmcsIHBlcnNlbnRhc2UgdGlkYWsgMTAwLCBkdWUgZGF0ZSB0aWRhayB1cnV0KQAAABFJbnZhbGlkTWlsZXN0b25lcwAAAAAAABAAAABGRGFuYSBjYW1wYWlnbiBiZXItbWlsZXN0b25lIGhhbnlhIGJpc2EgZGlyaWxpcyBsZXdhdCBhcHByb3ZlX21pbGVzdG9uZQAAAAAADUZ1bmRzSW5Fc2Nyb3cAAAAAAAARAAAAHkNhbXBhaWduIHRpZGFrIHB1bnlhIG1pbGVzdG9uZQAAAAAADE5vTWlsZXN0b25lcwAAABIAAABUVGlkYWsgYWRhIG1pbGVzdG9uZSB5YW5nIGJpc2EgZGlwcm9zZXMgKGRpdG9sYWssIHRlcmxld2F0LCBhdGF1IHN1ZGFoIGRpcmlsaXMgc2VtdWEpAAAAD01pbGVzdG9uZUNsb3NlZAAAAAATAAAAT01pbGVzdG9uZSBjYW1wYWlnbiBpbmkgZGlwdXR1c2thbiBkZW5nYW4gY2FyYSBsYWluIChhcHByb3ZlciB2cyB2b3RpbmcgZG9uYXR1cikAAAAAEVdyb25nQXBwcm92YWxNb2RlAAAAAAAAFAAAAC9RdW9ydW0gLyB0aHJlc2hvbGQgLyBwZXJpb2RlIHZvdGluZyB0aWRhayB2YWxpZAAAAAATSW52YWxpZFZvdGluZ0NvbmZpZwAAAAAVAAAAKk1hc2loIGFkYSBwcm9wb3NhbCB5YW5nIGJlbHVtIGRpZmluYWxpc2FzaQAAAAAADFByb3Bvc2FsT3BlbgAAABYAAAAnVGlkYWsgYWRhIHByb3Bvc2FsIHlhbmcgc2VkYW5nIGJlcmphbGFuAAAAAApOb1Byb3Bvc2FsAAAAAAAXAAAAHVBlcmlvZGUgdm90aW5nIHN1ZGFoIGJlcmFraGlyAAAAAAAADFZvdGluZ0Nsb3NlZAAAABgAAAAdUGVyaW9kZSB2b3RpbmcgYmVsdW0gYmVyYWtoaXIAAAAAAAAOVm90aW5nTm90RW5kZWQAAAAAABkAAAAxRG9uYXR1ciBzdWRhaCBtZW1iZXJpa2FuIHN1YXJhIHVudHVrIHByb3Bvc2FsIGluaQAAAAAAAAxBbHJlYWR5Vm90ZWQAAAAaAAAAJ0hhbnlhIGRvbmF0dXIgY2FtcGFpZ24geWFuZyBiaXNhIHZvdGluZwAAAAAITm90RG9ub3IAAAAbAAAAHkRhZnRhciByZXdhcmQgdGllciB0aWRhayB2YWxpZAAAAAAADEludmFsaWRUaWVycwAAABwAAAArUmV3YXJkIHRpZXIgZGVuZ2FuIGluZGV4IHRlcnNlYnV0IHRpZGFrIGFkYQAAAAAMVGllck5vdEZvdW5kAAAAHQAAACpUb3RhbCBkb25hc2kgZG9uYXR1ciBkaSBiYXdhaCBtaW5pbXVtIHRpZXIAAAAAABBUaWVyQmVsb3dNaW5pbXVtAAAAHgAAAB1LdW90YSByZXdhcmQgdGllciBzdWRhaCBoYWJpcwAAAAAAAAtUaWVyU29sZE91dAAAAAAfAAAATkNhbXBhaWduIGtlZXAtaXQtYWxsOiBkYW5hIHlhbmcgdGVya3VtcHVsIHRldGFwIHVudHVrIG93bmVyLCB0aWRhayBiaXNhIHJlZnVuZAAAAAAACUZ1bmRzS2VwdAAAAAAAACAAAAAjSGFyZCBjYXAgLyBiYXRhcyBkb25hc2kgdGlkYWsgdmFsaWQAAAAADUludmFsaWRMaW1pdHMAAAAAAAAhAAAAJ0RvbmFzaSBkaSBiYXdhaCBtaW5pbXVtIGRvbmFzaSBjYW1wYWlnbgAAAAAQQmVsb3dNaW5Eb25hdGlvbgAAACIAAAAvVG90YWwgZG9uYXNpIGRvbmF0dXIgbWVsZWJpaGkgYmF0YXMgcGVyIGRvbmF0dXIAAAAAEkRvbm9yTGltaXRFeGNlZWRlZAAAAAAAIwAAACFEb25hc2kgbWVsZWJpaGkgaGFyZCBjYXAgY2FtcGFpZ24AAAAAAAAPSGFyZENhcEV4Y2VlZGVkAAAAACQAAAAiV2FrdHUgbXVsYWkgaGFydXMgc2ViZWx1bSBkZWFkbGluZQAAAAAAEEludmFsaWRTdGFydFRpbWUAAAAlAAAAKkNhbXBhaWduIGJlbHVtIGRpYnVrYSAoc2ViZWx1bSBzdGFydF90aW1lKQAAAAAAEkNhbXBhaWduTm90U3RhcnRlZAAAAAAAJgAAAFNQZXJwYW5qYW5nYW4gZGVhZGxpbmUgdGlkYWsgdmFsaWQgKGtvbmZpZ3VyYXNpLCBkZWFkbGluZSBiYXJ1LCBhdGF1IG1lbGV3YXRpIGJhdGFzKQAAAAAQSW52YWxpZEV4dGVuc2lvbgAAACcAAAA8UGVycGFuamFuZ2FuIGRpdG9sYWsga2FyZW5hIGtlYmVyYXRhbiBkb25hdHVyIG1lbmNhcGFpIGJhdGFzAAAAEUV4dGVuc2lvbk9iamVjdGVkAAAAAAAAKAAAACJEb25hdHVyIHN1ZGFoIG1lbnlhdGFrYW4ga2ViZXJhdGFuAAAAAAAPQWxyZWFkeU9iamVjdGVkAAAAACkAAAA8Q2FtcGFpZ24gdGlkYWsgbWVuZ2l6aW5rYW4gcGVuYXJpa2FuIGRvbmFzaSBzZWJlbHVtIGRlYWRsaW5lAAAAGFBsZWRnZVdpdGhkcmF3YWxEaXNhYmxlZAAAACoAAAA9UGVuYXJpa2FuIGRvbmFzaSBkaWt1bmNpIChnb2FsIHRlcmNhcGFpIC8gbWVuZGVrYXRpIGRlYWRsaW5lKQAAAAAAAAxQbGVkZ2VMb2NrZWQAAAArAAAAKEp1bWxhaCBwZW5hcmlrYW4gbWVsZWJpaGkgZG9uYXNpIGRvbmF0dXIAAAAVQW1vdW50RXhjZWVkc0RvbmF0aW9uAAAAAAAALAAAAEQoVGlkYWsgZGlwYWthaSBsYWdpIHNlamFrIGNvbnN0cnVjdG9yKSBQbGF0Zm9ybSBzdWRhaCBkaWluaXNpYWxpc2FzaQAAABJBbHJlYWR5SW5pdGlhbGl6ZWQAAAAAAC0AAAAbRmVlIG1lbGViaWhpIGJhdGFzIG1ha3NpbXVtAAAAAApJbnZhbGlkRmVlAAAAAAAuAAAAKUtvbmZpZ3VyYXNpIHBsYXRmb3JtIGJlbHVtIGRpaW5pc2lhbGlzYXNpAAAAAAAADk5vdEluaXRpYWxpemVkAAAAAAAvAAAAIktvbnRyYWsgc2VkYW5nIGRpLXBhdXNlIG9sZWggYWRtaW4AAAAAAA5Db250cmFjdFBhdXNlZAAAAAAAMAAAACZUaWRhayBhZGEgdHJhbnNmZXIgYWRtaW4geWFuZyBtZW51bmdndQAAAAAADk5vUGVuZGluZ0FkbWluAAAAAAAxAAAAMFRyYW5zZmVyIHRva2VuIGdhZ2FsIChtaXMuIHNhbGRvIGRvbmF0dXIga3VyYW5nKQAAABNUb2tlblRyYW5zZmVyRmFpbGVkAAAAADI=",
+        "AAAAAAAAAHFEb25hdHVyIG1lbWJlcmlrYW4gc3VhcmEgdW50dWsgcHJvcG9zYWwgeWFuZyBzZWRhbmcgYmVyamFsYW4uCkJvYm90IHN1YXJhID0gdG90YWwgZG9uYXNpIGRvbmF0dXIga2UgY2FtcGFpZ24gaW5pLgAAAAAAAAR2b3RlAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAdhcHByb3ZlAAAAAAEAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAABQAAAEJ0b3BpY3MgWyJjYW1wYWlnbl9jYW5jZWxsZWQiLCBjYW1wYWlnbl9pZCwgb3duZXJdLCBkYXRhIHsgcmFpc2VkIH0AAAAAAAAAAAARQ2FtcGFpZ25DYW5jZWxsZWQAAAAAAAABAAAAEmNhbXBhaWduX2NhbmNlbGxlZAAAAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAABnJhaXNlZAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAHh0b3BpY3MgWyJleHRlbnNpb25fb2JqZWN0ZWQiLCBjYW1wYWlnbl9pZCwgZG9ub3JdLCBkYXRhIG9iamVjdGlvbnMKKGp1bWxhaCBkb25hdHVyIHlhbmcga2ViZXJhdGFuIHNldGVsYWgga2ViZXJhdGFuIGluaSkAAAAAAAAAEUV4dGVuc2lvbk9iamVjdGVkAAAAAAAAAQAAABJleHRlbnNpb25fb2JqZWN0ZWQAAAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAAAAAAAApvYmplY3Rpb25zAAAAAAAEAAAAAAAAAAA=",
+        "AAAABQAAAEV0b3BpY3MgWyJtaWxlc3RvbmVfcmVqZWN0ZWQiLCBjYW1wYWlnbl9pZCwgaW5kZXhdLCBkYXRhIHsgcmVtYWluaW5nIH0AAAAAAAAAAAAAEU1pbGVzdG9uZVJlamVjdGVkAAAAAAAAAQAAABJtaWxlc3RvbmVfcmVqZWN0ZWQAAAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAAAAAAVpbmRleAAAAAAAAAQAAAABAAAAAAAAAAlyZW1haW5pbmcAAAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAEJ0b3BpY3MgWyJtaWxlc3RvbmVfcmVsZWFzZWQiLCBjYW1wYWlnbl9pZCwgaW5kZXhdLCBkYXRhIHsgYW1vdW50IH0AAAAAAAAAAAARTWlsZXN0b25lUmVsZWFzZWQAAAAAAAABAAAAEm1pbGVzdG9uZV9yZWxlYXNlZAAAAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAAAAAAABWluZGV4AAAAAAAABAAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAGJ0b3BpY3MgWyJwcm9wb3NhbF9maW5hbGl6ZWQiLCBjYW1wYWlnbl9pZCwgbWlsZXN0b25lXSwgZGF0YSB7IGFwcHJvdmVkLCB2b3Rlc19mb3IsIHZvdGVzX2FnYWluc3QgfQAAAAAAAAAAABFQcm9wb3NhbEZpbmFsaXplZAAAAAAAAAEAAAAScHJvcG9zYWxfZmluYWxpemVkAAAAAAAFAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAAAAAAJbWlsZXN0b25lAAAAAAAABAAAAAEAAAAAAAAACGFwcHJvdmVkAAAAAQAAAAAAAAAAAAAACXZvdGVzX2ZvcgAAAAAAAAsAAAAAAAAAAAAAAA12b3Rlc19hZ2FpbnN0AAAAAAAACwAAAAAAAAAC",
+        "AAAAAAAAAGtBZG1pbiBtZW5naGVudGlrYW4gc2VtZW50YXJhIGBjcmVhdGVfY2FtcGFpZ25gICYgYGRvbmF0ZWAuClJlZnVuZCwgd2l0aGRyYXdfcGxlZGdlICYgcGF5b3V0IHRldGFwIGJlcmphbGFuLgAAAAAFcGF1c2UAAAAAAAAAAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAAOpEb25hc2kga2UgY2FtcGFpZ24gdGVydGVudHUgbWVuZ2d1bmFrYW4gdG9rZW4gdHJhbnNmZXIuCmB0aWVyYCBvcHNpb25hbDogaW5kZXggcmV3YXJkIHRpZXIgeWFuZyBkaXBpbGloIGRvbmF0dXIuCk1lbmdlbWJhbGlrYW4ganVtbGFoIHlhbmcgZGl0ZXJpbWEgKGJpc2EgbGViaWgga2VjaWwgZGFyaSBgYW1vdW50YApqaWthIGNhbXBhaWduIG1lbWFrYWkgT3ZlckxpbWl0UG9saWN5OjpQYXJ0aWFsQWNjZXB0KS4AAAAAAAZkb25hdGUAAAAAAAQAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAR0aWVyAAAD6AAAAAQAAAABAAAD6QAAAAsAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+        "AAAAAAAAAF5SZWZ1bmQgbWVjaGFuaXNtIGppa2EgY2FtcGFpZ24gZ2FnYWwgYXRhdSBkaWJhdGFsa2FuIG93bmVyCihoYW55YSBiaXNhIGRpcGFuZ2dpbCBvbGVoIGRvbmF0dXIpAAAAAAAGcmVmdW5kAAAAAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAD6QAAAAsAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+        "AAAAAAAAAOpNZW1pbmRhaGthbiBtYWtzaW1hbCBgbGltaXRgIGNhbXBhaWduIGRhcmkgbWFwIENBTVBBSUdOUyAoc2NoZW1hIDEpCmtlIGtleSBwZXIgY2FtcGFpZ24uIEJvbGVoIGRpcGFuZ2dpbCBzaWFwYSBzYWphIGthcmVuYSBkYXRhIHRpZGFrCmJlcnViYWg7IHBhbmdnaWwgYmVydWxhbmcgc2FtcGFpIGhhc2lsbnlhIDAuIE1lbmdlbWJhbGlrYW4ganVtbGFoCmNhbXBhaWduIGxhbWEgeWFuZyBiZWx1bSBkaXBpbmRhaC4AAAAAAAdtaWdyYXRlAAAAAAEAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAAE",
+        "AAAAAAAAAKlBZG1pbiBtZW5qYWR3YWxrYW4gZmVlIGJhcnUgKG1ha3MgYG1heF9mZWVfYnBzYCkuIEJlcmxha3UgdW50dWsKY2FtcGFpZ24geWFuZyBkaWJ1YXQgc2V0ZWxhaCB0aW1lbG9jayBGRUVfVElNRUxPQ0sgbGV3YXQuCk1lbmdlbWJhbGlrYW4gdGltZXN0YW1wIGZlZSBiYXJ1IG11bGFpIGJlcmxha3UuAAAAAAAAB3NldF9mZWUAAAAAAQAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAQAAA+kAAAAGAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAB1BZG1pbiBtZW1idWthIGtlbWJhbGkga29udHJhawAAAAAAAAd1bnBhdXNlAAAAAAAAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAMlBZG1pbiBtZW5nZ2FudGkga29kZSBrb250cmFrIGRlbmdhbiBXQVNNIHlhbmcgc3VkYWggZGktdXBsb2FkLgpBZG1pbiBkaXRldGFwa2FuIG9sZWggY29uc3RydWN0b3Igc2FhdCBkZXBsb3ksIGphZGkgdGlkYWsgYmlzYSBkaXJlYnV0LgpTdG9yYWdlIHRldGFwOyBqYWxhbmthbiBgbWlncmF0ZWAgamlrYSB2ZXJzaSBiYXJ1IG1lbmd1YmFoIGxheW91dC4AAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAABhHZXQgY2FtcGFpZ24gZ29hbCBhbW91bnQAAAAIZ2V0X2dvYWwAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAALAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAHtDaGVjayBhcGFrYWggZG9uYXR1ciBzdWRhaCB2b3RpbmcgdW50dWsgbWlsZXN0b25lIHRlcnRlbnR1LgpgTm9uZWAgamlrYSBiZWx1bSB2b3RpbmcsIGBTb21lKHRydWUvZmFsc2UpYCBzZXN1YWkgcGlsaWhhbm55YS4AAAAACGdldF92b3RlAAAAAwAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAAAAAAJbWlsZXN0b25lAAAAAAAABAAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAA+gAAAAB",
+        "AAAAAAAAADZDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggYmVyYWtoaXIgKGRlYWRsaW5lIHBhc3NlZCkAAAAAAAhpc19lbmRlZAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAAAEAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+        "AAAAAAAAAOBPd25lciBtZW5hcmlrIGRhbmEgY2FtcGFpZ24geWFuZyBiZXJoYXNpbCAoZGVhZGxpbmUgbGV3YXQgJiBnb2FsIHRlcmNhcGFpLAphdGF1IGFkYSBkYW5hIHRlcmt1bXB1bCB1bnR1ayBtb2RlIEtlZXBJdEFsbCkuCkhhbnlhIGJpc2EgZGlsYWt1a2FuIHNla2FsaS4gTWVuZ2VtYmFsaWthbiBqdW1sYWggeWFuZyBkaXRlcmltYSBvd25lcgooc2V0ZWxhaCBkaXBvdG9uZyBmZWUgcGxhdGZvcm0pLgAAAAh3aXRoZHJhdwAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAAAsAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+        "AAAAAAAAABJHZXQgYWRtaW4gcGxhdGZvcm0AAAAAAAlnZXRfYWRtaW4AAAAAAAAAAAAAAQAAA+kAAAATAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAADFHZXQgZGFmdGFyIHJld2FyZCB0aWVyIGNhbXBhaWduIGJlc2VydGEgc2lzYSBzbG90AAAAAAAACWdldF90aWVycwAAAAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAA+oAAAfQAAAACFRpZXJJbmZvAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAACJDZWsgYXBha2FoIGtvbnRyYWsgc2VkYW5nIGRpLXBhdXNlAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAEJHZXQgZXNjcm93IG1pbGVzdG9uZSBjYW1wYWlnbiAobWlsZXN0b25lLCBjYXJhIGFwcHJvdmFsICYgcHJvZ3JlcykAAAAAAApnZXRfZXNjcm93AAAAAAABAAAAAAAAAAJpZAAAAAAABgAAAAEAAAPpAAAH0AAAAAZFc2Nyb3cAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAAEVHZXQgc3RhdHVzIGNhbXBhaWduIHNhYXQgaW5pIChsaWhhdCB0YWJlbCB0cmFuc2lzaSBkaSBDYW1wYWlnblN0YXR1cykAAAAAAAAKZ2V0X3N0YXR1cwAAAAAAAQAAAAAAAAACaWQAAAAAAAYAAAABAAAD6QAAB9AAAAAOQ2FtcGFpZ25TdGF0dXMAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAADNDaGVjayBhcGFrYWggZGFuYSBjYW1wYWlnbiBzdWRhaCBkaXRhcmlrIG9sZWggb3duZXIAAAAACmlzX2NsYWltZWQAAAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAAAEAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
         "AAAAAAAAACZHZXQgSUQgKHU2NCkgdW50dWsgY2FtcGFpZ24gYmVyaWt1dG55YQAAAAAAC2dldF9uZXh0X2lkAAAAAAAAAAABAAAABg==",
-        "AAAAAAAAAD1HZXQgdG90YWwgYW1vdW50IHlhbmcgc3VkYWggdGVya3VtcHVsIHVudHVrIGNhbXBhaWduIHRlcnRlbnR1AAAAAAAAEGdldF90b3RhbF9yYWlzZWQAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAs=",
-        "AAAAAAAAAEJHZXQgYmVyYXBhIGJhbnlhayBzcGVjaWZpYyBkb25vciBzdWRhaCBkb25hdGUga2UgY2FtcGFpZ24gdGVydGVudHUAAAAAAAxnZXRfZG9uYXRpb24AAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
-        "AAAAAAAAABhHZXQgY2FtcGFpZ24gZ29hbCBhbW91bnQAAAAIZ2V0X2dvYWwAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAs=",
-        "AAAAAAAAAB9HZXQgY2FtcGFpZ24gZGVhZGxpbmUgdGltZXN0YW1wAAAAAAxnZXRfZGVhZGxpbmUAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAY=",
-        "AAAAAAAAACZDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggcmVhY2ggZ29hbAAAAAAAD2lzX2dvYWxfcmVhY2hlZAAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAE=",
-        "AAAAAAAAADZDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggYmVyYWtoaXIgKGRlYWRsaW5lIHBhc3NlZCkAAAAAAAhpc19lbmRlZAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAAAQ==",
-        "AAAAAAAAACtDYWxjdWxhdGUgcHJvZ3Jlc3MgcGVyY2VudGFnZSBkYXJpIGNhbXBhaWduAAAAABdnZXRfcHJvZ3Jlc3NfcGVyY2VudGFnZQAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAAAs=" ]),
+        "AAAAAAAAADdMYW5na2FoIDIgdHJhbnNmZXIgYWRtaW46IGNhbG9uIGFkbWluIG1lbmVyaW1hIHBlcmFubnlhAAAAAAxhY2NlcHRfYWRtaW4AAAAAAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAADRHZXQgZGF0YSBsZW5na2FwIGRhcmkgc2F0dSBjYW1wYWlnbiAodW50dWsgZnJvbnRlbmQpAAAADGdldF9jYW1wYWlnbgAAAAEAAAAAAAAAAmlkAAAAAAAGAAAAAQAAA+kAAAfQAAAACENhbXBhaWduAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAB9HZXQgY2FtcGFpZ24gZGVhZGxpbmUgdGltZXN0YW1wAAAAAAxnZXRfZGVhZGxpbmUAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAAGAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAEJHZXQgYmVyYXBhIGJhbnlhayBzcGVjaWZpYyBkb25vciBzdWRhaCBkb25hdGUga2UgY2FtcGFpZ24gdGVydGVudHUAAAAAAAxnZXRfZG9uYXRpb24AAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAD6QAAAAsAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+        "AAAAAAAAAEBHZXQgbWV0YWRhdGEgdGFtcGlsYW4gY2FtcGFpZ24gKGp1ZHVsLCBkZXNrcmlwc2ksIFVSSSwga2F0ZWdvcmkpAAAADGdldF9tZXRhZGF0YQAAAAEAAAAAAAAAAmlkAAAAAAAGAAAAAQAAA+kAAAfQAAAAEENhbXBhaWduTWV0YWRhdGEAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+        "AAAAAAAAACxHZXQgcHJvcG9zYWwgbWlsZXN0b25lIHlhbmcgc2VkYW5nIGRpLXZvdGluZwAAAAxnZXRfcHJvcG9zYWwAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAfQAAAACFByb3Bvc2FsAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAACxDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggZGliYXRhbGthbiBvd25lcgAAAAxpc19jYW5jZWxsZWQAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAABAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAMtDb25zdHJ1Y3Rvcjogc2V0IGFkbWluICYgZmVlIHBsYXRmb3JtIHNhYXQgZGVwbG95LiBEaWphbGFua2FuIGF0b21pawpiZXJzYW1hIGRlcGxveSwgamFkaSB0aWRhayBhZGEgY2VsYWggdW50dWsgbWVyZWJ1dCBwZXJhbiBhZG1pbi4KYG1heF9mZWVfYnBzYCBoYXJ1cyBkaSBiYXdhaCAxMDAlIHN1cGF5YSBvd25lciBzZWxhbHUgbWVuZXJpbWEgcGF5b3V0LgAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAQAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIdHJlYXN1cnkAAAATAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAAAAAAC21heF9mZWVfYnBzAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAElHZXQgaW5kZXggcmV3YXJkIHRpZXIgeWFuZyBkaXBpbGloIGRvbmF0dXIgKE5vbmUgamlrYSB0aWRhayBtZW1pbGloIHRpZXIpAAAAAAAADmdldF9kb25vcl90aWVyAAAAAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAD6QAAA+gAAAAEAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAClHZXQga29uZmlndXJhc2kgZmVlIHlhbmcgYmVybGFrdSBzYWF0IGluaQAAAAAAAA5nZXRfZmVlX2NvbmZpZwAAAAAAAAAAAAEAAAPpAAAH0AAAAAlGZWVDb25maWcAAAAAAAfQAAAAEUNyb3dkZnVuZGluZ0Vycm9yAAAA",
+        "AAAAAAAAAIhEYWZ0YXIgY2FtcGFpZ24gYmVyZGFzYXJrYW4gdXJ1dGFuIElELCBtdWxhaSBkYXJpIElEIGBzdGFydGAuCklEIHlhbmcgYmVsdW0gZGlwaW5kYWggZGFyaSBtYXAgQ0FNUEFJR05TIGxhbWEgKGxpaGF0IGBtaWdyYXRlYCkgZGlsZXdhdGkuAAAADmxpc3RfY2FtcGFpZ25zAAAAAAACAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPpAAAD6gAAB9AAAAAPQ2FtcGFpZ25TdW1tYXJ5AAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAAHpMYW5na2FoIDEgdHJhbnNmZXIgYWRtaW46IGFkbWluIG1lbnVuanVrIGNhbG9uIGFkbWluIGJhcnUuCkFkbWluIGxhbWEgdGV0YXAgYmVybGFrdSBzYW1wYWkgY2Fsb24gbWVtYW5nZ2lsIGBhY2NlcHRfYWRtaW5gLgAAAAAADnRyYW5zZmVyX2FkbWluAAAAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAAKpPd25lciBtZW1iYXRhbGthbiBjYW1wYWlnbiB5YW5nIG1hc2loIGJlcmphbGFuLgpEb25hc2kgYmFydSBsYW5nc3VuZyBkaXRvbGFrIGRhbiBzZW11YSBkb25hdHVyIGJpc2EgYHJlZnVuZGAKdGFucGEgbWVudW5nZ3UgZGVhZGxpbmUsIGJlcmFwYXB1biBkYW5hIHlhbmcgc3VkYWggdGVya3VtcHVsLgAAAAAAD2NhbmNlbF9jYW1wYWlnbgAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAAGpNZW1idWF0IGNhbXBhaWduIGJhcnUuClNpYXBhcHVuIGJpc2EgbWVtYW5nZ2lsIGluaS4KTWVuZ2VtYmFsaWthbiBJRCAodTY0KSBkYXJpIGNhbXBhaWduIHlhbmcgYmFydSBkaWJ1YXQuAAAAAAAPY3JlYXRlX2NhbXBhaWduAAAAAAgAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAEZ29hbAAAAAsAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZjb25maWcAAAAAB9AAAAAOQ2FtcGFpZ25Db25maWcAAAAAAAAAAAAIbWV0YWRhdGEAAAfQAAAAEENhbXBhaWduTWV0YWRhdGEAAAAAAAAACm1pbGVzdG9uZXMAAAAAA+gAAAfQAAAADU1pbGVzdG9uZVBsYW4AAAAAAAAAAAAABXRpZXJzAAAAAAAD6gAAB9AAAAAKUmV3YXJkVGllcgAAAAAAAQAAA+kAAAAGAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAP9Pd25lciBtZW1wZXJwYW5qYW5nIGRlYWRsaW5lIGNhbXBhaWduIHlhbmcgbWFzaWggQWN0aXZlLgpUb3RhbCBwZXJwYW5qYW5nYW4gZGliYXRhc2kgYG1heF9leHRlbnNpb25gLCBkYW4gamlrYSBgb2JqZWN0aW9uX2Jwc2AKZGktc2V0LCBkaWJsb2tpciBzYWF0IHBlcnNlbnRhc2UgZG9uYXR1ciB5YW5nIGtlYmVyYXRhbiBtZW5jYXBhaSBiYXRhcwp0ZXJzZWJ1dCAoZGloaXR1bmcgcGVyIGRvbmF0dXIsIGJ1a2FuIHBlciBqdW1sYWggZG9uYXNpKS4AAAAAD2V4dGVuZF9kZWFkbGluZQAAAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAxuZXdfZGVhZGxpbmUAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAAEtHZXQgcGVydWJhaGFuIGZlZSB5YW5nIGRpamFkd2Fsa2FuIChOb25lIGppa2EgdGlkYWsgYWRhIGF0YXUgc3VkYWggYmVybGFrdSkAAAAAD2dldF9wZW5kaW5nX2ZlZQAAAAAAAAAAAQAAA+gAAAfQAAAAClBlbmRpbmdGZWUAAA==",
+        "AAAAAAAAACZDaGVjayBhcGFrYWggY2FtcGFpZ24gc3VkYWggcmVhY2ggZ29hbAAAAAAAD2lzX2dvYWxfcmVhY2hlZAAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAABAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAKJPd25lciBtZW5ndWJhaCBtZXRhZGF0YSBjYW1wYWlnbi4KSGFueWEgYmlzYSBzZWxhbWEgYmVsdW0gYWRhIGRvbmFzaSBkYW4gY2FtcGFpZ24gYmVsdW0gYmVyYWtoaXIsCnN1cGF5YSBkb25hdHVyIHRpZGFrICJ0ZXJ0aXB1IiBvbGVoIHBlcnViYWhhbiBzZXRlbGFoIGJlcmRvbmFzaS4AAAAAAA91cGRhdGVfbWV0YWRhdGEAAAAAAgAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAAAAAAIbWV0YWRhdGEAAAfQAAAAEENhbXBhaWduTWV0YWRhdGEAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAIFEb25hdHVyIG1lbmFyaWsgc2ViYWdpYW4gLyBzZWx1cnVoIGRvbmFzaW55YSBzZWxhbWEgY2FtcGFpZ24gQWN0aXZlCihqaWthIGRpaXppbmthbiBjYW1wYWlnbikuIE1lbmdlbWJhbGlrYW4gc2lzYSBkb25hc2kgZG9uYXR1ci4AAAAAAAAPd2l0aGRyYXdfcGxlZGdlAAAAAAMAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAALAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAD1HZXQgdG90YWwgYW1vdW50IHlhbmcgc3VkYWggdGVya3VtcHVsIHVudHVrIGNhbXBhaWduIHRlcnRlbnR1AAAAAAAAEGdldF90b3RhbF9yYWlzZWQAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAALAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAHBEb25hdHVyIG1lbnlhdGFrYW4ga2ViZXJhdGFuIGF0YXMgcGVycGFuamFuZ2FuIGRlYWRsaW5lLgpTZXRpYXAgZG9uYXR1ciBkaWhpdHVuZyBzYXR1IGthbGksIGJlcmFwYXB1biBkb25hc2lueWEuAAAAEG9iamVjdF9leHRlbnNpb24AAAACAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAIZBcHByb3ZlciBtZW5vbGFrIG1pbGVzdG9uZSBiZXJpa3V0bnlhLiBDYW1wYWlnbiBtZW5qYWRpIEZhaWxlZCBkYW4KZG9uYXR1ciBiaXNhIGByZWZ1bmRgIGJhZ2lhbiBwcm8gcmF0YSBkYXJpIGRhbmEgeWFuZyBiZWx1bSBkaXJpbGlzLgAAAAAAEHJlamVjdF9taWxlc3RvbmUAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAANBBcHByb3ZlciBtZW55ZXR1anVpIG1pbGVzdG9uZSBiZXJpa3V0bnlhIGRhbiBtZXJpbGlzIGJhZ2lhbm55YSBrZSBvd25lci4KSGFueWEgdW50dWsgY2FtcGFpZ24gYmVyLW1pbGVzdG9uZSB5YW5nIHN1ZGFoIFN1Y2NlZWRlZCwgc2ViZWx1bSBkdWUgZGF0ZQptaWxlc3RvbmUgdGVyc2VidXQuIE1lbmdlbWJhbGlrYW4ganVtbGFoIHlhbmcgZGl0ZXJpbWEgb3duZXIuAAAAEWFwcHJvdmVfbWlsZXN0b25lAAAAAAAAAQAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAPpAAAACwAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAAP1NZW51dHVwIHByb3Bvc2FsIHNldGVsYWggcGVyaW9kZSB2b3RpbmcgYmVyYWtoaXIgKHNpYXBhcHVuIGJpc2EgbWVtYW5nZ2lsKS4KSmlrYSBxdW9ydW0gJiB0aHJlc2hvbGQgdGVycGVudWhpIG1pbGVzdG9uZSBkaXJpbGlzIGtlIG93bmVyLCBqaWthIHRpZGFrCmNhbXBhaWduIG1lbmphZGkgRmFpbGVkIGRhbiBkb25hdHVyIGJpc2EgcmVmdW5kIHNpc2EgZGFuYS4KTWVuZ2VtYmFsaWthbiBgdHJ1ZWAgamlrYSBwcm9wb3NhbCBkaXNldHVqdWkuAAAAAAAAEWZpbmFsaXplX3Byb3Bvc2FsAAAAAAAAAQAAAAAAAAALY2FtcGFpZ25faWQAAAAABgAAAAEAAAPpAAAAAQAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAADlHZXQgY2Fsb24gYWRtaW4geWFuZyBiZWx1bSBtZW5lcmltYSAoTm9uZSBqaWthIHRpZGFrIGFkYSkAAAAAAAARZ2V0X3BlbmRpbmdfYWRtaW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAJlPd25lciBtZW5nYWp1a2FuIHBlbmNhaXJhbiBtaWxlc3RvbmUgYmVyaWt1dG55YSB1bnR1ayBkaS12b3RpbmcgZG9uYXR1ci4KSGFydXMgZGlhanVrYW4gc2ViZWx1bSBkdWUgZGF0ZSBtaWxlc3RvbmUuIE1lbmdlbWJhbGlrYW4gdGltZXN0YW1wIGFraGlyIHZvdGluZy4AAAAAAAARcHJvcG9zZV9taWxlc3RvbmUAAAAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAAGAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAIlHZXQgZ29hbCAmIHJhaXNlZCBjYW1wYWlnbiBkYWxhbSBzYXR1YW4gdG9rZW4sIG1lbWFrYWkgYGRlY2ltYWxzKClgCmRhcmkga29udHJhayB0b2tlbiBjYW1wYWlnbiAobWlzLiAyNV8wMDBfMDAwIEAgNyBkZXNpbWFsID0gMi41IHRva2VuKQAAAAAAABJnZXRfY2FtcGFpZ25fdW5pdHMAAAAAAAEAAAAAAAAAC2NhbXBhaWduX2lkAAAAAAYAAAABAAAD6QAAB9AAAAANQ2FtcGFpZ25Vbml0cwAAAAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAAGBHZXQgdmVyc2kgbGF5b3V0IHN0b3JhZ2UuIEtvbnRyYWsgbGFtYSB0YW5wYSB2ZXJzaSB0ZXJzaW1wYW4gPSB2ZXJzaSAxCnNhbXBhaSBgbWlncmF0ZWAgc2VsZXNhaS4AAAASZ2V0X3NjaGVtYV92ZXJzaW9uAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAOBEYWZ0YXIgY2FtcGFpZ24geWFuZyBtYXNpaCBtZW5lcmltYSBkb25hc2ksIG11bGFpIGRhcmkgSUQgYHN0YXJ0YC4KQmVyaGVudGkgc2V0ZWxhaCBgbGltaXRgIGNhbXBhaWduIGFrdGlmIGF0YXUgTUFYX1NDQU4gSUQgZGlwZXJpa3NhLApqYWRpIGhhbGFtYW4gYmlzYSBrdXJhbmcgZGFyaSBgbGltaXRgOyBsYW5qdXRrYW4gZGFyaSBgbmV4dGAKc2FtcGFpIGBuZXh0YCBiZXJuaWxhaSBOb25lLgAAABVsaXN0X2FjdGl2ZV9jYW1wYWlnbnMAAAAAAAACAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAfQAAAACkFjdGl2ZVBhZ2UAAA==",
+        "AAAAAAAAACtDYWxjdWxhdGUgcHJvZ3Jlc3MgcGVyY2VudGFnZSBkYXJpIGNhbXBhaWduAAAAABdnZXRfcHJvZ3Jlc3NfcGVyY2VudGFnZQAAAAABAAAAAAAAAAtjYW1wYWlnbl9pZAAAAAAGAAAAAQAAA+kAAAALAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAAHZEYWZ0YXIgY2FtcGFpZ24gbWlsaWsgYG93bmVyYC4gYHN0YXJ0YCBhZGFsYWggcG9zaXNpIGRpIGluZGV4IG93bmVyCigwID0gY2FtcGFpZ24gcGVydGFtYSB5YW5nIGRpYnVhdCBvd25lciB0ZXJzZWJ1dCkuAAAAAAAXbGlzdF9jYW1wYWlnbnNfYnlfb3duZXIAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPpAAAD6gAAB9AAAAAPQ2FtcGFpZ25TdW1tYXJ5AAAAB9AAAAARQ3Jvd2RmdW5kaW5nRXJyb3IAAAA=",
+        "AAAAAAAAAFBEYWZ0YXIgY2FtcGFpZ24geWFuZyBtZW5ndW1wdWxrYW4gYHRva2VuYC4gYHN0YXJ0YCBhZGFsYWggcG9zaXNpIGRpIGluZGV4IHRva2VuLgAAABdsaXN0X2NhbXBhaWduc19ieV90b2tlbgAAAAADAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+kAAAPqAAAH0AAAAA9DYW1wYWlnblN1bW1hcnkAAAAH0AAAABFDcm93ZGZ1bmRpbmdFcnJvcgAAAA==",
+        "AAAAAAAAADRHZXQganVtbGFoIGNhbXBhaWduIG1pbGlrIGBvd25lcmAgKHVudHVrIHBhZ2luYXRpb24pAAAAGGdldF9vd25lcl9jYW1wYWlnbl9jb3VudAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAAEBHZXQganVtbGFoIGNhbXBhaWduIHlhbmcgbWVuZ3VtcHVsa2FuIGB0b2tlbmAgKHVudHVrIHBhZ2luYXRpb24pAAAAGGdldF90b2tlbl9jYW1wYWlnbl9jb3VudAAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAE" ]),
       options
     )
   }
   public readonly fromJSON = {
-    create_campaign: this.txFromJSON<u64>,
-        donate: this.txFromJSON<null>,
-        refund: this.txFromJSON<i128>,
-        get_campaign: this.txFromJSON<Campaign>,
+    vote: this.txFromJSON<Result<void>>,
+        pause: this.txFromJSON<Result<void>>,
+        donate: this.txFromJSON<Result<i128>>,
+        refund: this.txFromJSON<Result<i128>>,
+        migrate: this.txFromJSON<u32>,
+        set_fee: this.txFromJSON<Result<u64>>,
+        unpause: this.txFromJSON<Result<void>>,
+        upgrade: this.txFromJSON<Result<void>>,
+        get_goal: this.txFromJSON<Result<i128>>,
+        get_vote: this.txFromJSON<Option<boolean>>,
+        is_ended: this.txFromJSON<Result<boolean>>,
+        withdraw: this.txFromJSON<Result<i128>>,
+        get_admin: this.txFromJSON<Result<string>>,
+        get_tiers: this.txFromJSON<Result<Array<TierInfo>>>,
+        is_paused: this.txFromJSON<boolean>,
+        get_escrow: this.txFromJSON<Result<Escrow>>,
+        get_status: this.txFromJSON<Result<CampaignStatus>>,
+        is_claimed: this.txFromJSON<Result<boolean>>,
         get_next_id: this.txFromJSON<u64>,
-        get_total_raised: this.txFromJSON<i128>,
-        get_donation: this.txFromJSON<i128>,
-        get_goal: this.txFromJSON<i128>,
-        get_deadline: this.txFromJSON<u64>,
-        is_goal_reached: this.txFromJSON<boolean>,
-        is_ended: this.txFromJSON<boolean>,
-        get_progress_percentage: this.txFromJSON<i128>
+        accept_admin: this.txFromJSON<Result<void>>,
+        get_campaign: this.txFromJSON<Result<Campaign>>,
+        get_deadline: this.txFromJSON<Result<u64>>,
+        get_donation: this.txFromJSON<Result<i128>>,
+        get_metadata: this.txFromJSON<Result<CampaignMetadata>>,
+        get_proposal: this.txFromJSON<Result<Proposal>>,
+        is_cancelled: this.txFromJSON<Result<boolean>>,
+        get_donor_tier: this.txFromJSON<Result<Option<u32>>>,
+        get_fee_config: this.txFromJSON<Result<FeeConfig>>,
+        list_campaigns: this.txFromJSON<Result<Array<CampaignSummary>>>,
+        transfer_admin: this.txFromJSON<Result<void>>,
+        cancel_campaign: this.txFromJSON<Result<void>>,
+        create_campaign: this.txFromJSON<Result<u64>>,
+        extend_deadline: this.txFromJSON<Result<void>>,
+        get_pending_fee: this.txFromJSON<Option<PendingFee>>,
+        is_goal_reached: this.txFromJSON<Result<boolean>>,
+        update_metadata: this.txFromJSON<Result<void>>,
+        withdraw_pledge: this.txFromJSON<Result<i128>>,
+        get_total_raised: this.txFromJSON<Result<i128>>,
+        object_extension: this.txFromJSON<Result<void>>,
+        reject_milestone: this.txFromJSON<Result<void>>,
+        approve_milestone: this.txFromJSON<Result<i128>>,
+        finalize_proposal: this.txFromJSON<Result<boolean>>,
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        propose_milestone: this.txFromJSON<Result<u64>>,
+        get_campaign_units: this.txFromJSON<Result<CampaignUnits>>,
+        get_schema_version: this.txFromJSON<u32>,
+        list_active_campaigns: this.txFromJSON<ActivePage>,
+        get_progress_percentage: this.txFromJSON<Result<i128>>,
+        list_campaigns_by_owner: this.txFromJSON<Result<Array<CampaignSummary>>>,
+        list_campaigns_by_token: this.txFromJSON<Result<Array<CampaignSummary>>>,
+        get_owner_campaign_count: this.txFromJSON<u32>,
+        get_token_campaign_count: this.txFromJSON<u32>
   }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
-import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions } from '@stellar/stellar-sdk/contract';
-import type { i128 } from '@stellar/stellar-sdk/contract';
+import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions, Result } from '@stellar/stellar-sdk/contract';
+import type { u32, i128, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -10,129 +10,653 @@ export declare const networks: {
         readonly contractId: "CARTOXDYIATAXBADV6HO7BDFKWCO47GRUUYTHJ7LVEBNN7GCZLMWR27S";
     };
 };
-export interface TokenInfo {
-    name: string;
-    symbol: string;
-    total_supply: i128;
+export type DataKey = {
+    tag: "Allowance";
+    values: readonly [AllowanceDataKey];
+} | {
+    tag: "Balance";
+    values: readonly [string];
+} | {
+    tag: "Authorized";
+    values: readonly [string];
+};
+export declare const TokenError: {
+    1: {
+        message: string;
+    };
+    2: {
+        message: string;
+    };
+    3: {
+        message: string;
+    };
+    4: {
+        message: string;
+    };
+    5: {
+        message: string;
+    };
+    6: {
+        message: string;
+    };
+    7: {
+        message: string;
+    };
+    8: {
+        message: string;
+    };
+    9: {
+        message: string;
+    };
+    10: {
+        message: string;
+    };
+    11: {
+        message: string;
+    };
+    12: {
+        message: string;
+    };
+    13: {
+        message: string;
+    };
+    14: {
+        message: string;
+    };
+};
+export interface AllowanceValue {
+    amount: i128;
+    expiration_ledger: u32;
+}
+export interface AllowanceDataKey {
+    from: string;
+    spender: string;
 }
 export interface Client {
     /**
-     * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    burn: ({ from, amount }: {
+        from: string;
+        amount: i128;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
      */
-    initialize: ({ admin, name, symbol, total_supply }: {
-        admin: string;
-        name: string;
-        symbol: string;
-        total_supply: i128;
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<null>>;
+    /**
+   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    mint: ({ to, amount }: {
+        to: string;
+        amount: i128;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    name: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+   * Construct and simulate a admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    admin: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<string>>>;
+    /**
+   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    symbol: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    approve: ({ from, spender, amount, expiration_ledger }: {
+        from: string;
+        spender: string;
+        amount: i128;
+        expiration_ledger: u32;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
     }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    balance: ({ id }: {
+        id: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    migrate: ({ limit }: {
+        limit: u32;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    upgrade: ({ new_wasm_hash }: {
+        new_wasm_hash: Buffer;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a clawback transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    clawback: ({ from, amount }: {
+        from: string;
+        amount: i128;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
      */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a decimals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    decimals: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+   * Construct and simulate a get_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
     get_name: (options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
     }) => Promise<AssembledTransaction<string>>;
     /**
-     * Construct and simulate a get_symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    transfer: ({ from, to, amount }: {
+        from: string;
+        to: string;
+        amount: i128;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<null>>;
+    /**
+   * Construct and simulate a allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    allowance: ({ from, spender }: {
+        from: string;
+        spender: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+   * Construct and simulate a burn_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    burn_from: ({ spender, from, amount }: {
+        spender: string;
+        from: string;
+        amount: i128;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<null>>;
+    /**
+   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    set_admin: ({ new_admin }: {
+        new_admin: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a authorized transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    authorized: ({ id }: {
+        id: string;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<boolean>>;
+    /**
+   * Construct and simulate a get_symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
     get_symbol: (options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
     }) => Promise<AssembledTransaction<string>>;
     /**
-     * Construct and simulate a get_total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a max_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    max_supply: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
      */
-    get_total_supply: (options?: {
+        fee?: number;
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<i128>>>;
+    /**
+   * Construct and simulate a get_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    get_balance: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<Result<i128>>>;
     /**
-     * Construct and simulate a get_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    accept_admin: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
      */
-    get_balance: (options?: {
+        fee?: number;
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    total_supply: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
     }) => Promise<AssembledTransaction<i128>>;
     /**
-     * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a auth_required transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    auth_required: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
      */
-    transfer: ({ from, to, amount }: {
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<boolean>>;
+    /**
+   * Construct and simulate a pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    pending_admin: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    transfer_from: ({ spender, from, to, amount }: {
+        spender: string;
         from: string;
         to: string;
         amount: i128;
     }, options?: {
         /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
         fee?: number;
         /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
         timeoutInSeconds?: number;
         /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
         simulate?: boolean;
     }) => Promise<AssembledTransaction<null>>;
+    /**
+   * Construct and simulate a schema_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    schema_version: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+   * Construct and simulate a set_authorized transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    set_authorized: ({ id, authorize }: {
+        id: string;
+        authorize: boolean;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a set_max_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    set_max_supply: ({ max_supply }: {
+        max_supply: i128;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a get_total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    get_total_supply: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+   * Construct and simulate a set_auth_required transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    set_auth_required: ({ required }: {
+        required: boolean;
+    }, options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Result<void>>>;
+    /**
+   * Construct and simulate a legacy_holder_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+    legacy_holder_count: (options?: {
+        /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+        fee?: number;
+        /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+        timeoutInSeconds?: number;
+        /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
 }
 export declare class Client extends ContractClient {
     readonly options: ContractClientOptions;
-    static deploy<T = Client>(
-    /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
-    options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
+    static deploy<T = Client>(/** Constructor/Initialization Args for the contract's `__constructor` method */ { admin, name, symbol, decimals, total_supply }: {
+        admin: string;
+        name: string;
+        symbol: string;
+        decimals: u32;
+        total_supply: i128;
+    }, /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */ options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
         wasmHash: Buffer | string;
         /** Salt used to generate the contract's ID. Passed through to {@link Operation.createCustomContract}. Default: random. */
@@ -142,11 +666,36 @@ export declare class Client extends ContractClient {
     }): Promise<AssembledTransaction<T>>;
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
-        initialize: (json: string) => AssembledTransaction<null>;
+        burn: (json: string) => AssembledTransaction<null>;
+        mint: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        name: (json: string) => AssembledTransaction<string>;
+        admin: (json: string) => AssembledTransaction<Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        symbol: (json: string) => AssembledTransaction<string>;
+        approve: (json: string) => AssembledTransaction<null>;
+        balance: (json: string) => AssembledTransaction<bigint>;
+        migrate: (json: string) => AssembledTransaction<number>;
+        upgrade: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        clawback: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        decimals: (json: string) => AssembledTransaction<number>;
         get_name: (json: string) => AssembledTransaction<string>;
+        transfer: (json: string) => AssembledTransaction<null>;
+        allowance: (json: string) => AssembledTransaction<bigint>;
+        burn_from: (json: string) => AssembledTransaction<null>;
+        set_admin: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        authorized: (json: string) => AssembledTransaction<boolean>;
         get_symbol: (json: string) => AssembledTransaction<string>;
+        max_supply: (json: string) => AssembledTransaction<bigint | undefined>;
+        get_balance: (json: string) => AssembledTransaction<Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        accept_admin: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        total_supply: (json: string) => AssembledTransaction<bigint>;
+        auth_required: (json: string) => AssembledTransaction<boolean>;
+        pending_admin: (json: string) => AssembledTransaction<string | undefined>;
+        transfer_from: (json: string) => AssembledTransaction<null>;
+        schema_version: (json: string) => AssembledTransaction<number>;
+        set_authorized: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        set_max_supply: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
         get_total_supply: (json: string) => AssembledTransaction<bigint>;
-        get_balance: (json: string) => AssembledTransaction<bigint>;
-        transfer: (json: string) => AssembledTransaction<null>;
+        set_auth_required: (json: string) => AssembledTransaction<Result<null, import("@stellar/stellar-sdk/contract").ErrorMessage>>;
+        legacy_holder_count: (json: string) => AssembledTransaction<number>;
     };
 }
@@ -1,5 +1,5 @@
 import { Buffer } from "buffer";
-import { Client as ContractClient, Spec as ContractSpec, } from '@stellar/stellar-sdk/contract';
+import { Client as ContractClient, Spec as ContractSpec } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -10,32 +10,143 @@ if (typeof window !== 'undefined') {
 export const networks = {
     testnet: {
         networkPassphrase: "Test SDF Network ; September 2015",
-        contractId: "CARTOXDYIATAXBADV6HO7BDFKWCO47GRUUYTHJ7LVEBNN7GCZLMWR27S",
+        contractId: "CARTOXDYIATAXBADV6HO7BDFKWCO47GRUUYTHJ7LVEBNN7GCZLMWR27S"
+    }
+};
+export const TokenError = {
+    1: {
+        message: "AlreadyInitialized"
+    },
+    2: {
+        message: "NotInitialized"
+    },
+    3: {
+        message: "InvalidSupply"
+    },
+    4: {
+        message: "AmountNotPositive"
+    },
+    5: {
+        message: "NegativeAmount"
+    },
+    6: {
+        message: "InsufficientBalance"
+    },
+    7: {
+        message: "InsufficientAllowance"
+    },
+    8: {
+        message: "InvalidExpirationLedger"
+    },
+    9: {
+        message: "Deprecated"
+    },
+    10: {
+        message: "MaxSupplyExceeded"
+    },
+    11: {
+        message: "InvalidMaxSupply"
+    },
+    12: {
+        message: "NoPendingAdmin"
+    },
+    13: {
+        message: "NotAuthorized"
+    },
+    14: {
+        message: "InvalidDecimals"
     }
 };
 export class Client extends ContractClient {
     options;
-    static async deploy(
-    /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
-    options) {
-        return ContractClient.deploy(null, options);
+    static async deploy({ admin, name, symbol, decimals, total_supply }, options) {
+        return ContractClient.deploy({
+            admin,
+            name,
+            symbol,
+            decimals,
+            total_supply
+        }, options);
     }
-    constructor(options) {
-        super(new ContractSpec(["AAAAAQAAAAAAAAAAAAAACVRva2VuSW5mbwAAAAAAAAMAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAZzeW1ib2wAAAAAABAAAAAAAAAADHRvdGFsX3N1cHBseQAAAAs=",
-            "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAABAAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAGc3ltYm9sAAAAAAAQAAAAAAAAAAx0b3RhbF9zdXBwbHkAAAALAAAAAA==",
+    constructor(options){
+        super(new ContractSpec([
+            "AAAABQAAAAAAAAAAAAAABEJ1cm4AAAABAAAABGJ1cm4AAAACAAAAAAAAAARmcm9tAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAA",
+            "AAAABQAAAAAAAAAAAAAABE1pbnQAAAABAAAABG1pbnQAAAACAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAA",
+            "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAACUFsbG93YW5jZQAAAAAAAAEAAAfQAAAAEEFsbG93YW5jZURhdGFLZXkAAAABAAAAAAAAAAdCYWxhbmNlAAAAAAEAAAATAAAAAQAAAAAAAAAKQXV0aG9yaXplZAAAAAAAAQAAABM=",
+            "AAAABQAAAAAAAAAAAAAAB0FwcHJvdmUAAAAAAQAAAAdhcHByb3ZlAAAAAAQAAAAAAAAABGZyb20AAAATAAAAAQAAAAAAAAAHc3BlbmRlcgAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAARZXhwaXJhdGlvbl9sZWRnZXIAAAAAAAAEAAAAAAAAAAE=",
+            "AAAABQAAAAAAAAAAAAAACENsYXdiYWNrAAAAAQAAAAhjbGF3YmFjawAAAAIAAAAAAAAABGZyb20AAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAA=",
+            "AAAABQAAAAAAAAAAAAAACFNldEFkbWluAAAAAQAAAAlzZXRfYWRtaW4AAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAAAAAAA=",
+            "AAAABQAAAAAAAAAAAAAACFRyYW5zZmVyAAAAAQAAAAh0cmFuc2ZlcgAAAAMAAAAAAAAABGZyb20AAAATAAAAAQAAAAAAAAACdG8AAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAA==",
+            "AAAABAAAAAAAAAAAAAAAClRva2VuRXJyb3IAAAAAAA4AAAAAAAAAEkFscmVhZHlJbml0aWFsaXplZAAAAAAAAQAAAAAAAAAOTm90SW5pdGlhbGl6ZWQAAAAAAAIAAAAAAAAADUludmFsaWRTdXBwbHkAAAAAAAADAAAAAAAAABFBbW91bnROb3RQb3NpdGl2ZQAAAAAAAAQAAAAAAAAADk5lZ2F0aXZlQW1vdW50AAAAAAAFAAAAAAAAABNJbnN1ZmZpY2llbnRCYWxhbmNlAAAAAAYAAAAAAAAAFUluc3VmZmljaWVudEFsbG93YW5jZQAAAAAAAAcAAAAAAAAAF0ludmFsaWRFeHBpcmF0aW9uTGVkZ2VyAAAAAAgAAAAAAAAACkRlcHJlY2F0ZWQAAAAAAAkAAAAAAAAAEU1heFN1cHBseUV4Y2VlZGVkAAAAAAAACgAAAAAAAAAQSW52YWxpZE1heFN1cHBseQAAAAsAAAAAAAAADk5vUGVuZGluZ0FkbWluAAAAAAAMAAAAAAAAAA1Ob3RBdXRob3JpemVkAAAAAAAADQAAAAAAAAAPSW52YWxpZERlY2ltYWxzAAAAAA4=",
+            "AAAAAAAAAAAAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+            "AAAAAAAAAAAAAAAEbWludAAAAAIAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApUb2tlbkVycm9yAAA=",
+            "AAAAAAAAAAAAAAAEbmFtZQAAAAAAAAABAAAAEA==",
+            "AAAAAAAAAAAAAAAFYWRtaW4AAAAAAAAAAAAAAQAAA+kAAAATAAAH0AAAAApUb2tlbkVycm9yAAA=",
+            "AAAAAAAAAAAAAAAGc3ltYm9sAAAAAAAAAAAAAQAAABA=",
+            "AAAABQAAAAAAAAAAAAAADVNldEF1dGhvcml6ZWQAAAAAAAABAAAADnNldF9hdXRob3JpemVkAAAAAAACAAAAAAAAAAJpZAAAAAAAEwAAAAEAAAAAAAAACWF1dGhvcml6ZQAAAAAAAAEAAAAAAAAAAA==",
+            "AAAABQAAAAAAAAAAAAAADVRyYW5zZmVyTXV4ZWQAAAAAAAABAAAACHRyYW5zZmVyAAAABAAAAAAAAAAEZnJvbQAAABMAAAABAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAAAAAAAC3RvX211eGVkX2lkAAAAAAYAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+            "AAAAAQAAAAAAAAAAAAAADkFsbG93YW5jZVZhbHVlAAAAAAACAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABA==",
+            "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAHc3BlbmRlcgAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAEWV4cGlyYXRpb25fbGVkZ2VyAAAAAAAABAAAAAA=",
+            "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAJpZAAAAAAAEwAAAAEAAAAL",
+            "AAAAAAAAAAAAAAAHbWlncmF0ZQAAAAABAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAABA==",
+            "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApUb2tlbkVycm9yAAA=",
+            "AAAAAAAAAAAAAAAIY2xhd2JhY2sAAAACAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAKVG9rZW5FcnJvcgAA",
+            "AAAAAAAAAAAAAAAIZGVjaW1hbHMAAAAAAAAAAQAAAAQ=",
             "AAAAAAAAAAAAAAAIZ2V0X25hbWUAAAAAAAAAAQAAABA=",
+            "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABQAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+            "AAAAAQAAAAAAAAAAAAAAEEFsbG93YW5jZURhdGFLZXkAAAACAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAHc3BlbmRlcgAAAAAT",
+            "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAEZnJvbQAAABMAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAEAAAAL",
+            "AAAAAAAAAAAAAAAJYnVybl9mcm9tAAAAAAAAAwAAAAAAAAAHc3BlbmRlcgAAAAATAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+            "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAAClRva2VuRXJyb3IAAA==",
+            "AAAAAAAAAAAAAAAKYXV0aG9yaXplZAAAAAAAAQAAAAAAAAACaWQAAAAAABMAAAABAAAAAQ==",
             "AAAAAAAAAAAAAAAKZ2V0X3N5bWJvbAAAAAAAAAAAAAEAAAAQ",
+            "AAAAAAAAAAAAAAAKbWF4X3N1cHBseQAAAAAAAAAAAAEAAAPoAAAACw==",
+            "AAAAAAAAAAAAAAALZ2V0X2JhbGFuY2UAAAAAAAAAAAEAAAPpAAAACwAAB9AAAAAKVG9rZW5FcnJvcgAA",
+            "AAAAAAAAAAAAAAAMYWNjZXB0X2FkbWluAAAAAAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAAClRva2VuRXJyb3IAAA==",
+            "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL",
+            "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAUAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAAIZGVjaW1hbHMAAAAEAAAAAAAAAAx0b3RhbF9zdXBwbHkAAAALAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAKVG9rZW5FcnJvcgAA",
+            "AAAAAAAAAAAAAAANYXV0aF9yZXF1aXJlZAAAAAAAAAAAAAABAAAAAQ==",
+            "AAAAAAAAAAAAAAANcGVuZGluZ19hZG1pbgAAAAAAAAAAAAABAAAD6AAAABM=",
+            "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+            "AAAAAAAAAAAAAAAOc2NoZW1hX3ZlcnNpb24AAAAAAAAAAAABAAAABA==",
+            "AAAAAAAAAAAAAAAOc2V0X2F1dGhvcml6ZWQAAAAAAAIAAAAAAAAAAmlkAAAAAAATAAAAAAAAAAlhdXRob3JpemUAAAAAAAABAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAAKVG9rZW5FcnJvcgAA",
+            "AAAAAAAAAAAAAAAOc2V0X21heF9zdXBwbHkAAAAAAAEAAAAAAAAACm1heF9zdXBwbHkAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApUb2tlbkVycm9yAAA=",
             "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3N1cHBseQAAAAAAAAABAAAACw==",
-            "AAAAAAAAAAAAAAALZ2V0X2JhbGFuY2UAAAAAAAAAAAEAAAAL",
-            "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA="]), options);
-        this.options = options;
+            "AAAAAAAAAAAAAAARc2V0X2F1dGhfcmVxdWlyZWQAAAAAAAABAAAAAAAAAAhyZXF1aXJlZAAAAAEAAAABAAAD6QAAA+0AAAAAAAAH0AAAAApUb2tlbkVycm9yAAA=",
+            "AAAAAAAAAAAAAAATbGVnYWN5X2hvbGRlcl9jb3VudAAAAAAAAAAAAQAAAAQ="
+        ]), options), this.options = options, this.fromJSON = {
+            burn: this.txFromJSON,
+            mint: this.txFromJSON,
+            name: this.txFromJSON,
+            admin: this.txFromJSON,
+            symbol: this.txFromJSON,
+            approve: this.txFromJSON,
+            balance: this.txFromJSON,
+            migrate: this.txFromJSON,
+            upgrade: this.txFromJSON,
+            clawback: this.txFromJSON,
+            decimals: this.txFromJSON,
+            get_name: this.txFromJSON,
+            transfer: this.txFromJSON,
+            allowance: this.txFromJSON,
+            burn_from: this.txFromJSON,
+            set_admin: this.txFromJSON,
+            authorized: this.txFromJSON,
+            get_symbol: this.txFromJSON,
+            max_supply: this.txFromJSON,
+            get_balance: this.txFromJSON,
+            accept_admin: this.txFromJSON,
+            total_supply: this.txFromJSON,
+            auth_required: this.txFromJSON,
+            pending_admin: this.txFromJSON,
+            transfer_from: this.txFromJSON,
+            schema_version: this.txFromJSON,
+            set_authorized: this.txFromJSON,
+            set_max_supply: this.txFromJSON,
+            get_total_supply: this.txFromJSON,
+            set_auth_required: this.txFromJSON,
+            legacy_holder_count: this.txFromJSON
+        };
     }
-    fromJSON = {
-        initialize: (this.txFromJSON),
-        get_name: (this.txFromJSON),
-        get_symbol: (this.txFromJSON),
-        get_total_supply: (this.txFromJSON),
-        get_balance: (this.txFromJSON),
-        transfer: (this.txFromJSON)
-    };
+    fromJSON;
 }
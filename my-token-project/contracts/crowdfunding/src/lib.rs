@@ -1,8 +1,197 @@
 #![no_std]
+// create_campaign menerima semua opsi campaign sebagai argumen terpisah
+// (batas Soroban 10 argumen); lint ini juga kena ke client yang di-generate
+#![allow(clippy::too_many_arguments)]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, token, Address, Env, Map, Symbol,
+    contract, contracterror, contractevent, contractimpl, contracttype, symbol_short, token,
+    Address, BytesN, Env, Map, String, Symbol, Vec,
 };
 
+// --- Error Codes ---
+/// Kode error kontrak. Nomor dipakai oleh binding TS dan kontrak pemanggil,
+/// jadi nomor yang sudah ada tidak boleh diubah.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CrowdfundingError {
+    /// Goal campaign harus lebih dari 0
+    GoalNotPositive = 1,
+    /// Deadline harus di masa depan
+    DeadlineInPast = 2,
+    /// Jumlah donasi harus lebih dari 0
+    AmountNotPositive = 3,
+    /// Campaign dengan ID tersebut tidak ada
+    CampaignNotFound = 4,
+    /// Campaign sudah berakhir, tidak menerima donasi
+    CampaignEnded = 5,
+    /// Campaign belum berakhir
+    CampaignNotEnded = 6,
+    /// Goal sudah tercapai, tidak bisa refund
+    GoalReached = 7,
+    /// Goal belum tercapai, owner tidak bisa menarik dana
+    GoalNotReached = 8,
+    /// Dana campaign sudah ditarik owner
+    AlreadyClaimed = 9,
+    /// Donatur tidak ditemukan atau sudah refund
+    NothingToRefund = 10,
+    /// Judul kosong atau terlalu panjang
+    InvalidTitle = 11,
+    /// Deskripsi terlalu panjang
+    DescriptionTooLong = 12,
+    /// URI metadata terlalu panjang atau bukan ipfs:// / https://
+    InvalidMetadataUri = 13,
+    /// Metadata tidak bisa diubah lagi (sudah ada donasi / campaign berakhir)
+    MetadataLocked = 14,
+    /// Campaign sudah dibatalkan owner
+    CampaignCancelled = 15,
+    /// Daftar milestone tidak valid (kosong, persentase tidak 100, due date tidak urut)
+    InvalidMilestones = 16,
+    /// Dana campaign ber-milestone hanya bisa dirilis lewat approve_milestone
+    FundsInEscrow = 17,
+    /// Campaign tidak punya milestone
+    NoMilestones = 18,
+    /// Tidak ada milestone yang bisa diproses (ditolak, terlewat, atau sudah dirilis semua)
+    MilestoneClosed = 19,
+    /// Milestone campaign ini diputuskan dengan cara lain (approver vs voting donatur)
+    WrongApprovalMode = 20,
+    /// Quorum / threshold / periode voting tidak valid
+    InvalidVotingConfig = 21,
+    /// Masih ada proposal yang belum difinalisasi
+    ProposalOpen = 22,
+    /// Tidak ada proposal yang sedang berjalan
+    NoProposal = 23,
+    /// Periode voting sudah berakhir
+    VotingClosed = 24,
+    /// Periode voting belum berakhir
+    VotingNotEnded = 25,
+    /// Donatur sudah memberikan suara untuk proposal ini
+    AlreadyVoted = 26,
+    /// Hanya donatur campaign yang bisa voting
+    NotDonor = 27,
+    /// Daftar reward tier tidak valid
+    InvalidTiers = 28,
+    /// Reward tier dengan index tersebut tidak ada
+    TierNotFound = 29,
+    /// Total donasi donatur di bawah minimum tier
+    TierBelowMinimum = 30,
+    /// Kuota reward tier sudah habis
+    TierSoldOut = 31,
+    /// Campaign keep-it-all: dana yang terkumpul tetap untuk owner, tidak bisa refund
+    FundsKept = 32,
+    /// Hard cap / batas donasi tidak valid
+    InvalidLimits = 33,
+    /// Donasi di bawah minimum donasi campaign
+    BelowMinDonation = 34,
+    /// Total donasi donatur melebihi batas per donatur
+    DonorLimitExceeded = 35,
+    /// Donasi melebihi hard cap campaign
+    HardCapExceeded = 36,
+    /// Waktu mulai harus sebelum deadline
+    InvalidStartTime = 37,
+    /// Campaign belum dibuka (sebelum start_time)
+    CampaignNotStarted = 38,
+    /// Perpanjangan deadline tidak valid (konfigurasi, deadline baru, atau melewati batas)
+    InvalidExtension = 39,
+    /// Perpanjangan ditolak karena keberatan donatur mencapai batas
+    ExtensionObjected = 40,
+    /// Donatur sudah menyatakan keberatan
+    AlreadyObjected = 41,
+    /// Campaign tidak mengizinkan penarikan donasi sebelum deadline
+    PledgeWithdrawalDisabled = 42,
+    /// Penarikan donasi dikunci (goal tercapai / mendekati deadline)
+    PledgeLocked = 43,
+    /// Jumlah penarikan melebihi donasi donatur
+    AmountExceedsDonation = 44,
+    /// (Tidak dipakai lagi sejak constructor) Platform sudah diinisialisasi
+    AlreadyInitialized = 45,
+    /// Fee melebihi batas maksimum
+    InvalidFee = 46,
+    /// Konfigurasi platform belum diinisialisasi
+    NotInitialized = 47,
+    /// Kontrak sedang di-pause oleh admin
+    ContractPaused = 48,
+    /// Tidak ada transfer admin yang menunggu
+    NoPendingAdmin = 49,
+    /// Transfer token gagal (mis. saldo donatur kurang)
+    TokenTransferFailed = 50,
+}
+
+// --- Status Campaign ---
+/// Status campaign. Transisi yang diizinkan:
+///
+/// | Dari      | Ke        | Pemicu                                  |
+/// |-----------|-----------|-----------------------------------------|
+/// | Pending   | Active    | `now >= start_time`                     |
+/// | Pending   | Cancelled | `cancel_campaign`                       |
+/// | Active    | Succeeded | `now >= deadline` dan dana cukup (*)    |
+/// | Active    | Failed    | `now >= deadline` dan dana tidak cukup (*) |
+/// | Active    | Succeeded | hard cap tercapai (sebelum deadline)    |
+/// | Active    | Cancelled | `cancel_campaign`                       |
+/// | Succeeded | Claimed   | `withdraw` / milestone terakhir dirilis |
+/// | Succeeded | Failed    | milestone ditolak atau lewat due date   |
+///
+/// (*) AllOrNothing: goal tercapai. KeepItAll: ada dana terkumpul (> 0).
+///
+/// Failed, Cancelled & Claimed adalah status akhir dan disimpan; status
+/// lain dihitung dari waktu ledger & dana.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CampaignStatus {
+    /// Belum dibuka untuk donasi
+    Pending = 0,
+    /// Menerima donasi
+    Active = 1,
+    /// Deadline lewat & goal tercapai, owner bisa `withdraw`
+    Succeeded = 2,
+    /// Deadline lewat & goal tidak tercapai, donatur bisa `refund`
+    Failed = 3,
+    /// Dibatalkan owner, donatur bisa `refund`
+    Cancelled = 4,
+    /// Dana sudah ditarik owner
+    Claimed = 5,
+}
+
+// --- Mode Pendanaan ---
+/// Apa yang terjadi dengan dana jika goal tidak tercapai saat deadline
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum FundingMode {
+    /// Goal tidak tercapai: donatur refund, owner tidak menerima apa-apa
+    AllOrNothing = 0,
+    /// Owner tetap menerima semua dana yang terkumpul (cocok untuk charity)
+    KeepItAll = 1,
+}
+
+/// Perlakuan donasi yang melewati hard cap / batas per donatur
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OverLimitPolicy {
+    /// Tolak seluruh donasi
+    Reject = 0,
+    /// Terima sebagian sampai batas, sisanya tidak ditransfer
+    PartialAccept = 1,
+}
+
+/// Opsi campaign yang dipilih saat create_campaign
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignConfig {
+    pub funding_mode: FundingMode,
+    pub hard_cap: Option<i128>,      // Maks total dana (>= goal), campaign otomatis ditutup
+    pub min_donation: Option<i128>,  // Minimal per donasi
+    pub max_per_donor: Option<i128>, // Maks total donasi per donatur
+    pub over_limit: OverLimitPolicy, // Reject / PartialAccept saat melewati batas
+    pub start_time: Option<u64>,     // Donasi dibuka mulai timestamp ini (None = langsung)
+    pub max_extension: u64,          // Total perpanjangan deadline maksimal (detik, 0 = tidak boleh)
+    pub objection_bps: Option<u32>,  // Perpanjangan diblokir jika >= bps donatur keberatan
+    pub pledge_withdrawal: bool,     // Donatur boleh menarik donasi selama Active
+    pub lock_on_goal: bool,          // Penarikan dikunci setelah goal tercapai
+    pub lock_window: u64,            // Penarikan dikunci N detik terakhir sebelum deadline
+}
+
 // --- Struct untuk menyimpan data Campaign ---
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -12,14 +201,1208 @@ pub struct Campaign {
     pub deadline: u64,     // Batas waktu (Unix timestamp)
     pub token: Address,    // Token yang dikumpulkan (misal: XLM, USDC)
     pub raised: i128,      // Dana terkumpul
-    pub donations: Map<Address, i128>, // Peta donatur dan jumlah donasi
+    pub released: i128,    // Dana yang sudah dikirim ke owner (withdraw / milestone)
+    pub status: CampaignStatus, // Status campaign (lihat CampaignStatus)
+    pub funding_mode: FundingMode, // AllOrNothing / KeepItAll
+    pub hard_cap: Option<i128>,      // Maks total dana (None = tanpa batas)
+    pub min_donation: Option<i128>,  // Minimal per donasi
+    pub max_per_donor: Option<i128>, // Maks total donasi per donatur
+    pub over_limit: OverLimitPolicy, // Perlakuan donasi yang melewati batas
+    pub start_time: Option<u64>,     // Waktu mulai menerima donasi (None = sejak dibuat)
+    pub max_extension: u64,          // Total perpanjangan deadline maksimal (detik)
+    pub extended: u64,               // Total perpanjangan yang sudah dipakai (detik)
+    pub objection_bps: Option<u32>,  // Batas keberatan (bps dari jumlah donatur)
+    pub donors: u32,                 // Jumlah donatur dengan donasi > 0
+    pub objections: u32,             // Jumlah donatur yang keberatan atas perpanjangan
+    pub pledge_withdrawal: bool,     // Donatur boleh menarik donasi selama Active
+    pub lock_on_goal: bool,          // Penarikan dikunci setelah goal tercapai
+    pub lock_window: u64,            // Penarikan dikunci N detik terakhir sebelum deadline
+    pub fee_bps: u32,                // Fee platform saat campaign dibuat (bps)
+    pub fee_paid: i128,              // Total fee yang sudah dikirim ke treasury
+}
+
+// --- Platform Fee ---
+// Perubahan fee baru berlaku setelah timelock (2 hari)
+const FEE_TIMELOCK: u64 = 2 * 24 * 60 * 60;
+
+/// Konfigurasi fee platform (diset lewat constructor saat deploy)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub treasury: Address, // Penerima fee
+    pub fee_bps: u32,      // Fee dari payout owner (bps)
+    pub max_fee_bps: u32,  // Batas atas fee, tidak bisa diubah
+}
+
+/// Perubahan fee yang menunggu timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingFee {
+    pub fee_bps: u32,
+    pub effective_at: u64, // Timestamp fee baru mulai berlaku
+}
+
+// --- Milestone Escrow ---
+// Batas jumlah milestone per campaign
+const MAX_MILESTONES: u32 = 10;
+
+/// Satu tahap pencairan dana
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub description: String, // Deliverable milestone
+    pub percentage: u32,     // Bagian dana yang dirilis (persen, total 100)
+    pub due_date: u64,       // Batas approve (Unix timestamp), setelah deadline
+}
+
+// Basis point: 10_000 = 100%
+const MAX_BPS: u32 = 10_000;
+
+/// Parameter voting donatur. Bobot suara = jumlah donasi.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingConfig {
+    pub quorum_bps: u32,    // Minimal total bobot suara (bps dari `raised`)
+    pub threshold_bps: u32, // Minimal bobot "setuju" (bps dari suara masuk)
+    pub voting_period: u64, // Lama voting (detik) sejak proposal dibuat
+}
+
+/// Siapa yang memutuskan setiap milestone
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Approval {
+    /// Satu address approver memanggil approve_milestone / reject_milestone
+    Approver(Address),
+    /// Owner mengajukan proposal, donatur voting, lalu finalize_proposal
+    DonorVote(VotingConfig),
+}
+
+/// Rencana milestone saat create_campaign
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestonePlan {
+    pub approval: Approval,
+    pub milestones: Vec<Milestone>,
+}
+
+/// Proposal pencairan milestone yang sedang di-voting
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub milestone: u32,      // Index milestone yang diajukan
+    pub voting_end: u64,     // Voting ditutup pada timestamp ini
+    pub votes_for: i128,     // Total bobot setuju
+    pub votes_against: i128, // Total bobot menolak
+}
+
+/// State escrow campaign ber-milestone
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Escrow {
+    pub approval: Approval,
+    pub milestones: Vec<Milestone>,
+    pub next: u32, // Index milestone berikutnya yang menunggu approval
+}
+
+// --- Reward Tier ---
+// Batas jumlah reward tier per campaign
+const MAX_TIERS: u32 = 10;
+
+/// Reward untuk donatur dengan donasi minimal tertentu
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardTier {
+    pub description: String, // Reward yang didapat (misal: "Kaos + stiker")
+    pub min_amount: i128,    // Minimal total donasi untuk tier ini
+    pub quantity: u32,       // Jumlah slot yang tersedia
+}
+
+/// Reward tier beserta sisa slot (hasil `get_tiers`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierInfo {
+    pub description: String,
+    pub min_amount: i128,
+    pub quantity: u32,
+    pub remaining: u32, // Slot yang belum diambil
+}
+
+// --- Metadata Campaign ---
+// Batas panjang (dalam byte) untuk field metadata
+const MAX_TITLE_LEN: u32 = 64;
+const MAX_DESCRIPTION_LEN: u32 = 280;
+const MAX_URI_LEN: u32 = 256;
+
+/// Kategori campaign, dipakai frontend untuk filter
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Category {
+    Technology = 0,
+    Art = 1,
+    Community = 2,
+    Charity = 3,
+    Education = 4,
+    Health = 5,
+    Environment = 6,
+    Other = 7,
+}
+
+/// Metadata tampilan campaign. Disimpan terpisah dari `Campaign` supaya
+/// `donate`/`refund` tidak perlu membaca teks yang panjang.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignMetadata {
+    pub title: String,        // Judul singkat (1-64 byte)
+    pub description: String,  // Deskripsi singkat (maks 280 byte)
+    pub metadata_uri: String, // Detail off-chain (ipfs:// atau https://), boleh kosong
+    pub category: Category,
+}
+
+/// Ringkasan campaign untuk halaman daftar (hasil `list_*`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignSummary {
+    pub id: u64,
+    pub owner: Address,
+    pub token: Address,
+    pub title: String,
+    pub category: Category,
+    pub goal: i128,
+    pub raised: i128,
+    pub deadline: u64,
+    pub status: CampaignStatus,
+}
+
+/// Jumlah token dalam satuan "manusia": `whole` + `fraction` / 10^decimals
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnitAmount {
+    pub whole: i128,
+    pub fraction: i128,
+}
+
+/// Goal & raised campaign dalam satuan token (hasil `get_campaign_units`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignUnits {
+    pub decimals: u32, // decimals() dari token campaign
+    pub goal: UnitAmount,
+    pub raised: UnitAmount,
+}
+
+/// Satu halaman hasil `list_active_campaigns`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivePage {
+    pub campaigns: Vec<CampaignSummary>,
+    pub next: Option<u64>, // ID untuk melanjutkan, None jika semua ID sudah diperiksa
+}
+
+// --- Events ---
+// Topic pertama adalah nama event (snake_case), diikuti field #[topic].
+// Frontend bisa subscribe ke event ini alih-alih polling get_campaign.
+
+/// topics ["campaign_created", campaign_id, owner], data { goal, deadline, token }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignCreated {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub owner: Address,
+    pub goal: i128,
+    pub deadline: u64,
+    pub token: Address,
+}
+
+/// topics ["donated", campaign_id, donor], data { amount, raised }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Donated {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub donor: Address,
+    pub amount: i128,
+    pub raised: i128,
+}
+
+/// topics ["refunded", campaign_id, donor], data { amount, raised }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Refunded {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub donor: Address,
+    pub amount: i128,
+    pub raised: i128,
+}
+
+/// topics ["metadata_updated", campaign_id], data { title, category }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataUpdated {
+    #[topic]
+    pub campaign_id: u64,
+    pub title: String,
+    pub category: Category,
+}
+
+/// topics ["deadline_extended", campaign_id], data { old_deadline, new_deadline }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineExtended {
+    #[topic]
+    pub campaign_id: u64,
+    pub old_deadline: u64,
+    pub new_deadline: u64,
+}
+
+/// topics ["extension_objected", campaign_id, donor], data objections
+/// (jumlah donatur yang keberatan setelah keberatan ini)
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtensionObjected {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub donor: Address,
+    pub objections: u32,
+}
+
+/// topics ["pledge_withdrawn", campaign_id, donor], data { amount, raised }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PledgeWithdrawn {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub donor: Address,
+    pub amount: i128,
+    pub raised: i128,
+}
+
+/// topics ["campaign_cancelled", campaign_id, owner], data { raised }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignCancelled {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub owner: Address,
+    pub raised: i128,
+}
+
+/// topics ["milestone_released", campaign_id, index], data { amount }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneReleased {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub index: u32,
+    pub amount: i128,
+}
+
+/// topics ["milestone_rejected", campaign_id, index], data { remaining }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneRejected {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub index: u32,
+    pub remaining: i128,
+}
+
+/// topics ["proposal_created", campaign_id, milestone], data { voting_end }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreated {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub milestone: u32,
+    pub voting_end: u64,
+}
+
+/// topics ["voted", campaign_id, donor], data { approve, weight }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Voted {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub donor: Address,
+    pub approve: bool,
+    pub weight: i128,
+}
+
+/// topics ["proposal_finalized", campaign_id, milestone], data { approved, votes_for, votes_against }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalFinalized {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub milestone: u32,
+    pub approved: bool,
+    pub votes_for: i128,
+    pub votes_against: i128,
+}
+
+/// topics ["tier_claimed", campaign_id, donor], data tier
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierClaimed {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub donor: Address,
+    pub tier: u32,
+}
+
+/// topics ["fee_charged", campaign_id, treasury], data amount
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCharged {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub treasury: Address,
+    pub amount: i128,
+}
+
+/// topics ["fee_scheduled"], data { fee_bps, effective_at }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeScheduled {
+    pub fee_bps: u32,
+    pub effective_at: u64,
+}
+
+/// topics ["withdrawn", campaign_id, owner], data { amount }
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Withdrawn {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub owner: Address,
+    pub amount: i128,
+}
+
+/// topics ["paused", admin]
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Paused {
+    #[topic]
+    pub admin: Address,
+}
+
+/// topics ["unpaused", admin]
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unpaused {
+    #[topic]
+    pub admin: Address,
+}
+
+/// topics ["admin_changed", old_admin, new_admin]
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChanged {
+    #[topic]
+    pub old_admin: Address,
+    #[topic]
+    pub new_admin: Address,
+}
+
+// --- Storage Keys untuk Factory ---
+// Menyimpan u64 untuk campaign ID berikutnya (instance storage)
+const NEXT_ID: Symbol = symbol_short!("NEXT_ID");
+// Admin platform (Address)
+const ADMIN: Symbol = symbol_short!("ADMIN");
+// Calon admin baru, menunggu `accept_admin` (Address)
+const PEND_ADM: Symbol = symbol_short!("PEND_ADM");
+// Saklar darurat (bool)
+const PAUSED: Symbol = symbol_short!("PAUSED");
+// FeeConfig & PendingFee
+const FEE_CFG: Symbol = symbol_short!("FEE_CFG");
+const FEE_NEXT: Symbol = symbol_short!("FEE_NEXT");
+// Versi layout storage (u32), ditulis oleh constructor & `migrate`
+const VERSION: Symbol = symbol_short!("VERSION");
+// (Legacy, versi 1) Map<u64, LegacyCampaign> di instance storage,
+// diganti DataKey::Campaign & DataKey::Donation. Hanya dibaca oleh `migrate`.
+const CAMPAIGNS: Symbol = symbol_short!("CAMPAIGNS");
+
+// --- Schema Version ---
+// 1: semua campaign dalam satu map CAMPAIGNS
+// 2: campaign, donasi & metadata per key di persistent storage
+const SCHEMA_VERSION: u32 = 2;
+
+/// (Legacy) Bentuk campaign di schema versi 1, termasuk map donasinya
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyCampaign {
+    pub owner: Address,
+    pub goal: i128,
+    pub deadline: u64,
+    pub token: Address,
+    pub raised: i128,
+    pub donations: Map<Address, i128>,
+}
+
+// Kunci per campaign / per donatur (persistent storage).
+// Setiap call hanya membaca entry yang dibutuhkan, jadi biayanya
+// tidak bertambah seiring jumlah campaign.
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    // Data Campaign
+    Campaign(u64),
+    // Jumlah donasi seorang donatur ke campaign tertentu
+    Donation(u64, Address),
+    // Metadata tampilan campaign
+    Metadata(u64),
+    // Escrow milestone (hanya ada untuk campaign ber-milestone)
+    Escrow(u64),
+    // Proposal milestone yang sedang berjalan (mode DonorVote)
+    Proposal(u64),
+    // Daftar reward tier campaign
+    Tiers(u64),
+    // Jumlah slot yang sudah diambil: (campaign, index tier)
+    TierClaimed(u64, u32),
+    // Tier yang dipilih donatur: (campaign, donatur)
+    DonorTier(u64, Address),
+    // Penanda donatur keberatan atas perpanjangan deadline: (campaign, donatur)
+    Objection(u64, Address),
+    // Penanda donatur sudah voting: (campaign, index milestone, donatur)
+    Vote(u64, u32, Address),
+    // Index sekunder: jumlah campaign milik owner & ID campaign ke-n milik owner
+    OwnerCount(Address),
+    OwnerCampaign(Address, u32),
+    // Index sekunder: jumlah campaign per token & ID campaign ke-n per token
+    TokenCount(Address),
+    TokenCampaign(Address, u32),
+}
+
+// --- Pagination ---
+// Batas jumlah item per halaman supaya biaya read tetap terbatas
+const MAX_PAGE_SIZE: u32 = 50;
+// Batas ID yang diperiksa per panggilan `list_active_campaigns`
+const MAX_SCAN: u32 = 4 * MAX_PAGE_SIZE;
+
+// --- TTL ---
+// Ledger ditutup kira-kira tiap 5 detik
+const DAY_IN_LEDGERS: u32 = 17280;
+// Entry campaign & donasi diperpanjang sampai ~30 hari setiap kali dipakai
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+// Instance storage (konfigurasi global & kode kontrak) juga ~30 hari
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// --- Helper Storage ---
+
+/// (Helper) Mengambil satu campaign dari storage, `status` sudah dihitung
+/// sesuai waktu ledger saat ini
+fn load_campaign(env: &Env, id: u64) -> Result<Campaign, CrowdfundingError> {
+    let key = DataKey::Campaign(id);
+    let campaign: Campaign = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(CrowdfundingError::CampaignNotFound)?;
+    extend_persistent(env, &key);
+    let mut status = campaign_status(env, &campaign);
+    // Milestone yang lewat due date tanpa approval = gagal, sisa dana di-refund
+    if status == CampaignStatus::Succeeded {
+        if let Some(escrow) = load_escrow(env, id) {
+            if milestone_missed(env, id, &escrow) {
+                status = CampaignStatus::Failed;
+            }
+        }
+    }
+    Ok(Campaign { status, ..campaign })
+}
+
+/// (Helper) Menyimpan satu campaign ke storage
+fn save_campaign(env: &Env, id: u64, campaign: &Campaign) {
+    let key = DataKey::Campaign(id);
+    env.storage().persistent().set(&key, campaign);
+    extend_persistent(env, &key);
+}
+
+/// (Helper) Mengambil jumlah donasi donatur (0 jika belum pernah donasi)
+fn load_donation(env: &Env, id: u64, donor: &Address) -> i128 {
+    let key = DataKey::Donation(id, donor.clone());
+    match env.storage().persistent().get(&key) {
+        Some(amount) => {
+            extend_persistent(env, &key);
+            amount
+        }
+        None => 0,
+    }
+}
+
+/// (Helper) Menyimpan jumlah donasi donatur
+fn save_donation(env: &Env, id: u64, donor: &Address, amount: i128) {
+    let key = DataKey::Donation(id, donor.clone());
+    env.storage().persistent().set(&key, &amount);
+    extend_persistent(env, &key);
+}
+
+/// (Helper) Mengambil escrow milestone (None jika campaign tanpa milestone)
+fn load_escrow(env: &Env, id: u64) -> Option<Escrow> {
+    let key = DataKey::Escrow(id);
+    let escrow = env.storage().persistent().get(&key);
+    if escrow.is_some() {
+        extend_persistent(env, &key);
+    }
+    escrow
+}
+
+/// (Helper) Menyimpan escrow milestone
+fn save_escrow(env: &Env, id: u64, escrow: &Escrow) {
+    let key = DataKey::Escrow(id);
+    env.storage().persistent().set(&key, escrow);
+    extend_persistent(env, &key);
+}
+
+/// (Helper) Mengambil proposal yang sedang berjalan
+fn load_proposal(env: &Env, id: u64) -> Result<Proposal, CrowdfundingError> {
+    let key = DataKey::Proposal(id);
+    let proposal: Proposal = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(CrowdfundingError::NoProposal)?;
+    extend_persistent(env, &key);
+    Ok(proposal)
+}
+
+/// (Helper) Menyimpan proposal yang sedang berjalan
+fn save_proposal(env: &Env, id: u64, proposal: &Proposal) {
+    let key = DataKey::Proposal(id);
+    env.storage().persistent().set(&key, proposal);
+    extend_persistent(env, &key);
+}
+
+/// (Helper) Mengambil daftar reward tier (kosong jika campaign tanpa tier)
+fn load_tiers(env: &Env, id: u64) -> Vec<RewardTier> {
+    let key = DataKey::Tiers(id);
+    match env.storage().persistent().get(&key) {
+        Some(tiers) => {
+            extend_persistent(env, &key);
+            tiers
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// (Helper) Mengambil jumlah slot tier yang sudah diambil
+fn load_tier_claimed(env: &Env, id: u64, tier: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TierClaimed(id, tier))
+        .unwrap_or(0)
+}
+
+/// (Helper) Menyimpan jumlah slot tier yang sudah diambil
+fn save_tier_claimed(env: &Env, id: u64, tier: u32, claimed: u32) {
+    let key = DataKey::TierClaimed(id, tier);
+    env.storage().persistent().set(&key, &claimed);
+    extend_persistent(env, &key);
+}
+
+/// (Helper) Mengambil tier yang dipilih donatur
+fn load_donor_tier(env: &Env, id: u64, donor: &Address) -> Option<u32> {
+    let key = DataKey::DonorTier(id, donor.clone());
+    let tier = env.storage().persistent().get(&key);
+    if tier.is_some() {
+        extend_persistent(env, &key);
+    }
+    tier
+}
+
+/// (Helper) Melepas slot tier donatur (saat refund / pindah tier)
+fn release_donor_tier(env: &Env, id: u64, donor: &Address) {
+    if let Some(tier) = load_donor_tier(env, id, donor) {
+        let claimed = load_tier_claimed(env, id, tier);
+        save_tier_claimed(env, id, tier, claimed.saturating_sub(1));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::DonorTier(id, donor.clone()));
+    }
+}
+
+/// (Helper) Donatur mengambil slot tier. Total donasi donatur (`total`) harus
+/// memenuhi minimum tier. Jika donatur sudah punya tier lain, slot lama dilepas.
+/// Mengembalikan false jika donatur sudah memegang tier ini (tidak ada slot baru).
+fn claim_tier(
+    env: &Env,
+    id: u64,
+    donor: &Address,
+    tier: u32,
+    total: i128,
+) -> Result<bool, CrowdfundingError> {
+    let reward = load_tiers(env, id)
+        .get(tier)
+        .ok_or(CrowdfundingError::TierNotFound)?;
+    if total < reward.min_amount {
+        return Err(CrowdfundingError::TierBelowMinimum);
+    }
+    if load_donor_tier(env, id, donor) == Some(tier) {
+        return Ok(false);
+    }
+    let claimed = load_tier_claimed(env, id, tier);
+    if claimed >= reward.quantity {
+        return Err(CrowdfundingError::TierSoldOut);
+    }
+    release_donor_tier(env, id, donor);
+    save_tier_claimed(env, id, tier, claimed + 1);
+    let key = DataKey::DonorTier(id, donor.clone());
+    env.storage().persistent().set(&key, &tier);
+    extend_persistent(env, &key);
+    Ok(true)
+}
+
+/// (Helper) Mengambil metadata campaign
+fn load_metadata(env: &Env, id: u64) -> Result<CampaignMetadata, CrowdfundingError> {
+    let key = DataKey::Metadata(id);
+    let metadata: CampaignMetadata = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(CrowdfundingError::CampaignNotFound)?;
+    extend_persistent(env, &key);
+    Ok(metadata)
+}
+
+/// (Helper) Menyimpan metadata campaign
+fn save_metadata(env: &Env, id: u64, metadata: &CampaignMetadata) {
+    let key = DataKey::Metadata(id);
+    env.storage().persistent().set(&key, metadata);
+    extend_persistent(env, &key);
+}
+
+/// (Helper) Menambahkan campaign ke index sekunder (owner / token)
+fn push_index(env: &Env, count_key: DataKey, entry_key: impl Fn(u32) -> DataKey, id: u64) {
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    let key = entry_key(count);
+    env.storage().persistent().set(&key, &id);
+    extend_persistent(env, &key);
+    env.storage().persistent().set(&count_key, &(count + 1));
+    extend_persistent(env, &count_key);
+}
+
+/// (Helper) Mendaftarkan campaign ke index owner & token
+fn index_campaign(env: &Env, id: u64, owner: &Address, token: &Address) {
+    push_index(
+        env,
+        DataKey::OwnerCount(owner.clone()),
+        |i| DataKey::OwnerCampaign(owner.clone(), i),
+        id,
+    );
+    push_index(
+        env,
+        DataKey::TokenCount(token.clone()),
+        |i| DataKey::TokenCampaign(token.clone(), i),
+        id,
+    );
+}
+
+/// (Helper) Memindahkan satu campaign versi 1 ke layout per key.
+/// Campaign lama tidak punya opsi tambahan, jadi dianggap AllOrNothing
+/// tanpa batas, tanpa fee & dengan metadata placeholder.
+fn migrate_legacy_campaign(env: &Env, id: u64, legacy: LegacyCampaign) {
+    let campaign = Campaign {
+        owner: legacy.owner.clone(),
+        goal: legacy.goal,
+        deadline: legacy.deadline,
+        token: legacy.token.clone(),
+        raised: legacy.raised,
+        released: 0,
+        status: CampaignStatus::Active,
+        funding_mode: FundingMode::AllOrNothing,
+        hard_cap: None,
+        min_donation: None,
+        max_per_donor: None,
+        over_limit: OverLimitPolicy::Reject,
+        start_time: None,
+        max_extension: 0,
+        extended: 0,
+        objection_bps: None,
+        donors: 0,
+        objections: 0,
+        pledge_withdrawal: false,
+        lock_on_goal: false,
+        lock_window: 0,
+        fee_bps: 0,
+        fee_paid: 0,
+    };
+    save_campaign(env, id, &campaign);
+    save_metadata(
+        env,
+        id,
+        &CampaignMetadata {
+            title: String::from_str(env, "Untitled campaign"),
+            description: String::from_str(env, ""),
+            metadata_uri: String::from_str(env, ""),
+            category: Category::Other,
+        },
+    );
+    // Donatur yang sudah refund tersimpan dengan nilai 0, tidak perlu dipindah
+    let mut campaign = campaign;
+    for (donor, amount) in legacy.donations.iter() {
+        if amount > 0 {
+            save_donation(env, id, &donor, amount);
+            campaign.donors += 1;
+        }
+    }
+    save_campaign(env, id, &campaign);
+    index_campaign(env, id, &legacy.owner, &legacy.token);
+}
+
+/// (Helper) Membaca satu halaman ID dari index sekunder
+fn read_index(
+    env: &Env,
+    count_key: DataKey,
+    entry_key: impl Fn(u32) -> DataKey,
+    start: u32,
+    limit: u32,
+) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+    for i in start..end {
+        let key = entry_key(i);
+        if let Some(id) = env.storage().persistent().get(&key) {
+            extend_persistent(env, &key);
+            ids.push_back(id);
+        }
+    }
+    ids
+}
+
+/// (Helper) Membuat ringkasan campaign untuk hasil list
+fn load_summary(env: &Env, id: u64) -> Result<CampaignSummary, CrowdfundingError> {
+    let campaign = load_campaign(env, id)?;
+    let metadata = load_metadata(env, id)?;
+    Ok(CampaignSummary {
+        id,
+        owner: campaign.owner,
+        token: campaign.token,
+        title: metadata.title,
+        category: metadata.category,
+        goal: campaign.goal,
+        raised: campaign.raised,
+        deadline: campaign.deadline,
+        status: campaign.status,
+    })
+}
+
+/// (Helper) Mengubah daftar ID menjadi daftar ringkasan
+fn load_summaries(env: &Env, ids: Vec<u64>) -> Result<Vec<CampaignSummary>, CrowdfundingError> {
+    let mut summaries = Vec::new(env);
+    for id in ids.iter() {
+        summaries.push_back(load_summary(env, id)?);
+    }
+    Ok(summaries)
+}
+
+/// (Helper) Memperpanjang TTL entry persistent
+fn extend_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// (Helper) Memperpanjang TTL instance storage (admin, fee, NEXT_ID, dll).
+/// Dipanggil di setiap entrypoint yang mengubah state.
+fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// (Helper) Check apakah campaign sudah reach goal
+fn goal_reached(campaign: &Campaign) -> bool {
+    campaign.goal > 0 && campaign.raised >= campaign.goal
+}
+
+/// (Helper) Check apakah dana campaign boleh diterima owner setelah deadline
+fn funded(campaign: &Campaign) -> bool {
+    match campaign.funding_mode {
+        FundingMode::AllOrNothing => goal_reached(campaign),
+        FundingMode::KeepItAll => campaign.raised > 0,
+    }
+}
+
+/// (Helper) Check apakah hard cap campaign sudah tercapai
+fn capped(campaign: &Campaign) -> bool {
+    campaign.hard_cap.is_some_and(|cap| campaign.raised >= cap)
+}
+
+/// (Helper) Check apakah campaign sudah berakhir: deadline lewat (tepat di
+/// detik `deadline` sudah dianggap berakhir) atau hard cap tercapai.
+fn ended(env: &Env, campaign: &Campaign) -> bool {
+    env.ledger().timestamp() >= campaign.deadline || capped(campaign)
+}
+
+/// (Helper) Validasi hard cap & batas donasi
+fn validate_limits(goal: i128, config: &CampaignConfig) -> Result<(), CrowdfundingError> {
+    let min = config.min_donation.unwrap_or(1);
+    if min <= 0
+        || config.hard_cap.is_some_and(|cap| cap < goal)
+        || config.max_per_donor.is_some_and(|max| max < min)
+    {
+        return Err(CrowdfundingError::InvalidLimits);
+    }
+    Ok(())
+}
+
+/// (Helper) Jumlah donasi yang diterima setelah hard cap & batas per donatur.
+/// Dengan PartialAccept donasi dipotong sampai batas; dengan Reject donasi
+/// yang melewati batas ditolak. Hasil potongan tetap harus >= min_donation,
+/// jika tidak donasi ditolak dengan error batas yang memotongnya.
+fn accepted_amount(
+    campaign: &Campaign,
+    current_donation: i128,
+    amount: i128,
+) -> Result<i128, CrowdfundingError> {
+    let min = campaign.min_donation.unwrap_or(0);
+    if amount < min {
+        return Err(CrowdfundingError::BelowMinDonation);
+    }
+    let mut accepted = amount;
+    if let Some(max) = campaign.max_per_donor {
+        let room = max - current_donation;
+        if accepted > room {
+            if campaign.over_limit == OverLimitPolicy::Reject || room <= 0 || room < min {
+                return Err(CrowdfundingError::DonorLimitExceeded);
+            }
+            accepted = room;
+        }
+    }
+    if let Some(cap) = campaign.hard_cap {
+        let room = cap - campaign.raised;
+        if accepted > room {
+            if campaign.over_limit == OverLimitPolicy::Reject || room <= 0 || room < min {
+                return Err(CrowdfundingError::HardCapExceeded);
+            }
+            accepted = room;
+        }
+    }
+    Ok(accepted)
+}
+
+/// (Helper) Menghitung status campaign dari status tersimpan & waktu ledger
+fn campaign_status(env: &Env, campaign: &Campaign) -> CampaignStatus {
+    match campaign.status {
+        CampaignStatus::Failed | CampaignStatus::Cancelled | CampaignStatus::Claimed => {
+            campaign.status
+        }
+        _ if env.ledger().timestamp() < campaign.start_time.unwrap_or(0) => {
+            CampaignStatus::Pending
+        }
+        _ if !ended(env, campaign) => CampaignStatus::Active,
+        _ if funded(campaign) => CampaignStatus::Succeeded,
+        _ => CampaignStatus::Failed,
+    }
+}
+
+/// (Helper) Check apakah milestone berikutnya sudah lewat due date.
+/// Milestone yang proposal-nya diajukan sebelum due date tidak dianggap
+/// terlewat; hasilnya ditentukan oleh finalize_proposal.
+fn milestone_missed(env: &Env, id: u64, escrow: &Escrow) -> bool {
+    if env.storage().persistent().has(&DataKey::Proposal(id)) {
+        return false;
+    }
+    match escrow.milestones.get(escrow.next) {
+        Some(milestone) => env.ledger().timestamp() >= milestone.due_date,
+        None => false,
+    }
+}
+
+/// (Helper) Validasi milestone: 1..=MAX_MILESTONES item, persentase total 100,
+/// due date urut naik dan setelah deadline campaign
+fn validate_milestones(deadline: u64, milestones: &Vec<Milestone>) -> Result<(), CrowdfundingError> {
+    if milestones.is_empty() || milestones.len() > MAX_MILESTONES {
+        return Err(CrowdfundingError::InvalidMilestones);
+    }
+    let mut total: u32 = 0;
+    let mut last_due = deadline;
+    for milestone in milestones.iter() {
+        if milestone.percentage == 0 || milestone.due_date <= last_due {
+            return Err(CrowdfundingError::InvalidMilestones);
+        }
+        total = total.saturating_add(milestone.percentage);
+        last_due = milestone.due_date;
+    }
+    if total != 100 {
+        return Err(CrowdfundingError::InvalidMilestones);
+    }
+    Ok(())
+}
+
+/// (Helper) Validasi reward tier: maks MAX_TIERS, deskripsi tidak kosong,
+/// minimum donasi & kuota lebih dari 0
+fn validate_tiers(tiers: &Vec<RewardTier>) -> Result<(), CrowdfundingError> {
+    if tiers.len() > MAX_TIERS {
+        return Err(CrowdfundingError::InvalidTiers);
+    }
+    for tier in tiers.iter() {
+        let description_len = tier.description.len();
+        if description_len == 0
+            || description_len > MAX_DESCRIPTION_LEN
+            || tier.min_amount <= 0
+            || tier.quantity == 0
+        {
+            return Err(CrowdfundingError::InvalidTiers);
+        }
+    }
+    Ok(())
+}
+
+/// (Helper) Validasi parameter voting donatur
+fn validate_voting(config: &VotingConfig) -> Result<(), CrowdfundingError> {
+    if config.quorum_bps > MAX_BPS
+        || config.threshold_bps == 0
+        || config.threshold_bps > MAX_BPS
+        || config.voting_period == 0
+    {
+        return Err(CrowdfundingError::InvalidVotingConfig);
+    }
+    Ok(())
+}
+
+/// (Helper) Memecah amount (stroops) menjadi bagian bulat & pecahan
+fn to_units(amount: i128, decimals: u32) -> UnitAmount {
+    match 10i128.checked_pow(decimals) {
+        Some(scale) => UnitAmount {
+            whole: amount / scale,
+            fraction: amount % scale,
+        },
+        // Decimals terlalu besar untuk i128: semuanya pecahan
+        None => UnitAmount {
+            whole: 0,
+            fraction: amount,
+        },
+    }
+}
+
+/// (Helper) Membaca admin & memastikan admin menandatangani transaksi
+fn require_admin(env: &Env) -> Result<Address, CrowdfundingError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&ADMIN)
+        .ok_or(CrowdfundingError::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+/// (Helper) Error jika kontrak sedang di-pause
+fn require_not_paused(env: &Env) -> Result<(), CrowdfundingError> {
+    if env.storage().instance().get(&PAUSED).unwrap_or(false) {
+        return Err(CrowdfundingError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// (Helper) Membaca konfigurasi fee; perubahan yang timelock-nya sudah
+/// lewat langsung diterapkan. None jika platform belum diinisialisasi.
+fn load_fee_config(env: &Env) -> Option<FeeConfig> {
+    let mut config: FeeConfig = env.storage().instance().get(&FEE_CFG)?;
+    if let Some(pending) = env.storage().instance().get::<_, PendingFee>(&FEE_NEXT) {
+        if env.ledger().timestamp() >= pending.effective_at {
+            config.fee_bps = pending.fee_bps;
+        }
+    }
+    Some(config)
+}
+
+/// (Helper) Seperti `load_fee_config`, tapi perubahan yang sudah lewat
+/// timelock disimpan ke FEE_CFG dan FEE_NEXT dihapus.
+fn apply_pending_fee(env: &Env) -> Option<FeeConfig> {
+    let config = load_fee_config(env)?;
+    let applied = env
+        .storage()
+        .instance()
+        .get::<_, PendingFee>(&FEE_NEXT)
+        .is_some_and(|pending| env.ledger().timestamp() >= pending.effective_at);
+    if applied {
+        env.storage().instance().set(&FEE_CFG, &config);
+        env.storage().instance().remove(&FEE_NEXT);
+    }
+    Some(config)
+}
+
+/// (Helper) Transfer token campaign. Error dari kontrak token dipetakan ke
+/// `TokenTransferFailed` karena kodenya bisa bentrok dengan kode error kontrak ini.
+/// Jumlah 0 (hasil pembulatan refund / tranche milestone) tidak ditransfer
+/// karena token menolak transfer 0.
+fn transfer_token(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), CrowdfundingError> {
+    if amount == 0 {
+        return Ok(());
+    }
+    match token::Client::new(env, token).try_transfer(from, to, &amount) {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(CrowdfundingError::TokenTransferFailed),
+    }
+}
+
+/// (Helper) Mengirim payout ke owner setelah dipotong fee platform.
+/// `campaign.fee_paid` harus sudah di-update & disimpan oleh pemanggil
+/// (lihat `payout_fee`). Mengembalikan jumlah yang diterima owner.
+fn send_payout(
+    env: &Env,
+    campaign_id: u64,
+    campaign: &Campaign,
+    amount: i128,
+    fee: i128,
+) -> Result<i128, CrowdfundingError> {
+    let contract_address = env.current_contract_address();
+    if fee > 0 {
+        // Fee > 0 hanya mungkin jika FeeConfig sudah ada
+        let treasury = load_fee_config(env).unwrap().treasury;
+        transfer_token(env, &campaign.token, &contract_address, &treasury, fee)?;
+        FeeCharged {
+            campaign_id,
+            treasury,
+            amount: fee,
+        }
+        .publish(env);
+    }
+    let net = amount - fee;
+    transfer_token(env, &campaign.token, &contract_address, &campaign.owner, net)?;
+    Ok(net)
+}
+
+/// (Helper) Fee platform untuk payout `amount`, memakai fee saat campaign dibuat
+fn payout_fee(campaign: &Campaign, amount: i128) -> i128 {
+    amount * campaign.fee_bps as i128 / MAX_BPS as i128
+}
+
+/// (Helper) Merilis milestone berikutnya ke owner (dipotong fee platform).
+/// Mengembalikan jumlah yang diterima owner.
+fn release_milestone(
+    env: &Env,
+    campaign_id: u64,
+    campaign: &mut Campaign,
+    escrow: &mut Escrow,
+) -> Result<i128, CrowdfundingError> {
+    let index = escrow.next;
+    let milestone = escrow.milestones.get(index).ok_or(CrowdfundingError::MilestoneClosed)?;
+    let last = index + 1 == escrow.milestones.len();
+    // Milestone terakhir merilis semua sisa supaya tidak ada sisa pembulatan
+    let amount = if last {
+        campaign.raised - campaign.released
+    } else {
+        campaign.raised * milestone.percentage as i128 / 100
+    };
+
+    let fee = payout_fee(campaign, amount);
+
+    escrow.next += 1;
+    campaign.released += amount;
+    campaign.fee_paid += fee;
+    if last {
+        campaign.status = CampaignStatus::Claimed;
+    }
+    save_escrow(env, campaign_id, escrow);
+    save_campaign(env, campaign_id, campaign);
+
+    let net = send_payout(env, campaign_id, campaign, amount, fee)?;
+
+    MilestoneReleased {
+        campaign_id,
+        index,
+        amount: net,
+    }
+    .publish(env);
+    Ok(net)
+}
+
+/// (Helper) Menolak milestone berikutnya: campaign menjadi Failed dan
+/// donatur bisa refund sisa dana pro rata
+fn reject_next_milestone(env: &Env, campaign_id: u64, campaign: &mut Campaign, escrow: &Escrow) {
+    campaign.status = CampaignStatus::Failed;
+    save_campaign(env, campaign_id, campaign);
+
+    MilestoneRejected {
+        campaign_id,
+        index: escrow.next,
+        remaining: campaign.raised - campaign.released,
+    }
+    .publish(env);
+}
+
+/// (Helper) Address approver, error jika milestone diputuskan lewat voting
+fn approver(escrow: &Escrow) -> Result<Address, CrowdfundingError> {
+    match &escrow.approval {
+        Approval::Approver(approver) => Ok(approver.clone()),
+        Approval::DonorVote(_) => Err(CrowdfundingError::WrongApprovalMode),
+    }
+}
+
+/// (Helper) Parameter voting, error jika milestone diputuskan approver
+fn voting_config(escrow: &Escrow) -> Result<VotingConfig, CrowdfundingError> {
+    match &escrow.approval {
+        Approval::DonorVote(config) => Ok(config.clone()),
+        Approval::Approver(_) => Err(CrowdfundingError::WrongApprovalMode),
+    }
+}
+
+/// (Helper) Validasi panjang & format field metadata
+fn validate_metadata(metadata: &CampaignMetadata) -> Result<(), CrowdfundingError> {
+    let title_len = metadata.title.len();
+    if title_len == 0 || title_len > MAX_TITLE_LEN {
+        return Err(CrowdfundingError::InvalidTitle);
+    }
+    if metadata.description.len() > MAX_DESCRIPTION_LEN {
+        return Err(CrowdfundingError::DescriptionTooLong);
+    }
+    if !valid_metadata_uri(&metadata.metadata_uri) {
+        return Err(CrowdfundingError::InvalidMetadataUri);
+    }
+    Ok(())
 }
 
-// --- Storage Keys untuk Factory ---
-// Menyimpan Map<u64, Campaign>
-const CAMPAIGNS: Symbol = symbol_short!("CAMPAIGNS");
-// Menyimpan u64 untuk campaign ID berikutnya
-const NEXT_ID: Symbol = symbol_short!("NEXT_ID");
+/// (Helper) URI boleh kosong, atau harus diawali ipfs:// / https://
+fn valid_metadata_uri(uri: &String) -> bool {
+    let len = uri.len();
+    if len == 0 {
+        return true;
+    }
+    if len > MAX_URI_LEN {
+        return false;
+    }
+    let mut buf = [0u8; MAX_URI_LEN as usize];
+    let bytes = &mut buf[..len as usize];
+    uri.copy_into_slice(bytes);
+    bytes.starts_with(b"ipfs://") || bytes.starts_with(b"https://")
+}
+
+/// (Helper) Milestone hanya bisa diproses saat campaign Succeeded
+fn require_milestone_open(campaign: &Campaign) -> Result<(), CrowdfundingError> {
+    match campaign.status {
+        CampaignStatus::Succeeded => Ok(()),
+        CampaignStatus::Pending | CampaignStatus::Active => Err(CrowdfundingError::CampaignNotEnded),
+        CampaignStatus::Cancelled => Err(CrowdfundingError::CampaignCancelled),
+        CampaignStatus::Failed | CampaignStatus::Claimed => Err(CrowdfundingError::MilestoneClosed),
+    }
+}
 
 // Contract struct
 #[contract]
@@ -28,6 +1411,196 @@ pub struct CrowdfundingContract;
 // Contract implementation
 #[contractimpl]
 impl CrowdfundingContract {
+    // --- Konfigurasi Platform ---
+
+    /// Constructor: set admin & fee platform saat deploy. Dijalankan atomik
+    /// bersama deploy, jadi tidak ada celah untuk merebut peran admin.
+    /// `max_fee_bps` harus di bawah 100% supaya owner selalu menerima payout.
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        treasury: Address,
+        fee_bps: u32,
+        max_fee_bps: u32,
+    ) -> Result<(), CrowdfundingError> {
+        if max_fee_bps >= MAX_BPS || fee_bps > max_fee_bps {
+            return Err(CrowdfundingError::InvalidFee);
+        }
+
+        env.storage().instance().set(&ADMIN, &admin);
+        env.storage().instance().set(&VERSION, &SCHEMA_VERSION);
+        env.storage().instance().set(
+            &FEE_CFG,
+            &FeeConfig {
+                treasury,
+                fee_bps,
+                max_fee_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Admin menjadwalkan fee baru (maks `max_fee_bps`). Berlaku untuk
+    /// campaign yang dibuat setelah timelock FEE_TIMELOCK lewat.
+    /// Mengembalikan timestamp fee baru mulai berlaku.
+    pub fn set_fee(env: Env, fee_bps: u32) -> Result<u64, CrowdfundingError> {
+        extend_instance(&env);
+        require_admin(&env)?;
+
+        // Terapkan perubahan sebelumnya yang sudah lewat timelock
+        let config = apply_pending_fee(&env).ok_or(CrowdfundingError::NotInitialized)?;
+        if fee_bps > config.max_fee_bps {
+            return Err(CrowdfundingError::InvalidFee);
+        }
+
+        let effective_at = env.ledger().timestamp() + FEE_TIMELOCK;
+        env.storage().instance().set(
+            &FEE_NEXT,
+            &PendingFee {
+                fee_bps,
+                effective_at,
+            },
+        );
+
+        FeeScheduled {
+            fee_bps,
+            effective_at,
+        }
+        .publish(&env);
+        Ok(effective_at)
+    }
+
+    /// Get konfigurasi fee yang berlaku saat ini
+    pub fn get_fee_config(env: Env) -> Result<FeeConfig, CrowdfundingError> {
+        load_fee_config(&env).ok_or(CrowdfundingError::NotInitialized)
+    }
+
+    /// Get perubahan fee yang dijadwalkan (None jika tidak ada atau sudah berlaku)
+    pub fn get_pending_fee(env: Env) -> Option<PendingFee> {
+        env.storage()
+            .instance()
+            .get::<_, PendingFee>(&FEE_NEXT)
+            .filter(|pending| env.ledger().timestamp() < pending.effective_at)
+    }
+
+    // --- Admin ---
+
+    /// Admin menghentikan sementara `create_campaign` & `donate`.
+    /// Refund, withdraw_pledge & payout tetap berjalan.
+    pub fn pause(env: Env) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        let admin = require_admin(&env)?;
+        env.storage().instance().set(&PAUSED, &true);
+        Paused { admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Admin membuka kembali kontrak
+    pub fn unpause(env: Env) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        let admin = require_admin(&env)?;
+        env.storage().instance().set(&PAUSED, &false);
+        Unpaused { admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Cek apakah kontrak sedang di-pause
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().get(&PAUSED).unwrap_or(false)
+    }
+
+    /// Langkah 1 transfer admin: admin menunjuk calon admin baru.
+    /// Admin lama tetap berlaku sampai calon memanggil `accept_admin`.
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        require_admin(&env)?;
+        env.storage().instance().set(&PEND_ADM, &new_admin);
+        Ok(())
+    }
+
+    /// Langkah 2 transfer admin: calon admin menerima perannya
+    pub fn accept_admin(env: Env) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        let new_admin: Address = env
+            .storage()
+            .instance()
+            .get(&PEND_ADM)
+            .ok_or(CrowdfundingError::NoPendingAdmin)?;
+        new_admin.require_auth();
+
+        let old_admin: Address = env.storage().instance().get(&ADMIN).unwrap();
+        env.storage().instance().set(&ADMIN, &new_admin);
+        env.storage().instance().remove(&PEND_ADM);
+
+        AdminChanged {
+            old_admin,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get admin platform
+    pub fn get_admin(env: Env) -> Result<Address, CrowdfundingError> {
+        env.storage()
+            .instance()
+            .get(&ADMIN)
+            .ok_or(CrowdfundingError::NotInitialized)
+    }
+
+    /// Get calon admin yang belum menerima (None jika tidak ada)
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&PEND_ADM)
+    }
+
+    // --- Upgrade & Migrasi ---
+
+    /// Admin mengganti kode kontrak dengan WASM yang sudah di-upload.
+    /// Admin ditetapkan oleh constructor saat deploy, jadi tidak bisa direbut.
+    /// Storage tetap; jalankan `migrate` jika versi baru mengubah layout.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        require_admin(&env)?;
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Memindahkan maksimal `limit` campaign dari map CAMPAIGNS (schema 1)
+    /// ke key per campaign. Boleh dipanggil siapa saja karena data tidak
+    /// berubah; panggil berulang sampai hasilnya 0. Mengembalikan jumlah
+    /// campaign lama yang belum dipindah.
+    pub fn migrate(env: Env, limit: u32) -> u32 {
+        extend_instance(&env);
+        let Some(mut campaigns) = env
+            .storage()
+            .instance()
+            .get::<_, Map<u64, LegacyCampaign>>(&CAMPAIGNS)
+        else {
+            env.storage().instance().set(&VERSION, &SCHEMA_VERSION);
+            return 0;
+        };
+
+        for id in campaigns.keys().iter().take(limit as usize) {
+            let legacy = campaigns.get(id).unwrap();
+            migrate_legacy_campaign(&env, id, legacy);
+            campaigns.remove(id);
+        }
+
+        if campaigns.is_empty() {
+            env.storage().instance().remove(&CAMPAIGNS);
+            env.storage().instance().set(&VERSION, &SCHEMA_VERSION);
+        } else {
+            env.storage().instance().set(&CAMPAIGNS, &campaigns);
+        }
+        campaigns.len()
+    }
+
+    /// Get versi layout storage. Kontrak lama tanpa versi tersimpan = versi 1
+    /// sampai `migrate` selesai.
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&VERSION).unwrap_or(1)
+    }
+
     /// Membuat campaign baru.
     /// Siapapun bisa memanggil ini.
     /// Mengembalikan ID (u64) dari campaign yang baru dibuat.
@@ -37,141 +1610,821 @@ impl CrowdfundingContract {
         goal: i128,        // Target amount (stroops)
         deadline: u64,     // Unix timestamp kapan campaign berakhir
         token: Address,    // Token contract address yang ingin dikumpulkan
-    ) -> u64 {
+        config: CampaignConfig, // Mode pendanaan & opsi lain
+        metadata: CampaignMetadata, // Judul, deskripsi, URI & kategori
+        milestones: Option<MilestonePlan>, // Opsional: dana dirilis bertahap
+        tiers: Vec<RewardTier>, // Opsional (boleh kosong): reward untuk donatur
+    ) -> Result<u64, CrowdfundingError> {
+        extend_instance(&env);
         // Otentikasi owner
         owner.require_auth();
+        require_not_paused(&env)?;
 
         // Validasi input
         if goal <= 0 {
-            panic!("Goal must be positive");
+            return Err(CrowdfundingError::GoalNotPositive);
         }
         if deadline <= env.ledger().timestamp() {
-            panic!("Deadline must be in the future");
+            return Err(CrowdfundingError::DeadlineInPast);
+        }
+        if config.start_time.is_some_and(|start| start >= deadline) {
+            return Err(CrowdfundingError::InvalidStartTime);
         }
+        if config.objection_bps.is_some_and(|bps| bps == 0 || bps > MAX_BPS) {
+            return Err(CrowdfundingError::InvalidExtension);
+        }
+        validate_metadata(&metadata)?;
+        if let Some(plan) = &milestones {
+            validate_milestones(deadline, &plan.milestones)?;
+            if let Approval::DonorVote(config) = &plan.approval {
+                validate_voting(config)?;
+            }
+        }
+        validate_tiers(&tiers)?;
+        validate_limits(goal, &config)?;
 
         // Buat struct Campaign baru
         let new_campaign = Campaign {
-            owner,
+            owner: owner.clone(),
             goal,
             deadline,
-            token,
+            token: token.clone(),
             raised: 0,
-            donations: Map::new(&env),
+            released: 0,
+            status: CampaignStatus::Active,
+            funding_mode: config.funding_mode,
+            hard_cap: config.hard_cap,
+            min_donation: config.min_donation,
+            max_per_donor: config.max_per_donor,
+            over_limit: config.over_limit,
+            start_time: config.start_time,
+            max_extension: config.max_extension,
+            extended: 0,
+            objection_bps: config.objection_bps,
+            donors: 0,
+            objections: 0,
+            pledge_withdrawal: config.pledge_withdrawal,
+            lock_on_goal: config.lock_on_goal,
+            lock_window: config.lock_window,
+            fee_bps: apply_pending_fee(&env).map(|fee| fee.fee_bps).unwrap_or(0),
+            fee_paid: 0,
         };
 
-        // Ambil campaigns map (atau buat baru jika belum ada)
-        let mut campaigns: Map<u64, Campaign> = env
-            .storage()
-            .instance()
-            .get(&CAMPAIGNS)
-            .unwrap_or_else(|| Map::new(&env));
-
         // Ambil ID berikutnya
         let campaign_id: u64 = env.storage().instance().get(&NEXT_ID).unwrap_or(0u64);
 
-        // Simpan campaign baru ke map
-        campaigns.set(campaign_id, new_campaign);
-
-        // Simpan map & ID berikutnya kembali ke storage
-        env.storage().instance().set(&CAMPAIGNS, &campaigns);
+        // Simpan campaign baru & ID berikutnya
+        save_campaign(&env, campaign_id, &new_campaign);
+        save_metadata(&env, campaign_id, &metadata);
+        if let Some(plan) = milestones {
+            let escrow = Escrow {
+                approval: plan.approval,
+                milestones: plan.milestones,
+                next: 0,
+            };
+            save_escrow(&env, campaign_id, &escrow);
+        }
+        if !tiers.is_empty() {
+            let key = DataKey::Tiers(campaign_id);
+            env.storage().persistent().set(&key, &tiers);
+            extend_persistent(&env, &key);
+        }
         env.storage().instance().set(&NEXT_ID, &(campaign_id + 1));
 
+        // Update index sekunder untuk query list_*
+        index_campaign(&env, campaign_id, &owner, &token);
+
+        CampaignCreated {
+            campaign_id,
+            owner,
+            goal,
+            deadline,
+            token,
+        }
+        .publish(&env);
+
         // Kembalikan ID campaign baru
-        campaign_id
+        Ok(campaign_id)
     }
 
-    /// Donasi ke campaign tertentu menggunakan token transfer
-    pub fn donate(env: Env, campaign_id: u64, donor: Address, amount: i128) {
+    /// Donasi ke campaign tertentu menggunakan token transfer.
+    /// `tier` opsional: index reward tier yang dipilih donatur.
+    /// Mengembalikan jumlah yang diterima (bisa lebih kecil dari `amount`
+    /// jika campaign memakai OverLimitPolicy::PartialAccept).
+    pub fn donate(
+        env: Env,
+        campaign_id: u64,
+        donor: Address,
+        amount: i128,
+        tier: Option<u32>,
+    ) -> Result<i128, CrowdfundingError> {
+        extend_instance(&env);
         donor.require_auth();
+        require_not_paused(&env)?;
 
         if amount <= 0 {
-            panic!("Donation amount must be positive");
+            return Err(CrowdfundingError::AmountNotPositive);
         }
 
-        // Ambil map utama dan campaign spesifik
-        let mut campaigns = CrowdfundingContract::get_campaigns_map(env.clone());
-        let mut campaign = CrowdfundingContract::get_campaign_internal(&campaigns, campaign_id);
+        let mut campaign = load_campaign(&env, campaign_id)?;
 
-        if campaign.deadline <= env.ledger().timestamp() {
-            panic!("Campaign has ended");
+        match campaign.status {
+            CampaignStatus::Active => {}
+            CampaignStatus::Pending => return Err(CrowdfundingError::CampaignNotStarted),
+            CampaignStatus::Cancelled => return Err(CrowdfundingError::CampaignCancelled),
+            _ => return Err(CrowdfundingError::CampaignEnded),
         }
 
+        let current_donation = load_donation(&env, campaign_id, &donor);
+        let amount = accepted_amount(&campaign, current_donation, amount)?;
+        let total_donation = current_donation + amount;
+        let claimed_tier = match tier {
+            Some(tier) if claim_tier(&env, campaign_id, &donor, tier, total_donation)? => {
+                Some(tier)
+            }
+            _ => None,
+        };
+
         // Transfer token dari donor ke kontrak ini
         // !! Gunakan token::Client generik di sini karena token bisa apa saja !!
-        let token_client = token::Client::new(&env, &campaign.token);
         let contract_address = env.current_contract_address();
-        token_client.transfer(&donor, &contract_address, &amount);
+        transfer_token(&env, &campaign.token, &donor, &contract_address, amount)?;
 
-        // Update data campaign
+        // Update data campaign & donasi donatur
         campaign.raised += amount;
-        let current_donation = campaign.donations.get(donor.clone()).unwrap_or(0);
-        campaign.donations.set(donor, current_donation + amount);
+        if current_donation <= 0 {
+            campaign.donors += 1;
+        }
+        save_donation(&env, campaign_id, &donor, total_donation);
+        save_campaign(&env, campaign_id, &campaign);
 
-        // Simpan campaign yang sudah diupdate ke map
-        campaigns.set(campaign_id, campaign);
-        // Simpan map kembali ke storage
-        env.storage().instance().set(&CAMPAIGNS, &campaigns);
+        Donated {
+            campaign_id,
+            donor: donor.clone(),
+            amount,
+            raised: campaign.raised,
+        }
+        .publish(&env);
+        if let Some(tier) = claimed_tier {
+            TierClaimed {
+                campaign_id,
+                donor,
+                tier,
+            }
+            .publish(&env);
+        }
+        Ok(amount)
     }
 
-    /// Refund mechanism jika campaign gagal (hanya bisa dipanggil oleh donatur)
-    pub fn refund(env: Env, campaign_id: u64, donor: Address) -> i128 {
+    /// Donatur menarik sebagian / seluruh donasinya selama campaign Active
+    /// (jika diizinkan campaign). Mengembalikan sisa donasi donatur.
+    pub fn withdraw_pledge(
+        env: Env,
+        campaign_id: u64,
+        donor: Address,
+        amount: i128,
+    ) -> Result<i128, CrowdfundingError> {
+        extend_instance(&env);
         donor.require_auth();
 
-        let mut campaigns = CrowdfundingContract::get_campaigns_map(env.clone());
-        let mut campaign = CrowdfundingContract::get_campaign_internal(&campaigns, campaign_id);
+        if amount <= 0 {
+            return Err(CrowdfundingError::AmountNotPositive);
+        }
+
+        let mut campaign = load_campaign(&env, campaign_id)?;
+
+        match campaign.status {
+            CampaignStatus::Active => {}
+            CampaignStatus::Pending => return Err(CrowdfundingError::CampaignNotStarted),
+            CampaignStatus::Cancelled => return Err(CrowdfundingError::CampaignCancelled),
+            _ => return Err(CrowdfundingError::CampaignEnded),
+        }
+        if !campaign.pledge_withdrawal {
+            return Err(CrowdfundingError::PledgeWithdrawalDisabled);
+        }
+        let in_lock_window =
+            env.ledger().timestamp() >= campaign.deadline.saturating_sub(campaign.lock_window);
+        if (campaign.lock_on_goal && goal_reached(&campaign)) || in_lock_window {
+            return Err(CrowdfundingError::PledgeLocked);
+        }
+
+        let donation = load_donation(&env, campaign_id, &donor);
+        if donation <= 0 {
+            return Err(CrowdfundingError::NothingToRefund);
+        }
+        if amount > donation {
+            return Err(CrowdfundingError::AmountExceedsDonation);
+        }
+        let remaining = donation - amount;
+
+        // Update donasi, dana terkumpul, reward tier & keberatan
+        save_donation(&env, campaign_id, &donor, remaining);
+        campaign.raised -= amount;
+        if let Some(tier) = load_donor_tier(&env, campaign_id, &donor) {
+            let min_amount = load_tiers(&env, campaign_id)
+                .get(tier)
+                .map(|reward| reward.min_amount)
+                .unwrap_or(0);
+            if remaining < min_amount {
+                release_donor_tier(&env, campaign_id, &donor);
+            }
+        }
+        // Donatur yang menarik seluruh donasinya tidak lagi dihitung,
+        // termasuk keberatannya
+        if remaining == 0 {
+            campaign.donors -= 1;
+            let objection_key = DataKey::Objection(campaign_id, donor.clone());
+            if env.storage().persistent().has(&objection_key) {
+                env.storage().persistent().remove(&objection_key);
+                campaign.objections -= 1;
+            }
+        }
+        save_campaign(&env, campaign_id, &campaign);
+
+        let contract_address = env.current_contract_address();
+        transfer_token(&env, &campaign.token, &contract_address, &donor, amount)?;
 
-        if !CrowdfundingContract::is_ended(env.clone(), campaign_id) {
-            panic!("Campaign belum berakhir");
+        PledgeWithdrawn {
+            campaign_id,
+            donor,
+            amount,
+            raised: campaign.raised,
         }
-        if CrowdfundingContract::is_goal_reached(env.clone(), campaign_id) {
-            panic!("Goal sudah tercapai, tidak bisa refund");
+        .publish(&env);
+        Ok(remaining)
+    }
+
+    /// Refund mechanism jika campaign gagal atau dibatalkan owner
+    /// (hanya bisa dipanggil oleh donatur)
+    pub fn refund(env: Env, campaign_id: u64, donor: Address) -> Result<i128, CrowdfundingError> {
+        extend_instance(&env);
+        donor.require_auth();
+
+        let mut campaign = load_campaign(&env, campaign_id)?;
+
+        // Campaign yang dibatalkan bisa langsung di-refund tanpa menunggu deadline
+        match campaign.status {
+            CampaignStatus::Failed | CampaignStatus::Cancelled => {}
+            CampaignStatus::Pending | CampaignStatus::Active => {
+                return Err(CrowdfundingError::CampaignNotEnded)
+            }
+            CampaignStatus::Succeeded if goal_reached(&campaign) => {
+                return Err(CrowdfundingError::GoalReached)
+            }
+            CampaignStatus::Succeeded => return Err(CrowdfundingError::FundsKept),
+            // Dana yang sudah ditarik owner tidak boleh di-refund lagi
+            CampaignStatus::Claimed => return Err(CrowdfundingError::AlreadyClaimed),
         }
 
-        let donation_amount = campaign.donations.get(donor.clone()).unwrap_or(0);
+        let donation_amount = load_donation(&env, campaign_id, &donor);
 
         if donation_amount <= 0 {
-            panic!("Donatur tidak ditemukan atau sudah refund");
+            return Err(CrowdfundingError::NothingToRefund);
         }
 
-        // Update state campaign
-        campaign.donations.set(donor.clone(), 0);
-        campaign.raised -= donation_amount;
+        // Jika sebagian dana sudah dirilis ke owner (milestone), donatur
+        // mendapat bagian pro rata dari sisa dana di escrow
+        let refund_amount = if campaign.released > 0 {
+            donation_amount * (campaign.raised - campaign.released) / campaign.raised
+        } else {
+            donation_amount
+        };
 
-        // Simpan perubahan state
-        campaigns.set(campaign_id, campaign.clone());
-        env.storage().instance().set(&CAMPAIGNS, &campaigns);
+        // Update state campaign & lepas slot reward tier
+        save_donation(&env, campaign_id, &donor, 0);
+        release_donor_tier(&env, campaign_id, &donor);
+        campaign.released -= donation_amount - refund_amount;
+        campaign.raised -= donation_amount;
+        campaign.donors -= 1;
+        save_campaign(&env, campaign_id, &campaign);
 
-        // <<< AWAL PERBAIKAN (Blok #[cfg(not(test))] dihapus) >>>
-        
         // !! Gunakan token::Client generik di sini !!
-        let token_client = token::Client::new(&env, &campaign.token);
         let contract_address = env.current_contract_address();
-        token_client.transfer(&contract_address, &donor, &donation_amount);
-        
-        // <<< AKHIR PERBAIKAN >>>
+        transfer_token(&env, &campaign.token, &contract_address, &donor, refund_amount)?;
+
+        Refunded {
+            campaign_id,
+            donor,
+            amount: refund_amount,
+            raised: campaign.raised,
+        }
+        .publish(&env);
+
+        Ok(refund_amount)
+    }
+
+    /// Owner menarik dana campaign yang berhasil (deadline lewat & goal tercapai,
+    /// atau ada dana terkumpul untuk mode KeepItAll).
+    /// Hanya bisa dilakukan sekali. Mengembalikan jumlah yang diterima owner
+    /// (setelah dipotong fee platform).
+    pub fn withdraw(env: Env, campaign_id: u64) -> Result<i128, CrowdfundingError> {
+        extend_instance(&env);
+        let mut campaign = load_campaign(&env, campaign_id)?;
+
+        campaign.owner.require_auth();
+
+        match campaign.status {
+            CampaignStatus::Succeeded => {}
+            CampaignStatus::Pending | CampaignStatus::Active => {
+                return Err(CrowdfundingError::CampaignNotEnded)
+            }
+            CampaignStatus::Failed => return Err(CrowdfundingError::GoalNotReached),
+            CampaignStatus::Cancelled => return Err(CrowdfundingError::CampaignCancelled),
+            CampaignStatus::Claimed => return Err(CrowdfundingError::AlreadyClaimed),
+        }
+        if env.storage().persistent().has(&DataKey::Escrow(campaign_id)) {
+            return Err(CrowdfundingError::FundsInEscrow);
+        }
+
+        let amount = campaign.raised;
+        let fee = payout_fee(&campaign, amount);
+
+        // Tandai sudah di-claim sebelum transfer keluar
+        campaign.status = CampaignStatus::Claimed;
+        campaign.released = amount;
+        campaign.fee_paid += fee;
+        save_campaign(&env, campaign_id, &campaign);
+
+        let amount = send_payout(&env, campaign_id, &campaign, amount, fee)?;
+
+        Withdrawn {
+            campaign_id,
+            owner: campaign.owner,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(amount)
+    }
+
+    /// Approver menyetujui milestone berikutnya dan merilis bagiannya ke owner.
+    /// Hanya untuk campaign ber-milestone yang sudah Succeeded, sebelum due date
+    /// milestone tersebut. Mengembalikan jumlah yang diterima owner.
+    pub fn approve_milestone(env: Env, campaign_id: u64) -> Result<i128, CrowdfundingError> {
+        extend_instance(&env);
+        let mut campaign = load_campaign(&env, campaign_id)?;
+        let mut escrow = load_escrow(&env, campaign_id).ok_or(CrowdfundingError::NoMilestones)?;
+
+        approver(&escrow)?.require_auth();
+        require_milestone_open(&campaign)?;
+
+        release_milestone(&env, campaign_id, &mut campaign, &mut escrow)
+    }
+
+    /// Approver menolak milestone berikutnya. Campaign menjadi Failed dan
+    /// donatur bisa `refund` bagian pro rata dari dana yang belum dirilis.
+    pub fn reject_milestone(env: Env, campaign_id: u64) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        let mut campaign = load_campaign(&env, campaign_id)?;
+        let escrow = load_escrow(&env, campaign_id).ok_or(CrowdfundingError::NoMilestones)?;
+
+        approver(&escrow)?.require_auth();
+        require_milestone_open(&campaign)?;
+
+        reject_next_milestone(&env, campaign_id, &mut campaign, &escrow);
+        Ok(())
+    }
+
+    // --- Voting Donatur (mode Approval::DonorVote) ---
+
+    /// Owner mengajukan pencairan milestone berikutnya untuk di-voting donatur.
+    /// Harus diajukan sebelum due date milestone. Mengembalikan timestamp akhir voting.
+    pub fn propose_milestone(env: Env, campaign_id: u64) -> Result<u64, CrowdfundingError> {
+        extend_instance(&env);
+        let campaign = load_campaign(&env, campaign_id)?;
+        let escrow = load_escrow(&env, campaign_id).ok_or(CrowdfundingError::NoMilestones)?;
+
+        campaign.owner.require_auth();
+
+        let config = voting_config(&escrow)?;
+        if env.storage().persistent().has(&DataKey::Proposal(campaign_id)) {
+            return Err(CrowdfundingError::ProposalOpen);
+        }
+        require_milestone_open(&campaign)?;
+
+        let voting_end = env.ledger().timestamp().saturating_add(config.voting_period);
+        let milestone = escrow.next;
+        let proposal = Proposal {
+            milestone,
+            voting_end,
+            votes_for: 0,
+            votes_against: 0,
+        };
+        save_proposal(&env, campaign_id, &proposal);
+
+        ProposalCreated {
+            campaign_id,
+            milestone,
+            voting_end,
+        }
+        .publish(&env);
+        Ok(voting_end)
+    }
+
+    /// Donatur memberikan suara untuk proposal yang sedang berjalan.
+    /// Bobot suara = total donasi donatur ke campaign ini.
+    pub fn vote(
+        env: Env,
+        campaign_id: u64,
+        donor: Address,
+        approve: bool,
+    ) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        donor.require_auth();
+
+        // Proposal lama tidak boleh menerima suara setelah campaign tertutup
+        let campaign = load_campaign(&env, campaign_id)?;
+        require_milestone_open(&campaign)?;
+        let mut proposal = load_proposal(&env, campaign_id)?;
+
+        if env.ledger().timestamp() >= proposal.voting_end {
+            return Err(CrowdfundingError::VotingClosed);
+        }
+        let weight = load_donation(&env, campaign_id, &donor);
+        if weight <= 0 {
+            return Err(CrowdfundingError::NotDonor);
+        }
+        let vote_key = DataKey::Vote(campaign_id, proposal.milestone, donor.clone());
+        if env.storage().persistent().has(&vote_key) {
+            return Err(CrowdfundingError::AlreadyVoted);
+        }
+
+        if approve {
+            proposal.votes_for += weight;
+        } else {
+            proposal.votes_against += weight;
+        }
+        env.storage().persistent().set(&vote_key, &approve);
+        extend_persistent(&env, &vote_key);
+        save_proposal(&env, campaign_id, &proposal);
+
+        Voted {
+            campaign_id,
+            donor,
+            approve,
+            weight,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Menutup proposal setelah periode voting berakhir (siapapun bisa memanggil).
+    /// Jika quorum & threshold terpenuhi milestone dirilis ke owner, jika tidak
+    /// campaign menjadi Failed dan donatur bisa refund sisa dana.
+    /// Mengembalikan `true` jika proposal disetujui.
+    pub fn finalize_proposal(env: Env, campaign_id: u64) -> Result<bool, CrowdfundingError> {
+        extend_instance(&env);
+        let mut campaign = load_campaign(&env, campaign_id)?;
+        let mut escrow = load_escrow(&env, campaign_id).ok_or(CrowdfundingError::NoMilestones)?;
+        let config = voting_config(&escrow)?;
+        let proposal = load_proposal(&env, campaign_id)?;
+
+        if env.ledger().timestamp() < proposal.voting_end {
+            return Err(CrowdfundingError::VotingNotEnded);
+        }
+
+        let total_votes = proposal.votes_for + proposal.votes_against;
+        let quorum_met = total_votes * MAX_BPS as i128 >= campaign.raised * config.quorum_bps as i128;
+        let approved = quorum_met
+            && total_votes > 0
+            && proposal.votes_for * MAX_BPS as i128 >= total_votes * config.threshold_bps as i128;
+
+        env.storage().persistent().remove(&DataKey::Proposal(campaign_id));
+        if approved {
+            release_milestone(&env, campaign_id, &mut campaign, &mut escrow)?;
+        } else {
+            reject_next_milestone(&env, campaign_id, &mut campaign, &escrow);
+        }
+
+        ProposalFinalized {
+            campaign_id,
+            milestone: proposal.milestone,
+            approved,
+            votes_for: proposal.votes_for,
+            votes_against: proposal.votes_against,
+        }
+        .publish(&env);
+        Ok(approved)
+    }
+
+    /// Owner memperpanjang deadline campaign yang masih Active.
+    /// Total perpanjangan dibatasi `max_extension`, dan jika `objection_bps`
+    /// di-set, diblokir saat persentase donatur yang keberatan mencapai batas
+    /// tersebut (dihitung per donatur, bukan per jumlah donasi).
+    pub fn extend_deadline(
+        env: Env,
+        campaign_id: u64,
+        new_deadline: u64,
+    ) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        let mut campaign = load_campaign(&env, campaign_id)?;
+
+        campaign.owner.require_auth();
+
+        match campaign.status {
+            CampaignStatus::Active => {}
+            CampaignStatus::Pending => return Err(CrowdfundingError::CampaignNotStarted),
+            CampaignStatus::Cancelled => return Err(CrowdfundingError::CampaignCancelled),
+            _ => return Err(CrowdfundingError::CampaignEnded),
+        }
+
+        let old_deadline = campaign.deadline;
+        if new_deadline <= old_deadline {
+            return Err(CrowdfundingError::InvalidExtension);
+        }
+        let extended = campaign.extended + (new_deadline - old_deadline);
+        if extended > campaign.max_extension {
+            return Err(CrowdfundingError::InvalidExtension);
+        }
+        // Deadline tidak boleh melewati due date milestone pertama
+        if let Some(escrow) = load_escrow(&env, campaign_id) {
+            let first_due = escrow.milestones.get(0).map(|m| m.due_date).unwrap_or(u64::MAX);
+            if new_deadline >= first_due {
+                return Err(CrowdfundingError::InvalidExtension);
+            }
+        }
+        if let Some(bps) = campaign.objection_bps {
+            let objected = campaign.objections as u64 * MAX_BPS as u64;
+            if campaign.objections > 0 && objected >= campaign.donors as u64 * bps as u64 {
+                return Err(CrowdfundingError::ExtensionObjected);
+            }
+        }
+
+        campaign.deadline = new_deadline;
+        campaign.extended = extended;
+        save_campaign(&env, campaign_id, &campaign);
+
+        DeadlineExtended {
+            campaign_id,
+            old_deadline,
+            new_deadline,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Donatur menyatakan keberatan atas perpanjangan deadline.
+    /// Setiap donatur dihitung satu kali, berapapun donasinya.
+    pub fn object_extension(
+        env: Env,
+        campaign_id: u64,
+        donor: Address,
+    ) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        donor.require_auth();
+
+        let mut campaign = load_campaign(&env, campaign_id)?;
+        match campaign.status {
+            CampaignStatus::Active => {}
+            CampaignStatus::Pending => return Err(CrowdfundingError::CampaignNotStarted),
+            CampaignStatus::Cancelled => return Err(CrowdfundingError::CampaignCancelled),
+            _ => return Err(CrowdfundingError::CampaignEnded),
+        }
+        if load_donation(&env, campaign_id, &donor) <= 0 {
+            return Err(CrowdfundingError::NotDonor);
+        }
+        let key = DataKey::Objection(campaign_id, donor.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(CrowdfundingError::AlreadyObjected);
+        }
 
-        donation_amount
+        env.storage().persistent().set(&key, &true);
+        extend_persistent(&env, &key);
+        campaign.objections += 1;
+        save_campaign(&env, campaign_id, &campaign);
+
+        ExtensionObjected {
+            campaign_id,
+            donor,
+            objections: campaign.objections,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Owner membatalkan campaign yang masih berjalan.
+    /// Donasi baru langsung ditolak dan semua donatur bisa `refund`
+    /// tanpa menunggu deadline, berapapun dana yang sudah terkumpul.
+    pub fn cancel_campaign(env: Env, campaign_id: u64) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        let mut campaign = load_campaign(&env, campaign_id)?;
+
+        campaign.owner.require_auth();
+
+        match campaign.status {
+            CampaignStatus::Pending | CampaignStatus::Active => {}
+            CampaignStatus::Cancelled => return Err(CrowdfundingError::CampaignCancelled),
+            _ => return Err(CrowdfundingError::CampaignEnded),
+        }
+
+        campaign.status = CampaignStatus::Cancelled;
+        save_campaign(&env, campaign_id, &campaign);
+
+        CampaignCancelled {
+            campaign_id,
+            owner: campaign.owner,
+            raised: campaign.raised,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Owner mengubah metadata campaign.
+    /// Hanya bisa selama belum ada donasi dan campaign belum berakhir,
+    /// supaya donatur tidak "tertipu" oleh perubahan setelah berdonasi.
+    pub fn update_metadata(
+        env: Env,
+        campaign_id: u64,
+        metadata: CampaignMetadata,
+    ) -> Result<(), CrowdfundingError> {
+        extend_instance(&env);
+        let campaign = load_campaign(&env, campaign_id)?;
+
+        campaign.owner.require_auth();
+
+        let open = matches!(
+            campaign.status,
+            CampaignStatus::Pending | CampaignStatus::Active
+        );
+        if !open || campaign.raised > 0 {
+            return Err(CrowdfundingError::MetadataLocked);
+        }
+        validate_metadata(&metadata)?;
+
+        save_metadata(&env, campaign_id, &metadata);
+
+        MetadataUpdated {
+            campaign_id,
+            title: metadata.title,
+            category: metadata.category,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Check apakah campaign sudah dibatalkan owner
+    pub fn is_cancelled(env: Env, campaign_id: u64) -> Result<bool, CrowdfundingError> {
+        Ok(load_campaign(&env, campaign_id)?.status == CampaignStatus::Cancelled)
+    }
+
+    /// Check apakah dana campaign sudah ditarik oleh owner
+    pub fn is_claimed(env: Env, campaign_id: u64) -> Result<bool, CrowdfundingError> {
+        Ok(load_campaign(&env, campaign_id)?.status == CampaignStatus::Claimed)
     }
 
     // --- Fungsi Getter (Read-Only) ---
 
-    /// (Helper) Mengambil map semua campaign
-    fn get_campaigns_map(env: Env) -> Map<u64, Campaign> {
+    /// Get data lengkap dari satu campaign (untuk frontend)
+    pub fn get_campaign(env: Env, id: u64) -> Result<Campaign, CrowdfundingError> {
+        load_campaign(&env, id)
+    }
+
+    /// Get status campaign saat ini (lihat tabel transisi di CampaignStatus)
+    pub fn get_status(env: Env, id: u64) -> Result<CampaignStatus, CrowdfundingError> {
+        Ok(load_campaign(&env, id)?.status)
+    }
+
+    /// Get escrow milestone campaign (milestone, cara approval & progres)
+    pub fn get_escrow(env: Env, id: u64) -> Result<Escrow, CrowdfundingError> {
+        load_campaign(&env, id)?;
+        load_escrow(&env, id).ok_or(CrowdfundingError::NoMilestones)
+    }
+
+    /// Get proposal milestone yang sedang di-voting
+    pub fn get_proposal(env: Env, campaign_id: u64) -> Result<Proposal, CrowdfundingError> {
+        load_campaign(&env, campaign_id)?;
+        load_proposal(&env, campaign_id)
+    }
+
+    /// Check apakah donatur sudah voting untuk milestone tertentu.
+    /// `None` jika belum voting, `Some(true/false)` sesuai pilihannya.
+    pub fn get_vote(env: Env, campaign_id: u64, milestone: u32, donor: Address) -> Option<bool> {
         env.storage()
-            .instance()
-            .get(&CAMPAIGNS)
-            .unwrap_or_else(|| Map::new(&env))
+            .persistent()
+            .get(&DataKey::Vote(campaign_id, milestone, donor))
     }
 
-    /// (Helper) Mengambil satu campaign dari map
-    fn get_campaign_internal(campaigns: &Map<u64, Campaign>, id: u64) -> Campaign {
-        campaigns.get(id).expect("Campaign not found")
+    /// Get daftar reward tier campaign beserta sisa slot
+    pub fn get_tiers(env: Env, campaign_id: u64) -> Result<Vec<TierInfo>, CrowdfundingError> {
+        load_campaign(&env, campaign_id)?;
+        let mut infos = Vec::new(&env);
+        for (index, tier) in load_tiers(&env, campaign_id).iter().enumerate() {
+            let claimed = load_tier_claimed(&env, campaign_id, index as u32);
+            infos.push_back(TierInfo {
+                description: tier.description,
+                min_amount: tier.min_amount,
+                quantity: tier.quantity,
+                remaining: tier.quantity.saturating_sub(claimed),
+            });
+        }
+        Ok(infos)
     }
 
-    /// Get data lengkap dari satu campaign (untuk frontend)
-    pub fn get_campaign(env: Env, id: u64) -> Campaign {
-        let campaigns = CrowdfundingContract::get_campaigns_map(env);
-        CrowdfundingContract::get_campaign_internal(&campaigns, id)
+    /// Get index reward tier yang dipilih donatur (None jika tidak memilih tier)
+    pub fn get_donor_tier(
+        env: Env,
+        campaign_id: u64,
+        donor: Address,
+    ) -> Result<Option<u32>, CrowdfundingError> {
+        load_campaign(&env, campaign_id)?;
+        Ok(load_donor_tier(&env, campaign_id, &donor))
+    }
+
+    /// Get metadata tampilan campaign (judul, deskripsi, URI, kategori)
+    pub fn get_metadata(env: Env, id: u64) -> Result<CampaignMetadata, CrowdfundingError> {
+        load_metadata(&env, id)
+    }
+
+    // --- Listing (Paginated) ---
+    // `limit` dibatasi maksimal MAX_PAGE_SIZE item per panggilan.
+
+    /// Daftar campaign berdasarkan urutan ID, mulai dari ID `start`.
+    /// ID yang belum dipindah dari map CAMPAIGNS lama (lihat `migrate`) dilewati.
+    pub fn list_campaigns(
+        env: Env,
+        start: u64,
+        limit: u32,
+    ) -> Result<Vec<CampaignSummary>, CrowdfundingError> {
+        let next_id: u64 = env.storage().instance().get(&NEXT_ID).unwrap_or(0u64);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE) as u64).min(next_id);
+        let mut summaries = Vec::new(&env);
+        for id in start..end {
+            if let Ok(summary) = load_summary(&env, id) {
+                summaries.push_back(summary);
+            }
+        }
+        Ok(summaries)
+    }
+
+    /// Daftar campaign milik `owner`. `start` adalah posisi di index owner
+    /// (0 = campaign pertama yang dibuat owner tersebut).
+    pub fn list_campaigns_by_owner(
+        env: Env,
+        owner: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<CampaignSummary>, CrowdfundingError> {
+        let ids = read_index(
+            &env,
+            DataKey::OwnerCount(owner.clone()),
+            |i| DataKey::OwnerCampaign(owner.clone(), i),
+            start,
+            limit,
+        );
+        load_summaries(&env, ids)
+    }
+
+    /// Daftar campaign yang mengumpulkan `token`. `start` adalah posisi di index token.
+    pub fn list_campaigns_by_token(
+        env: Env,
+        token: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<CampaignSummary>, CrowdfundingError> {
+        let ids = read_index(
+            &env,
+            DataKey::TokenCount(token.clone()),
+            |i| DataKey::TokenCampaign(token.clone(), i),
+            start,
+            limit,
+        );
+        load_summaries(&env, ids)
+    }
+
+    /// Daftar campaign yang masih menerima donasi, mulai dari ID `start`.
+    /// Berhenti setelah `limit` campaign aktif atau MAX_SCAN ID diperiksa,
+    /// jadi halaman bisa kurang dari `limit`; lanjutkan dari `next`
+    /// sampai `next` bernilai None.
+    pub fn list_active_campaigns(env: Env, start: u64, limit: u32) -> ActivePage {
+        let next_id: u64 = env.storage().instance().get(&NEXT_ID).unwrap_or(0u64);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let scan_end = start.saturating_add(MAX_SCAN as u64).min(next_id);
+
+        let mut campaigns = Vec::new(&env);
+        let mut cursor = start;
+        while cursor < scan_end && campaigns.len() < limit {
+            if let Ok(summary) = load_summary(&env, cursor) {
+                if summary.status == CampaignStatus::Active {
+                    campaigns.push_back(summary);
+                }
+            }
+            cursor += 1;
+        }
+
+        ActivePage {
+            campaigns,
+            next: if cursor < next_id { Some(cursor) } else { None },
+        }
+    }
+
+    /// Get jumlah campaign milik `owner` (untuk pagination)
+    pub fn get_owner_campaign_count(env: Env, owner: Address) -> u32 {
+        env.storage().persistent().get(&DataKey::OwnerCount(owner)).unwrap_or(0)
+    }
+
+    /// Get jumlah campaign yang mengumpulkan `token` (untuk pagination)
+    pub fn get_token_campaign_count(env: Env, token: Address) -> u32 {
+        env.storage().persistent().get(&DataKey::TokenCount(token)).unwrap_or(0)
     }
 
     /// Get ID (u64) untuk campaign berikutnya
@@ -180,55 +2433,71 @@ impl CrowdfundingContract {
     }
 
     /// Get total amount yang sudah terkumpul untuk campaign tertentu
-    pub fn get_total_raised(env: Env, campaign_id: u64) -> i128 {
-        CrowdfundingContract::get_campaign(env, campaign_id).raised
+    pub fn get_total_raised(env: Env, campaign_id: u64) -> Result<i128, CrowdfundingError> {
+        Ok(load_campaign(&env, campaign_id)?.raised)
     }
 
     /// Get berapa banyak specific donor sudah donate ke campaign tertentu
-    pub fn get_donation(env: Env, campaign_id: u64, donor: Address) -> i128 {
-        let campaign = CrowdfundingContract::get_campaign(env, campaign_id);
-        campaign.donations.get(donor).unwrap_or(0)
+    pub fn get_donation(
+        env: Env,
+        campaign_id: u64,
+        donor: Address,
+    ) -> Result<i128, CrowdfundingError> {
+        // Pastikan campaign ada
+        load_campaign(&env, campaign_id)?;
+        Ok(load_donation(&env, campaign_id, &donor))
     }
 
     /// Get campaign goal amount
-    pub fn get_goal(env: Env, campaign_id: u64) -> i128 {
-        CrowdfundingContract::get_campaign(env, campaign_id).goal
+    pub fn get_goal(env: Env, campaign_id: u64) -> Result<i128, CrowdfundingError> {
+        Ok(load_campaign(&env, campaign_id)?.goal)
     }
 
     /// Get campaign deadline timestamp
-    pub fn get_deadline(env: Env, campaign_id: u64) -> u64 {
-        CrowdfundingContract::get_campaign(env, campaign_id).deadline
+    pub fn get_deadline(env: Env, campaign_id: u64) -> Result<u64, CrowdfundingError> {
+        Ok(load_campaign(&env, campaign_id)?.deadline)
     }
 
     /// Check apakah campaign sudah reach goal
-    pub fn is_goal_reached(env: Env, campaign_id: u64) -> bool {
-        let campaign = CrowdfundingContract::get_campaign(env, campaign_id);
-        campaign.goal > 0 && campaign.raised >= campaign.goal
+    pub fn is_goal_reached(env: Env, campaign_id: u64) -> Result<bool, CrowdfundingError> {
+        Ok(goal_reached(&load_campaign(&env, campaign_id)?))
     }
 
     /// Check apakah campaign sudah berakhir (deadline passed)
-     pub fn is_ended(env: Env, campaign_id: u64) -> bool {
-        // Tambahkan .clone() saat memanggil get_deadline
-        let deadline = CrowdfundingContract::get_deadline(env.clone(), campaign_id);
-        // Sekarang 'env' masih bisa dipakai di sini
-        deadline > 0 && env.ledger().timestamp() > deadline
+    pub fn is_ended(env: Env, campaign_id: u64) -> Result<bool, CrowdfundingError> {
+        Ok(ended(&env, &load_campaign(&env, campaign_id)?))
     }
 
     /// Calculate progress percentage dari campaign
-    pub fn get_progress_percentage(env: Env, campaign_id: u64) -> i128 {
-        let campaign = CrowdfundingContract::get_campaign(env, campaign_id);
+    pub fn get_progress_percentage(env: Env, campaign_id: u64) -> Result<i128, CrowdfundingError> {
+        let campaign = load_campaign(&env, campaign_id)?;
         if campaign.goal == 0 {
-            return 0;
+            return Ok(0);
         }
-        campaign
+        Ok(campaign
             .raised
             .checked_mul(100)
             .unwrap_or(i128::MAX)
             .checked_div(campaign.goal)
-            .unwrap_or(0)
+            .unwrap_or(0))
+    }
+
+    /// Get goal & raised campaign dalam satuan token, memakai `decimals()`
+    /// dari kontrak token campaign (mis. 25_000_000 @ 7 desimal = 2.5 token)
+    pub fn get_campaign_units(
+        env: Env,
+        campaign_id: u64,
+    ) -> Result<CampaignUnits, CrowdfundingError> {
+        let campaign = load_campaign(&env, campaign_id)?;
+        let decimals = token::Client::new(&env, &campaign.token).decimals();
+        Ok(CampaignUnits {
+            decimals,
+            goal: to_units(campaign.goal, decimals),
+            raised: to_units(campaign.raised, decimals),
+        })
     }
 }
 
 // Modul test harus ada di akhir
 #[cfg(test)]
-mod test;
\ No newline at end of file
+mod test;
@@ -6,42 +6,62 @@ use super::*;
 use ::token::{TokenContract, TokenContractClient}; // << DENGAN ::
 
 use soroban_sdk::{
-    testutils::{Address as _, Ledger, LedgerInfo},
-     Address, Env, // Hapus import yang tidak perlu lagi
+    symbol_short,
+    testutils::{storage::Instance as _, Address as _, EnvTestConfig, Events, Ledger, LedgerInfo},
+    vec, Address, Env, IntoVal, Map, String, Symbol, Val,
 };
 
 
-// Helper setup dasar (MENGGUNAKAN TOKEN KUSTOM)
+// Helper setup dasar (MENGGUNAKAN TOKEN KUSTOM), platform tanpa fee
 fn setup_test<'a>() -> (
     Env,
     CrowdfundingContractClient<'a>, // Client Crowdfunding
     TokenContractClient<'a>,        // Client Token Kustom
     Address,                        // Alamat Kontrak Token Kustom
     Address,                        // Alamat Admin Token/Pemilik Campaign Awal
+) {
+    setup_test_with_fee(0)
+}
+
+// Helper setup dengan fee platform `fee_bps` (maks 10%).
+// Admin & treasury platform dibaca lewat `platform_roles`.
+fn setup_test_with_fee<'a>(
+    fee_bps: u32,
+) -> (
+    Env,
+    CrowdfundingContractClient<'a>,
+    TokenContractClient<'a>,
+    Address,
+    Address,
 ) {
     let env = Env::default();
     // Tidak perlu mock_all_auths() di sini, panggil di test case
 
     // --- CROWDFUND CONTRACT ---
-    let crowdfunding_contract_id = env.register(CrowdfundingContract, ());
+    // Constructor langsung menyimpan admin & konfigurasi fee
+    let platform_admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let crowdfunding_contract_id = env.register(
+        CrowdfundingContract,
+        (&platform_admin, &treasury, fee_bps, 1_000u32),
+    );
     let crowdfunding_client = CrowdfundingContractClient::new(&env, &crowdfunding_contract_id);
 
     // --- TOKEN CONTRACT (KUSTOM) ---
-    let token_contract_id = env.register(TokenContract, ()); // << Register Token Kustom
-    let token_client = TokenContractClient::new(&env, &token_contract_id);
+    // Constructor token langsung mencetak supply awal ke admin
     let token_admin = Address::generate(&env); // Admin/pemilik token
-
-    // Inisialisasi token kustom (perlu mock_all_auths sementara)
-    let initial_supply = 1_000_000_000 * 10_000_000; // 1 Miliar token (misalnya @ 7 desimal)
-    { // Scope sementara untuk mock_all_auths
-        env.mock_all_auths();
-        token_client.initialize(
+    let initial_supply: i128 = 1_000_000_000 * 10_000_000; // 1 Miliar token (misalnya @ 7 desimal)
+    let token_contract_id = env.register(
+        TokenContract,
+        (
             &token_admin,
-            &soroban_sdk::String::from_str(&env, "Test Token"),
-            &soroban_sdk::String::from_str(&env, "TST"),
-            &initial_supply,
-        );
-    }
+            soroban_sdk::String::from_str(&env, "Test Token"),
+            soroban_sdk::String::from_str(&env, "TST"),
+            7u32,
+            initial_supply,
+        ),
+    ); // << Register Token Kustom
+    let token_client = TokenContractClient::new(&env, &token_contract_id);
     // token_admin sekarang punya semua supply awal
 
     // --- OTHER SETUP ---
@@ -66,6 +86,54 @@ fn setup_test<'a>() -> (
 }
 
 
+// Helper metadata campaign standar untuk test
+fn default_metadata(env: &Env) -> CampaignMetadata {
+    CampaignMetadata {
+        title: String::from_str(env, "Test Campaign"),
+        description: String::from_str(env, "Campaign untuk pengujian"),
+        metadata_uri: String::from_str(env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+        category: Category::Technology,
+    }
+}
+
+// Helper opsi campaign standar (all-or-nothing)
+fn default_config() -> CampaignConfig {
+    CampaignConfig {
+        funding_mode: FundingMode::AllOrNothing,
+        hard_cap: None,
+        min_donation: None,
+        max_per_donor: None,
+        over_limit: OverLimitPolicy::Reject,
+        start_time: None,
+        max_extension: 0,
+        objection_bps: None,
+        pledge_withdrawal: false,
+        lock_on_goal: false,
+        lock_window: 0,
+    }
+}
+
+// Helper create_campaign dengan opsi & metadata standar
+fn create_campaign(
+    client: &CrowdfundingContractClient,
+    owner: &Address,
+    goal: &i128,
+    deadline: &u64,
+    token: &Address,
+) -> u64 {
+    client.create_campaign(
+        owner,
+        goal,
+        deadline,
+        token,
+        &default_config(),
+        &default_metadata(&client.env),
+        &None,
+        &Vec::new(&client.env),
+    )
+}
+
+
 // --- FUNGSI HELPER BARU (Menggunakan transfer token kustom) ---
 // Helper untuk memberi dana donatur (transfer dari admin token)
 fn fund_donor<'a>(
@@ -104,7 +172,7 @@ fn test_create_campaign() {
     let deadline = env.ledger().timestamp() + 86400;
     env.mock_all_auths(); // Untuk create_campaign
 
-    let campaign_id = client.create_campaign(&owner, &goal, &deadline, &token_address);
+    let campaign_id = create_campaign(&client, &owner, &goal, &deadline, &token_address);
     assert_eq!(campaign_id, 0);
     assert_eq!(client.get_total_raised(&campaign_id), 0);
     assert_eq!(client.get_goal(&campaign_id), goal);
@@ -122,13 +190,13 @@ fn test_create_multiple_campaigns() {
 
     let goal1 = 100_000i128;
     let deadline1 = env.ledger().timestamp() + 100;
-    let campaign_id_1 = client.create_campaign(&owner1, &goal1, &deadline1, &token_address);
+    let campaign_id_1 = create_campaign(&client, &owner1, &goal1, &deadline1, &token_address);
     assert_eq!(campaign_id_1, 0);
 
     let owner2 = Address::generate(&env); // Owner baru
     let goal2 = 500_000i128;
     let deadline2 = env.ledger().timestamp() + 200;
-    let campaign_id_2 = client.create_campaign(&owner2, &goal2, &deadline2, &token_address);
+    let campaign_id_2 = create_campaign(&client, &owner2, &goal2, &deadline2, &token_address);
     assert_eq!(campaign_id_2, 1);
 
     assert_eq!(client.get_goal(&campaign_id_1), goal1);
@@ -143,7 +211,7 @@ fn test_get_donation_no_donation() {
     let deadline = env.ledger().timestamp() + 86400;
     env.mock_all_auths(); // Untuk create_campaign
 
-    let campaign_id = client.create_campaign(&owner, &goal, &deadline, &token_address);
+    let campaign_id = create_campaign(&client, &owner, &goal, &deadline, &token_address);
     assert_eq!(client.get_donation(&campaign_id, &non_donor), 0);
 }
 
@@ -162,27 +230,28 @@ fn test_donate_success() {
     // --- Selesai Setup Token ---
 
     env.mock_all_auths(); // Untuk create_campaign & donate (transfer dari donor)
-    let campaign_id = client.create_campaign(
+    let campaign_id = create_campaign(
+        &client,
         &token_admin, // Owner campaign = admin token
-        &1000_000_000i128,
+        &1_000_000_000i128,
         &(env.ledger().timestamp() + 86400),
         &token_address,
     );
 
     let amount1 = 100_000_000i128;
-    client.donate(&campaign_id, &donor1, &amount1);
+    client.donate(&campaign_id, &donor1, &amount1, &None);
     assert_eq!(client.get_total_raised(&campaign_id), amount1);
     assert_eq!(client.get_donation(&campaign_id, &donor1), amount1);
 
 
     let amount2 = 50_000_000i128;
-    client.donate(&campaign_id, &donor1, &amount2); // mock_all_auths() masih aktif
+    client.donate(&campaign_id, &donor1, &amount2, &None); // mock_all_auths() masih aktif
     assert_eq!(client.get_total_raised(&campaign_id), amount1 + amount2);
     assert_eq!(client.get_donation(&campaign_id, &donor1), amount1 + amount2);
 
 
     let amount3 = 300_000_000i128;
-    client.donate(&campaign_id, &donor2, &amount3); // mock_all_auths() masih aktif
+    client.donate(&campaign_id, &donor2, &amount3, &None); // mock_all_auths() masih aktif
     assert_eq!(
         client.get_total_raised(&campaign_id),
         amount1 + amount2 + amount3
@@ -192,40 +261,69 @@ fn test_donate_success() {
 }
 
 #[test]
-#[should_panic(expected = "Donation amount must be positive")]
 fn test_donate_zero_amount() {
     let (env, client, _token_client, token_address, owner) = setup_test();
     let donor = Address::generate(&env);
 
     env.mock_all_auths();
-    let campaign_id = client.create_campaign(
+    let campaign_id = create_campaign(
+        &client,
+        &owner,
+        &1000,
+        &(env.ledger().timestamp() + 100),
+        &token_address,
+    );
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &0, &None),
+        Err(Ok(CrowdfundingError::AmountNotPositive))
+    );
+}
+
+#[test]
+fn test_donate_insufficient_balance() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+
+    env.mock_all_auths();
+    let campaign_id = create_campaign(
+        &client,
         &owner,
         &1000,
         &(env.ledger().timestamp() + 100),
         &token_address,
     );
-    client.donate(&campaign_id, &donor, &0);
+    // Error token (InsufficientBalance = #6) tidak boleh terbaca sebagai
+    // error crowdfunding dengan kode yang sama
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &100, &None),
+        Err(Ok(CrowdfundingError::TokenTransferFailed))
+    );
+    assert_eq!(client.get_campaign(&campaign_id).raised, 0);
+    assert_eq!(client.get_donation(&campaign_id, &donor), 0);
+    assert_eq!(token_client.balance(&client.address), 0);
 }
 
 #[test]
-#[should_panic(expected = "Donation amount must be positive")]
 fn test_donate_negative_amount() {
     let (env, client, _token_client, token_address, owner) = setup_test();
     let donor = Address::generate(&env);
 
     env.mock_all_auths();
-    let campaign_id = client.create_campaign(
+    let campaign_id = create_campaign(
+        &client,
         &owner,
         &1000,
         &(env.ledger().timestamp() + 100),
         &token_address,
     );
-    client.donate(&campaign_id, &donor, &-100);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &-100, &None),
+        Err(Ok(CrowdfundingError::AmountNotPositive))
+    );
 }
 
 
 #[test]
-#[should_panic(expected = "Campaign has ended")]
 fn test_donate_after_deadline() {
     let (env, client, token_client, token_address, token_admin) = setup_test();
     let donor = Address::generate(&env);
@@ -235,11 +333,15 @@ fn test_donate_after_deadline() {
     env.mock_all_auths(); // Untuk fund_donor, create_campaign & donate
     fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
 
-    let campaign_id = client.create_campaign(&token_admin, &1000, &deadline, &token_address);
+    let campaign_id = create_campaign(&client, &token_admin, &1000, &deadline, &token_address);
 
     jump(&env, 101); // Lewati deadline
 
-    client.donate(&campaign_id, &donor, &100_000_000); // Ini akan panic
+    // Ini akan gagal
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &100_000_000, &None),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
 }
 
 
@@ -253,22 +355,23 @@ fn test_is_goal_reached() {
     env.mock_all_auths(); // Untuk fund_donor, create_campaign & donate
     fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
 
-    let campaign_id = client.create_campaign(
+    let campaign_id = create_campaign(
+        &client,
         &token_admin,
         &goal,
         &(env.ledger().timestamp() + 100),
         &token_address,
     );
-    assert_eq!(client.is_goal_reached(&campaign_id), false);
+    assert!(!client.is_goal_reached(&campaign_id));
 
-    client.donate(&campaign_id, &donor, &30_000_000);
-    assert_eq!(client.is_goal_reached(&campaign_id), false);
+    client.donate(&campaign_id, &donor, &30_000_000, &None);
+    assert!(!client.is_goal_reached(&campaign_id));
 
-    client.donate(&campaign_id, &donor, &20_000_000); // Total 50M
-    assert_eq!(client.is_goal_reached(&campaign_id), true);
+    client.donate(&campaign_id, &donor, &20_000_000, &None); // Total 50M
+    assert!(client.is_goal_reached(&campaign_id));
 
-    client.donate(&campaign_id, &donor, &10_000_000); // Total 60M
-    assert_eq!(client.is_goal_reached(&campaign_id), true);
+    client.donate(&campaign_id, &donor, &10_000_000, &None); // Total 60M
+    assert!(client.is_goal_reached(&campaign_id));
 }
 
 
@@ -277,13 +380,13 @@ fn test_is_ended() { // Tidak perlu mock auths tambahan
     let (env, client, _token_client, token_address, owner) = setup_test();
     let deadline = env.ledger().timestamp() + 1000;
     env.mock_all_auths(); // Untuk create_campaign
-    let campaign_id = client.create_campaign(&owner, &1000, &deadline, &token_address);
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
 
-    assert_eq!(client.is_ended(&campaign_id), false);
-    jump(&env, 1000);
-    assert_eq!(client.is_ended(&campaign_id), false);
-    jump(&env, 1);
-    assert_eq!(client.is_ended(&campaign_id), true);
+    assert!(!client.is_ended(&campaign_id));
+    jump(&env, 999);
+    assert!(!client.is_ended(&campaign_id));
+    jump(&env, 1); // Tepat di deadline sudah berakhir
+    assert!(client.is_ended(&campaign_id));
 }
 
 
@@ -297,7 +400,8 @@ fn test_get_progress_percentage() {
     env.mock_all_auths(); // Untuk fund_donor, create_campaign & donate
     fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
 
-    let campaign_id = client.create_campaign(
+    let campaign_id = create_campaign(
+        &client,
         &token_admin,
         &goal,
         &(env.ledger().timestamp() + 100),
@@ -305,16 +409,57 @@ fn test_get_progress_percentage() {
     );
 
     assert_eq!(client.get_progress_percentage(&campaign_id), 0);
-    client.donate(&campaign_id, &donor, &25_000_000);
+    client.donate(&campaign_id, &donor, &25_000_000, &None);
     assert_eq!(client.get_progress_percentage(&campaign_id), 25);
-    client.donate(&campaign_id, &donor, &25_000_000);
+    client.donate(&campaign_id, &donor, &25_000_000, &None);
     assert_eq!(client.get_progress_percentage(&campaign_id), 50);
-    client.donate(&campaign_id, &donor, &50_000_000);
+    client.donate(&campaign_id, &donor, &50_000_000, &None);
     assert_eq!(client.get_progress_percentage(&campaign_id), 100);
-    client.donate(&campaign_id, &donor, &20_000_000);
+    client.donate(&campaign_id, &donor, &20_000_000, &None);
     assert_eq!(client.get_progress_percentage(&campaign_id), 120);
 }
 
+#[test]
+fn test_get_campaign_units() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000_000_000);
+
+    // Token setup_test memakai 7 desimal
+    let campaign_id = create_campaign(&client, &token_admin, &100_000_000, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &25_000_005, &None);
+    assert_eq!(
+        client.get_campaign_units(&campaign_id),
+        CampaignUnits {
+            decimals: 7,
+            goal: UnitAmount { whole: 10, fraction: 0 },
+            raised: UnitAmount { whole: 2, fraction: 5_000_005 },
+        }
+    );
+
+    // Voucher token tanpa desimal
+    let voucher_address = env.register(
+        TokenContract,
+        (
+            &token_admin,
+            String::from_str(&env, "Voucher"),
+            String::from_str(&env, "VCH"),
+            0u32,
+            1_000i128,
+        ),
+    );
+    let voucher_id = create_campaign(&client, &token_admin, &50, &deadline, &voucher_address);
+    let units = client.get_campaign_units(&voucher_id);
+    assert_eq!(units.decimals, 0);
+    assert_eq!(units.goal, UnitAmount { whole: 50, fraction: 0 });
+
+    assert_eq!(
+        client.try_get_campaign_units(&99),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+}
 
 #[test]
 fn test_refund_success() {
@@ -327,10 +472,10 @@ fn test_refund_success() {
     env.mock_all_auths(); // Untuk fund_donor, create_campaign, donate & refund
     fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
 
-    let campaign_id = client.create_campaign(&token_admin, &goal, &deadline, &token_address);
+    let campaign_id = create_campaign(&client, &token_admin, &goal, &deadline, &token_address);
 
     let donation_amount = 30_000_000i128;
-    client.donate(&campaign_id, &donor, &donation_amount);
+    client.donate(&campaign_id, &donor, &donation_amount, &None);
     assert_eq!(client.get_donation(&campaign_id, &donor), donation_amount);
     assert_eq!(client.get_total_raised(&campaign_id), donation_amount);
 
@@ -344,7 +489,6 @@ fn test_refund_success() {
 }
 
 #[test]
-#[should_panic(expected = "Campaign belum berakhir")]
 fn test_refund_before_deadline() {
     let (env, client, token_client, token_address, token_admin) = setup_test();
     let donor = Address::generate(&env);
@@ -354,15 +498,17 @@ fn test_refund_before_deadline() {
     env.mock_all_auths(); // Untuk fund_donor, create_campaign, donate & refund
     fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
 
-    let campaign_id = client.create_campaign(&token_admin, &1000, &deadline, &token_address);
+    let campaign_id = create_campaign(&client, &token_admin, &1000, &deadline, &token_address);
 
-    client.donate(&campaign_id, &donor, &30_000_000);
-    client.refund(&campaign_id, &donor); // Panic
+    client.donate(&campaign_id, &donor, &30_000_000, &None);
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor),
+        Err(Ok(CrowdfundingError::CampaignNotEnded))
+    );
 }
 
 
 #[test]
-#[should_panic(expected = "Goal sudah tercapai, tidak bisa refund")]
 fn test_refund_when_goal_reached() {
     let (env, client, token_client, token_address, token_admin) = setup_test();
     let donor = Address::generate(&env);
@@ -373,50 +519,76 @@ fn test_refund_when_goal_reached() {
     env.mock_all_auths(); // Untuk fund_donor, create_campaign, donate & refund
     fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
 
-    let campaign_id = client.create_campaign(&token_admin, &goal, &deadline, &token_address);
+    let campaign_id = create_campaign(&client, &token_admin, &goal, &deadline, &token_address);
 
-    client.donate(&campaign_id, &donor, &goal);
-    assert_eq!(client.is_goal_reached(&campaign_id), true);
+    client.donate(&campaign_id, &donor, &goal, &None);
+    assert!(client.is_goal_reached(&campaign_id));
 
     jump(&env, 101);
-    client.refund(&campaign_id, &donor); // Panic
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor),
+        Err(Ok(CrowdfundingError::GoalReached))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Goal must be positive")]
 fn test_create_campaign_zero_goal() { // Tidak perlu mock auths tambahan
     let (env, client, _token_client, token_address, owner) = setup_test();
     let deadline = env.ledger().timestamp() + 86400;
     env.mock_all_auths(); // Untuk create_campaign
-    client.create_campaign(&owner, &0i128, &deadline, &token_address);
+    assert_eq!(
+        client.try_create_campaign(
+            &owner,
+            &0i128,
+            &deadline,
+            &token_address,
+            &default_config(),
+            &default_metadata(&env),
+            &None,
+            &Vec::new(&env),
+        ),
+        Err(Ok(CrowdfundingError::GoalNotPositive))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Deadline must be in the future")]
 fn test_create_campaign_past_deadline() { // Tidak perlu mock auths tambahan
     let (env, client, _token_client, token_address, owner) = setup_test();
     let goal = 100_000_000i128;
     let deadline = env.ledger().timestamp();
     env.mock_all_auths(); // Untuk create_campaign
-    client.create_campaign(&owner, &goal, &deadline, &token_address);
+    assert_eq!(
+        client.try_create_campaign(
+            &owner,
+            &goal,
+            &deadline,
+            &token_address,
+            &default_config(),
+            &default_metadata(&env),
+            &None,
+            &Vec::new(&env),
+        ),
+        Err(Ok(CrowdfundingError::DeadlineInPast))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Donatur tidak ditemukan atau sudah refund")]
 fn test_refund_no_donation() { // Perlu mock auth untuk refund
     let (env, client, _token_client, token_address, owner) = setup_test();
     let donor = Address::generate(&env);
     let deadline = env.ledger().timestamp() + 100;
 
     env.mock_all_auths(); // << Mock auths SEBELUM create_campaign & refund
-    let campaign_id = client.create_campaign(&owner, &1000, &deadline, &token_address);
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
 
     jump(&env, 101);
-    client.refund(&campaign_id, &donor); // Panic
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor),
+        Err(Ok(CrowdfundingError::NothingToRefund))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Donatur tidak ditemukan atau sudah refund")]
 fn test_refund_twice() {
     let (env, client, token_client, token_address, token_admin) = setup_test();
     let donor = Address::generate(&env);
@@ -428,20 +600,23 @@ fn test_refund_twice() {
 
     // <<< PERBAIKAN DI SINI: Ubah goal agar campaign GAGAL >>>
     // Goal 1000 diganti menjadi 100_000_000 (100 Juta)
-    let campaign_id = client.create_campaign(&token_admin, &100_000_000i128, &deadline, &token_address);
+    let campaign_id = create_campaign(&client, &token_admin, &100_000_000i128, &deadline, &token_address);
 
     let donation_amount = 30_000_000i128; // Donasi 30 Juta (Goal 100 Juta tidak tercapai)
-    client.donate(&campaign_id, &donor, &donation_amount);
+    client.donate(&campaign_id, &donor, &donation_amount, &None);
 
     jump(&env, 101); // Waktu berakhir
 
     client.refund(&campaign_id, &donor); // Refund pertama sukses
-    client.refund(&campaign_id, &donor); // Refund kedua panic (karena donasi sudah 0)
+    // Refund kedua gagal (karena donasi sudah 0)
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor),
+        Err(Ok(CrowdfundingError::NothingToRefund))
+    );
 }
 
 
 #[test]
-#[should_panic(expected = "Campaign not found")]
 fn test_interact_with_nonexistent_campaign() { // Perlu mock auth untuk donate
     let (env, client, _, _, _) = setup_test();
     let donor = Address::generate(&env);
@@ -449,5 +624,3261 @@ fn test_interact_with_nonexistent_campaign() { // Perlu mock auth untuk donate
 
     env.mock_all_auths(); // << Mock auths di sini
 
-    client.donate(&999, &donor, &amount); // Akan panic
-}
\ No newline at end of file
+    // Akan gagal
+    assert_eq!(
+        client.try_donate(&999, &donor, &amount, &None),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+}
+
+#[test]
+fn test_getters_nonexistent_campaign() {
+    let (_env, client, _, _, _) = setup_test();
+
+    assert_eq!(
+        client.try_get_campaign(&999),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+    assert_eq!(
+        client.try_is_ended(&999),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+}
+
+#[test]
+fn test_withdraw_success() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 50_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+    let initial_fund: i128 = 1_000_000_000;
+
+    env.mock_all_auths(); // Untuk fund_donor, create_campaign, donate & withdraw
+    fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
+
+    let campaign_id = create_campaign(&client, &owner, &goal, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &60_000_000, &None);
+    assert!(!client.is_claimed(&campaign_id));
+
+    jump(&env, 101);
+
+    let withdrawn = client.withdraw(&campaign_id);
+    assert_eq!(withdrawn, 60_000_000);
+    assert_eq!(token_client.balance(&owner), 60_000_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert!(client.is_claimed(&campaign_id));
+}
+
+#[test]
+fn test_withdraw_before_deadline() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let donor = Address::generate(&env);
+    let goal = 50_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+    let initial_fund: i128 = 1_000_000_000;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
+
+    let campaign_id = create_campaign(&client, &token_admin, &goal, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &goal, &None);
+
+    // Panic, goal tercapai tapi belum berakhir
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(CrowdfundingError::CampaignNotEnded))
+    );
+}
+
+#[test]
+fn test_withdraw_goal_not_reached() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let donor = Address::generate(&env);
+    let goal = 50_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+    let initial_fund: i128 = 1_000_000_000;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
+
+    let campaign_id = create_campaign(&client, &token_admin, &goal, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &10_000_000, &None);
+
+    jump(&env, 101);
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(CrowdfundingError::GoalNotReached))
+    );
+}
+
+#[test]
+fn test_withdraw_twice() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let donor = Address::generate(&env);
+    let goal = 50_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+    let initial_fund: i128 = 1_000_000_000;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
+
+    let campaign_id = create_campaign(&client, &token_admin, &goal, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &goal, &None);
+
+    jump(&env, 101);
+    client.withdraw(&campaign_id); // Withdraw pertama sukses
+    // Withdraw kedua gagal
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(CrowdfundingError::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_refund_after_withdraw() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let donor = Address::generate(&env);
+    let goal = 50_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+    let initial_fund: i128 = 1_000_000_000;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
+
+    let campaign_id = create_campaign(&client, &token_admin, &goal, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &goal, &None);
+
+    jump(&env, 101);
+    client.withdraw(&campaign_id);
+    // Gagal, dana sudah ditarik owner
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor),
+        Err(Ok(CrowdfundingError::AlreadyClaimed))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_withdraw_requires_owner_auth() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let goal = 50_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+    let initial_fund: i128 = 1_000_000_000;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &initial_fund);
+
+    let campaign_id = create_campaign(&client, &owner, &goal, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &goal, &None);
+
+    jump(&env, 101);
+
+    // Hapus semua mock auth: owner tidak menandatangani
+    env.set_auths(&[]);
+    client.withdraw(&campaign_id);
+}
+
+
+
+// --- Budget Tests (biaya per call tidak boleh naik seiring jumlah campaign) ---
+// Yang dibandingkan adalah footprint ledger dari invocation terakhir (jumlah
+// entry yang dibaca/ditulis & byte yang ditulis), karena itu yang menentukan
+// fee. CPU di test host tidak dipakai: host test menyimpan seluruh ledger di
+// satu map in-memory sehingga CPU ikut naik seiring total state test.
+
+// Helper: jalankan `f` dan kembalikan footprint invocation tsb
+// (entry dibaca, entry ditulis, byte ditulis)
+fn measure(env: &Env, f: impl FnOnce()) -> (u32, u32, u32) {
+    f();
+    let resources = env.cost_estimate().resources();
+    (
+        resources.memory_read_entries,
+        resources.write_entries,
+        resources.write_bytes,
+    )
+}
+
+fn assert_same_footprint(few: &(u32, u32, u32), many: &(u32, u32, u32)) {
+    assert_eq!(few, many, "footprint (read entries, write entries, write bytes) grew");
+}
+
+// Helper: setup_test tanpa test snapshot (state ratusan campaign terlalu besar)
+fn setup_budget_test<'a>() -> (
+    Env,
+    CrowdfundingContractClient<'a>,
+    TokenContractClient<'a>,
+    Address,
+    Address,
+) {
+    let (mut env, client, token_client, token_address, token_admin) = setup_test();
+    env.set_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    (env, client, token_client, token_address, token_admin)
+}
+
+#[test]
+fn test_donate_cost_independent_of_campaign_count() {
+    let (env, client, token_client, token_address, token_admin) = setup_budget_test();
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor1, &1_000_000_000);
+    fund_donor(&env, &token_client, &token_admin, &donor2, &1_000_000_000);
+
+    let campaign_id = create_campaign(&client, &token_admin, &1_000_000, &deadline, &token_address);
+    let few = measure(&env, || {
+        client.donate(&campaign_id, &donor1, &1_000, &None);
+    });
+
+    // Tambah banyak campaign lain, masing-masing dengan donasi
+    for _ in 0..200 {
+        let other = create_campaign(&client, &token_admin, &1_000_000, &deadline, &token_address);
+        client.donate(&other, &donor1, &10, &None);
+    }
+
+    let many = measure(&env, || {
+        client.donate(&campaign_id, &donor2, &1_000, &None);
+    });
+    assert_same_footprint(&few, &many);
+}
+
+#[test]
+fn test_create_and_refund_cost_independent_of_campaign_count() {
+    let (env, client, token_client, token_address, token_admin) = setup_budget_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000_000_000);
+
+    let mut first = 0;
+    let create_few = measure(&env, || {
+        first = create_campaign(&client, &token_admin, &1_000_000, &deadline, &token_address);
+    });
+    client.donate(&first, &donor, &1_000, &None);
+
+    let mut last = first;
+    for _ in 0..200 {
+        last = create_campaign(&client, &token_admin, &1_000_000, &deadline, &token_address);
+        client.donate(&last, &donor, &1_000, &None);
+    }
+    let create_many = measure(&env, || {
+        create_campaign(&client, &token_admin, &1_000_000, &deadline, &token_address);
+    });
+    assert_same_footprint(&create_few, &create_many);
+
+    jump(&env, 101);
+    let refund_first = measure(&env, || {
+        client.refund(&first, &donor);
+    });
+    let refund_last = measure(&env, || {
+        client.refund(&last, &donor);
+    });
+    assert_same_footprint(&refund_first, &refund_last);
+}
+
+
+// --- Event Tests ---
+
+#[test]
+fn test_create_campaign_emits_event() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let goal = 100_000_000i128;
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    let campaign_id = create_campaign(&client, &owner, &goal, &deadline, &token_address);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "campaign_created"), campaign_id, owner.clone()).into_val(&env),
+                Map::<Symbol, Val>::from_array(
+                    &env,
+                    [
+                        (symbol_short!("goal"), goal.into_val(&env)),
+                        (symbol_short!("deadline"), deadline.into_val(&env)),
+                        (symbol_short!("token"), token_address.into_val(&env)),
+                    ],
+                )
+                .into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_donate_emits_events() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000_000_000);
+    let campaign_id = create_campaign(&client, &token_admin, &100_000_000, &deadline, &token_address);
+
+    client.donate(&campaign_id, &donor, &30_000_000, &None);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            // Event transfer dari kontrak token
+            (
+                token_address.clone(),
+                (symbol_short!("transfer"), donor.clone(), client.address.clone()).into_val(&env),
+                30_000_000i128.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (symbol_short!("donated"), campaign_id, donor.clone()).into_val(&env),
+                Map::<Symbol, Val>::from_array(
+                    &env,
+                    [
+                        (symbol_short!("amount"), 30_000_000i128.into_val(&env)),
+                        (symbol_short!("raised"), 30_000_000i128.into_val(&env)),
+                    ],
+                )
+                .into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_refund_emits_events() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000_000_000);
+    let campaign_id = create_campaign(&client, &token_admin, &100_000_000, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &30_000_000, &None);
+
+    jump(&env, 101);
+    client.refund(&campaign_id, &donor);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                token_address.clone(),
+                (symbol_short!("transfer"), client.address.clone(), donor.clone()).into_val(&env),
+                30_000_000i128.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (symbol_short!("refunded"), campaign_id, donor.clone()).into_val(&env),
+                Map::<Symbol, Val>::from_array(
+                    &env,
+                    [
+                        (symbol_short!("amount"), 30_000_000i128.into_val(&env)),
+                        (symbol_short!("raised"), 0i128.into_val(&env)),
+                    ],
+                )
+                .into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_withdraw_emits_events() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000_000_000);
+    let campaign_id = create_campaign(&client, &owner, &50_000_000, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &50_000_000, &None);
+
+    jump(&env, 101);
+    client.withdraw(&campaign_id);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                token_address.clone(),
+                (symbol_short!("transfer"), client.address.clone(), owner.clone()).into_val(&env),
+                50_000_000i128.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (symbol_short!("withdrawn"), campaign_id, owner.clone()).into_val(&env),
+                Map::<Symbol, Val>::from_array(
+                    &env,
+                    [
+                        (symbol_short!("amount"), 50_000_000i128.into_val(&env)),
+                    ],
+                )
+                .into_val(&env),
+            ),
+        ]
+    );
+}
+
+
+// --- Metadata Tests ---
+
+const LONG_TEXT: [u8; 300] = [b'x'; 300];
+
+#[test]
+fn test_create_campaign_stores_metadata() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    assert_eq!(client.get_metadata(&campaign_id), default_metadata(&env));
+}
+
+#[test]
+fn test_create_campaign_invalid_metadata() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    let long_text = core::str::from_utf8(&LONG_TEXT).unwrap();
+
+    let mut metadata = default_metadata(&env);
+    metadata.title = String::from_str(&env, "");
+    assert_eq!(
+        client.try_create_campaign(&owner, &1000, &deadline, &token_address, &default_config(), &metadata, &None, &Vec::new(&env)),
+        Err(Ok(CrowdfundingError::InvalidTitle))
+    );
+
+    let mut metadata = default_metadata(&env);
+    metadata.title = String::from_str(&env, &long_text[..65]);
+    assert_eq!(
+        client.try_create_campaign(&owner, &1000, &deadline, &token_address, &default_config(), &metadata, &None, &Vec::new(&env)),
+        Err(Ok(CrowdfundingError::InvalidTitle))
+    );
+
+    let mut metadata = default_metadata(&env);
+    metadata.description = String::from_str(&env, &long_text[..281]);
+    assert_eq!(
+        client.try_create_campaign(&owner, &1000, &deadline, &token_address, &default_config(), &metadata, &None, &Vec::new(&env)),
+        Err(Ok(CrowdfundingError::DescriptionTooLong))
+    );
+
+    let mut metadata = default_metadata(&env);
+    metadata.metadata_uri = String::from_str(&env, "http://example.com/campaign.json");
+    assert_eq!(
+        client.try_create_campaign(&owner, &1000, &deadline, &token_address, &default_config(), &metadata, &None, &Vec::new(&env)),
+        Err(Ok(CrowdfundingError::InvalidMetadataUri))
+    );
+
+    let mut long_uri = [b'x'; 258];
+    long_uri[..8].copy_from_slice(b"https://");
+    let mut metadata = default_metadata(&env);
+    metadata.metadata_uri = String::from_bytes(&env, &long_uri);
+    assert_eq!(
+        client.try_create_campaign(&owner, &1000, &deadline, &token_address, &default_config(), &metadata, &None, &Vec::new(&env)),
+        Err(Ok(CrowdfundingError::InvalidMetadataUri))
+    );
+
+    assert_eq!(client.get_next_id(), 0);
+}
+
+#[test]
+fn test_create_campaign_metadata_limits() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    let long_text = core::str::from_utf8(&LONG_TEXT).unwrap();
+
+    // Tepat di batas maksimum & URI kosong masih valid
+    let metadata = CampaignMetadata {
+        title: String::from_str(&env, &long_text[..64]),
+        description: String::from_str(&env, &long_text[..280]),
+        metadata_uri: String::from_str(&env, ""),
+        category: Category::Charity,
+    };
+    let campaign_id = client.create_campaign(&owner, &1000, &deadline, &token_address, &default_config(), &metadata, &None, &Vec::new(&env));
+    assert_eq!(client.get_metadata(&campaign_id), metadata);
+
+    let mut https_metadata = metadata.clone();
+    https_metadata.metadata_uri = String::from_str(&env, "https://example.com/campaign.json");
+    client.create_campaign(&owner, &1000, &deadline, &token_address, &default_config(), &https_metadata, &None, &Vec::new(&env));
+}
+
+#[test]
+fn test_update_metadata() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+
+    let metadata = CampaignMetadata {
+        title: String::from_str(&env, "Judul Baru"),
+        description: String::from_str(&env, "Deskripsi baru"),
+        metadata_uri: String::from_str(&env, "https://example.com/new.json"),
+        category: Category::Education,
+    };
+    client.update_metadata(&campaign_id, &metadata);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "metadata_updated"), campaign_id).into_val(&env),
+                Map::<Symbol, Val>::from_array(
+                    &env,
+                    [
+                        (symbol_short!("title"), metadata.title.clone().into_val(&env)),
+                        (symbol_short!("category"), Category::Education.into_val(&env)),
+                    ],
+                )
+                .into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_metadata(&campaign_id), metadata);
+}
+
+#[test]
+fn test_update_metadata_locked_after_donation() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000_000);
+    let campaign_id = create_campaign(&client, &token_admin, &1000, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &10, &None);
+
+    assert_eq!(
+        client.try_update_metadata(&campaign_id, &default_metadata(&env)),
+        Err(Ok(CrowdfundingError::MetadataLocked))
+    );
+}
+
+#[test]
+fn test_update_metadata_locked_after_deadline() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+
+    jump(&env, 100);
+    assert_eq!(
+        client.try_update_metadata(&campaign_id, &default_metadata(&env)),
+        Err(Ok(CrowdfundingError::MetadataLocked))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_update_metadata_requires_owner_auth() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+
+    // Hapus semua mock auth: owner tidak menandatangani
+    env.set_auths(&[]);
+    client.update_metadata(&campaign_id, &default_metadata(&env));
+}
+
+
+// --- Listing Tests ---
+
+// Helper: ambil ID dari hasil list_*
+fn summary_ids(env: &Env, summaries: &Vec<CampaignSummary>) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for summary in summaries.iter() {
+        ids.push_back(summary.id);
+    }
+    ids
+}
+
+#[test]
+fn test_list_campaigns_paginated() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    for _ in 0..5 {
+        create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    }
+
+    let page = client.list_campaigns(&0, &2);
+    assert_eq!(summary_ids(&env, &page), vec![&env, 0, 1]);
+    assert_eq!(summary_ids(&env, &client.list_campaigns(&2, &2)), vec![&env, 2, 3]);
+    assert_eq!(summary_ids(&env, &client.list_campaigns(&4, &2)), vec![&env, 4]);
+    assert_eq!(client.list_campaigns(&5, &2).len(), 0);
+    assert_eq!(client.list_campaigns(&u64::MAX, &u32::MAX).len(), 0);
+
+    assert_eq!(
+        page.get(0).unwrap(),
+        CampaignSummary {
+            id: 0,
+            owner,
+            token: token_address,
+            title: String::from_str(&env, "Test Campaign"),
+            category: Category::Technology,
+            goal: 1000,
+            raised: 0,
+            deadline,
+            status: CampaignStatus::Active,
+        }
+    );
+}
+
+#[test]
+fn test_list_campaigns_by_owner_and_token() {
+    let (env, client, _token_client, token_address, owner1) = setup_test();
+    let owner2 = Address::generate(&env);
+    let other_token = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    create_campaign(&client, &owner1, &1000, &deadline, &token_address); // 0
+    create_campaign(&client, &owner2, &1000, &deadline, &other_token); // 1
+    create_campaign(&client, &owner1, &1000, &deadline, &other_token); // 2
+    create_campaign(&client, &owner2, &1000, &deadline, &token_address); // 3
+    create_campaign(&client, &owner1, &1000, &deadline, &token_address); // 4
+
+    assert_eq!(client.get_owner_campaign_count(&owner1), 3);
+    assert_eq!(client.get_owner_campaign_count(&owner2), 2);
+    assert_eq!(
+        summary_ids(&env, &client.list_campaigns_by_owner(&owner1, &0, &10)),
+        vec![&env, 0, 2, 4]
+    );
+    assert_eq!(
+        summary_ids(&env, &client.list_campaigns_by_owner(&owner1, &1, &1)),
+        vec![&env, 2]
+    );
+    assert_eq!(
+        summary_ids(&env, &client.list_campaigns_by_owner(&owner2, &0, &10)),
+        vec![&env, 1, 3]
+    );
+
+    assert_eq!(client.get_token_campaign_count(&token_address), 3);
+    assert_eq!(
+        summary_ids(&env, &client.list_campaigns_by_token(&token_address, &0, &10)),
+        vec![&env, 0, 3, 4]
+    );
+    assert_eq!(
+        summary_ids(&env, &client.list_campaigns_by_token(&other_token, &0, &10)),
+        vec![&env, 1, 2]
+    );
+
+    // Owner / token tanpa campaign
+    let stranger = Address::generate(&env);
+    assert_eq!(client.get_owner_campaign_count(&stranger), 0);
+    assert_eq!(client.list_campaigns_by_owner(&stranger, &0, &10).len(), 0);
+    assert_eq!(client.list_campaigns_by_token(&stranger, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_list_active_campaigns() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000_000);
+
+    create_campaign(&client, &owner, &1000, &(now + 100), &token_address); // 0
+    create_campaign(&client, &owner, &1000, &(now + 300), &token_address); // 1
+    create_campaign(&client, &owner, &1000, &(now + 200), &token_address); // 2
+    client.donate(&1, &donor, &250, &None);
+
+    let page = client.list_active_campaigns(&0, &10);
+    assert_eq!(summary_ids(&env, &page.campaigns), vec![&env, 0, 1, 2]);
+    assert_eq!(page.next, None);
+
+    jump(&env, 150);
+    let active = client.list_active_campaigns(&0, &10).campaigns;
+    assert_eq!(summary_ids(&env, &active), vec![&env, 1, 2]);
+    assert_eq!(active.get(0).unwrap().raised, 250);
+    // Halaman penuh: lanjutkan dari cursor `next`
+    let page = client.list_active_campaigns(&0, &1);
+    assert_eq!(summary_ids(&env, &page.campaigns), vec![&env, 1]);
+    assert_eq!(page.next, Some(2));
+    let page = client.list_active_campaigns(&2, &1);
+    assert_eq!(summary_ids(&env, &page.campaigns), vec![&env, 2]);
+    assert_eq!(page.next, None);
+
+    jump(&env, 1000);
+    assert_eq!(client.list_active_campaigns(&0, &10).campaigns.len(), 0);
+}
+
+#[test]
+fn test_list_active_campaigns_scan_is_bounded() {
+    let (env, client, _token_client, token_address, owner) = setup_budget_test();
+    let now = env.ledger().timestamp();
+    env.mock_all_auths();
+
+    // MAX_SCAN campaign yang akan berakhir, diikuti 2 campaign aktif
+    for _ in 0..MAX_SCAN {
+        create_campaign(&client, &owner, &1000, &(now + 10), &token_address);
+    }
+    create_campaign(&client, &owner, &1000, &(now + 1000), &token_address);
+    create_campaign(&client, &owner, &1000, &(now + 1000), &token_address);
+    jump(&env, 100);
+
+    // Halaman pertama kosong tapi memberi cursor, bukan berhenti diam-diam
+    let page = client.list_active_campaigns(&0, &10);
+    assert_eq!(page.campaigns.len(), 0);
+    assert_eq!(page.next, Some(MAX_SCAN as u64));
+
+    let page = client.list_active_campaigns(&page.next.unwrap(), &10);
+    assert_eq!(
+        summary_ids(&env, &page.campaigns),
+        vec![&env, MAX_SCAN as u64, MAX_SCAN as u64 + 1]
+    );
+    assert_eq!(page.next, None);
+}
+
+#[test]
+fn test_list_campaigns_skips_unmigrated_ids() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    // Campaign 0 masih di map CAMPAIGNS lama (belum di-migrate)
+    env.as_contract(&client.address, || {
+        let mut campaigns: Map<u64, LegacyCampaign> = Map::new(&env);
+        campaigns.set(
+            0,
+            LegacyCampaign {
+                owner: owner.clone(),
+                goal: 1000,
+                deadline,
+                token: token_address.clone(),
+                raised: 0,
+                donations: Map::new(&env),
+            },
+        );
+        env.storage().instance().set(&CAMPAIGNS, &campaigns);
+        env.storage().instance().set(&NEXT_ID, &1u64);
+    });
+    create_campaign(&client, &owner, &1000, &deadline, &token_address);
+
+    assert_eq!(summary_ids(&env, &client.list_campaigns(&0, &10)), vec![&env, 1]);
+    assert_eq!(
+        summary_ids(&env, &client.list_active_campaigns(&0, &10).campaigns),
+        vec![&env, 1]
+    );
+}
+
+#[test]
+fn test_list_page_size_is_capped() {
+    let (env, client, _token_client, token_address, owner) = setup_budget_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    for _ in 0..(MAX_PAGE_SIZE + 5) {
+        create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    }
+
+    assert_eq!(client.list_campaigns(&0, &u32::MAX).len(), MAX_PAGE_SIZE);
+    assert_eq!(client.list_active_campaigns(&0, &u32::MAX).campaigns.len(), MAX_PAGE_SIZE);
+    assert_eq!(client.list_campaigns_by_owner(&owner, &0, &u32::MAX).len(), MAX_PAGE_SIZE);
+    assert_eq!(
+        client.list_campaigns_by_token(&token_address, &0, &u32::MAX).len(),
+        MAX_PAGE_SIZE
+    );
+    assert_eq!(client.list_campaigns_by_owner(&owner, &MAX_PAGE_SIZE, &u32::MAX).len(), 5);
+}
+
+#[test]
+fn test_list_by_owner_cost_independent_of_campaign_count() {
+    let (env, client, _token_client, token_address, owner) = setup_budget_test();
+    let other_owner = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    let few = measure(&env, || {
+        client.list_campaigns_by_owner(&owner, &0, &10);
+    });
+
+    for _ in 0..200 {
+        create_campaign(&client, &other_owner, &1000, &deadline, &token_address);
+    }
+    let many = measure(&env, || {
+        client.list_campaigns_by_owner(&owner, &0, &10);
+    });
+    assert_same_footprint(&few, &many);
+}
+
+
+// --- Cancellation Tests ---
+
+#[test]
+fn test_cancel_campaign_allows_refund_before_deadline() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor1, &1_000);
+    fund_donor(&env, &token_client, &owner, &donor2, &1_000);
+    let campaign_id = create_campaign(&client, &owner, &500, &deadline, &token_address);
+    client.donate(&campaign_id, &donor1, &400, &None);
+    client.donate(&campaign_id, &donor2, &300, &None); // Goal sudah tercapai
+
+    client.cancel_campaign(&campaign_id);
+    assert_eq!(client.get_campaign(&campaign_id).status, CampaignStatus::Cancelled);
+    assert!(client.is_cancelled(&campaign_id));
+
+    // Donasi baru ditolak
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor1, &100, &None),
+        Err(Ok(CrowdfundingError::CampaignCancelled))
+    );
+
+    // Refund langsung bisa walau deadline belum lewat & goal tercapai
+    assert_eq!(client.refund(&campaign_id, &donor1), 400);
+    assert_eq!(client.refund(&campaign_id, &donor2), 300);
+    assert_eq!(token_client.balance(&donor1), 1_000);
+    assert_eq!(token_client.balance(&donor2), 1_000);
+    assert_eq!(client.get_total_raised(&campaign_id), 0);
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor1),
+        Err(Ok(CrowdfundingError::NothingToRefund))
+    );
+
+    // Owner tidak bisa menarik dana campaign yang dibatalkan
+    jump(&env, 2000);
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(CrowdfundingError::CampaignCancelled))
+    );
+}
+
+#[test]
+fn test_cancel_campaign_errors() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.try_cancel_campaign(&99),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    client.cancel_campaign(&campaign_id);
+    assert_eq!(
+        client.try_cancel_campaign(&campaign_id),
+        Err(Ok(CrowdfundingError::CampaignCancelled))
+    );
+    assert_eq!(
+        client.try_update_metadata(&campaign_id, &default_metadata(&env)),
+        Err(Ok(CrowdfundingError::MetadataLocked))
+    );
+
+    // Campaign yang sudah berakhir tidak bisa dibatalkan
+    let ended_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    jump(&env, 100);
+    assert_eq!(
+        client.try_cancel_campaign(&ended_id),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+    assert!(!client.is_cancelled(&ended_id));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_cancel_campaign_requires_owner_auth() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+
+    // Hapus semua mock auth: owner tidak menandatangani
+    env.set_auths(&[]);
+    client.cancel_campaign(&campaign_id);
+}
+
+#[test]
+fn test_cancel_campaign_emits_event() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &250, &None);
+
+    client.cancel_campaign(&campaign_id);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "campaign_cancelled"), campaign_id, owner).into_val(&env),
+                Map::<Symbol, Val>::from_array(&env, [(symbol_short!("raised"), 250i128.into_val(&env))])
+                    .into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_list_active_campaigns_excludes_cancelled() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    client.cancel_campaign(&0);
+
+    assert_eq!(
+        summary_ids(&env, &client.list_active_campaigns(&0, &10).campaigns),
+        vec![&env, 1]
+    );
+    assert_eq!(client.list_campaigns(&0, &10).get(0).unwrap().status, CampaignStatus::Cancelled);
+}
+
+
+// --- Status Tests ---
+
+#[test]
+fn test_status_active_to_succeeded_at_deadline() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+    let campaign_id = create_campaign(&client, &owner, &500, &deadline, &token_address);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Active);
+
+    client.donate(&campaign_id, &donor, &500, &None);
+    // Goal tercapai tapi campaign tetap Active sampai deadline
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Active);
+
+    // Satu detik sebelum deadline: masih bisa donasi, belum bisa withdraw
+    jump(&env, 99);
+    client.donate(&campaign_id, &donor, &100, &None);
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(CrowdfundingError::CampaignNotEnded))
+    );
+
+    // Tepat di deadline: donasi ditutup & withdraw dibuka
+    jump(&env, 1);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Succeeded);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &100, &None),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor),
+        Err(Ok(CrowdfundingError::GoalReached))
+    );
+    assert_eq!(
+        client.try_cancel_campaign(&campaign_id),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+
+    // Succeeded -> Claimed
+    assert_eq!(client.withdraw(&campaign_id), 600);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Claimed);
+    assert_eq!(client.get_campaign(&campaign_id).status, CampaignStatus::Claimed);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &100, &None),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+    assert_eq!(
+        client.try_cancel_campaign(&campaign_id),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+}
+
+#[test]
+fn test_status_active_to_failed_at_deadline() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+    let campaign_id = create_campaign(&client, &owner, &500, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &200, &None);
+
+    jump(&env, 99);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Active);
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor),
+        Err(Ok(CrowdfundingError::CampaignNotEnded))
+    );
+
+    // Tepat di deadline: refund dibuka
+    jump(&env, 1);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Failed);
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(CrowdfundingError::GoalNotReached))
+    );
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &100, &None),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+    assert_eq!(client.refund(&campaign_id, &donor), 200);
+
+    // Failed adalah status akhir
+    jump(&env, 1000);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Failed);
+}
+
+#[test]
+fn test_status_active_to_cancelled() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+    let campaign_id = create_campaign(&client, &owner, &500, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &500, &None);
+
+    client.cancel_campaign(&campaign_id);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Cancelled);
+
+    // Cancelled tetap Cancelled walau deadline lewat & goal tercapai
+    jump(&env, 100);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Cancelled);
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(CrowdfundingError::CampaignCancelled))
+    );
+    assert_eq!(client.refund(&campaign_id, &donor), 500);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Cancelled);
+}
+
+#[test]
+fn test_get_status_nonexistent_campaign() {
+    let (_env, client, _token_client, _token_address, _owner) = setup_test();
+    assert_eq!(
+        client.try_get_status(&999),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+}
+
+
+// --- Milestone Escrow Tests ---
+
+// Helper: 3 milestone (30% / 30% / 40%) dengan due date 100, 200, 300 detik setelah deadline
+fn milestone_plan(env: &Env, approver: &Address, deadline: u64) -> MilestonePlan {
+    let milestone = |description: &str, percentage: u32, due_date: u64| Milestone {
+        description: String::from_str(env, description),
+        percentage,
+        due_date,
+    };
+    MilestonePlan {
+        approval: Approval::Approver(approver.clone()),
+        milestones: vec![
+            env,
+            milestone("Prototype", 30, deadline + 100),
+            milestone("Beta", 30, deadline + 200),
+            milestone("Launch", 40, deadline + 300),
+        ],
+    }
+}
+
+// Helper: campaign ber-milestone, didanai 2 donatur (donor1 700, donor2 300)
+fn setup_milestone_campaign<'a>() -> (
+    Env,
+    CrowdfundingContractClient<'a>,
+    TokenContractClient<'a>,
+    Address, // owner
+    Address, // donor1
+    Address, // donor2
+    u64,     // campaign_id
+) {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let approver = Address::generate(&env);
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor1, &700);
+    fund_donor(&env, &token_client, &token_admin, &donor2, &300);
+    let campaign_id = client.create_campaign(
+        &owner,
+        &1000,
+        &deadline,
+        &token_address,
+        &default_config(),
+        &default_metadata(&env),
+        &Some(milestone_plan(&env, &approver, deadline)),
+        &Vec::new(&env),
+    );
+    client.donate(&campaign_id, &donor1, &700, &None);
+    client.donate(&campaign_id, &donor2, &300, &None);
+
+    (env, client, token_client, owner, donor1, donor2, campaign_id)
+}
+
+#[test]
+fn test_milestones_released_on_approval() {
+    let (env, client, token_client, owner, _donor1, _donor2, campaign_id) =
+        setup_milestone_campaign();
+
+    // Belum berakhir
+    assert_eq!(
+        client.try_approve_milestone(&campaign_id),
+        Err(Ok(CrowdfundingError::CampaignNotEnded))
+    );
+
+    jump(&env, 100);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Succeeded);
+    // Dana ditahan di escrow, tidak bisa withdraw sekaligus
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(CrowdfundingError::FundsInEscrow))
+    );
+
+    assert_eq!(client.approve_milestone(&campaign_id), 300);
+    // Event terakhir (setelah event transfer token)
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "milestone_released"), campaign_id, 0u32).into_val(&env),
+                Map::<Symbol, Val>::from_array(&env, [(symbol_short!("amount"), 300i128.into_val(&env))])
+                    .into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.approve_milestone(&campaign_id), 300);
+    assert_eq!(client.get_campaign(&campaign_id).released, 600);
+    assert_eq!(client.get_escrow(&campaign_id).next, 2);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Succeeded);
+
+    assert_eq!(client.approve_milestone(&campaign_id), 400);
+    assert_eq!(token_client.balance(&owner), 1000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Claimed);
+    assert_eq!(
+        client.try_approve_milestone(&campaign_id),
+        Err(Ok(CrowdfundingError::MilestoneClosed))
+    );
+}
+
+#[test]
+fn test_milestone_rejected_refunds_remainder_pro_rata() {
+    let (env, client, token_client, owner, donor1, donor2, campaign_id) =
+        setup_milestone_campaign();
+
+    jump(&env, 100);
+    assert_eq!(client.approve_milestone(&campaign_id), 300);
+    client.reject_milestone(&campaign_id);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Failed);
+    assert_eq!(
+        client.try_approve_milestone(&campaign_id),
+        Err(Ok(CrowdfundingError::MilestoneClosed))
+    );
+
+    // Sisa 700 dibagi sesuai porsi donasi (70% / 30%)
+    assert_eq!(client.refund(&campaign_id, &donor1), 490);
+    assert_eq!(client.refund(&campaign_id, &donor2), 210);
+    assert_eq!(token_client.balance(&owner), 300);
+    assert_eq!(token_client.balance(&client.address), 0);
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!((campaign.raised, campaign.released), (0, 0));
+}
+
+#[test]
+fn test_milestone_missed_refunds_remainder() {
+    let (env, client, token_client, _owner, donor1, donor2, campaign_id) =
+        setup_milestone_campaign();
+
+    jump(&env, 100);
+    assert_eq!(client.approve_milestone(&campaign_id), 300);
+
+    // Milestone kedua lewat due date (deadline + 200) tanpa approval
+    jump(&env, 199);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Succeeded);
+    jump(&env, 1);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Failed);
+    assert_eq!(
+        client.try_approve_milestone(&campaign_id),
+        Err(Ok(CrowdfundingError::MilestoneClosed))
+    );
+
+    assert_eq!(client.refund(&campaign_id, &donor2), 210);
+    assert_eq!(client.refund(&campaign_id, &donor1), 490);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_milestone_refund_rounding_leaves_no_dust() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let approver = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    let campaign_id = client.create_campaign(
+        &owner,
+        &10,
+        &deadline,
+        &token_address,
+        &default_config(),
+        &default_metadata(&env),
+        &Some(milestone_plan(&env, &approver, deadline)),
+        &Vec::new(&env),
+    );
+    let donors = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for donor in donors.iter() {
+        fund_donor(&env, &token_client, &token_admin, donor, &7);
+        client.donate(&campaign_id, donor, &7, &None);
+    }
+
+    jump(&env, 100);
+    assert_eq!(client.approve_milestone(&campaign_id), 6); // 30% dari 21
+    client.reject_milestone(&campaign_id);
+
+    let mut refunded = 0;
+    for donor in donors.iter() {
+        refunded += client.refund(&campaign_id, donor);
+    }
+    assert_eq!(refunded, 15);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_milestone_refund_rounding_to_zero() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let approver = Address::generate(&env);
+    let big_donor = Address::generate(&env);
+    let small_donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    let campaign_id = client.create_campaign(
+        &owner,
+        &1000,
+        &deadline,
+        &token_address,
+        &default_config(),
+        &default_metadata(&env),
+        &Some(milestone_plan(&env, &approver, deadline)),
+        &Vec::new(&env),
+    );
+    fund_donor(&env, &token_client, &token_admin, &big_donor, &999);
+    fund_donor(&env, &token_client, &token_admin, &small_donor, &1);
+    client.donate(&campaign_id, &big_donor, &999, &None);
+    client.donate(&campaign_id, &small_donor, &1, &None);
+
+    jump(&env, 100);
+    client.approve_milestone(&campaign_id);
+    client.approve_milestone(&campaign_id);
+    client.reject_milestone(&campaign_id);
+
+    // Bagian pro rata donatur kecil dibulatkan ke 0: refund tetap tercatat
+    assert_eq!(client.refund(&campaign_id, &small_donor), 0);
+    assert_eq!(client.get_donation(&campaign_id, &small_donor), 0);
+    assert_eq!(
+        client.try_refund(&campaign_id, &small_donor),
+        Err(Ok(CrowdfundingError::NothingToRefund))
+    );
+    assert_eq!(client.get_campaign(&campaign_id).donors, 1);
+
+    // Sisanya tidak terkunci di kontrak
+    assert_eq!(client.refund(&campaign_id, &big_donor), 400);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_milestone_tranche_rounding_to_zero() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let approver = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    let campaign_id = client.create_campaign(
+        &owner,
+        &2,
+        &deadline,
+        &token_address,
+        &default_config(),
+        &default_metadata(&env),
+        &Some(milestone_plan(&env, &approver, deadline)),
+        &Vec::new(&env),
+    );
+    fund_donor(&env, &token_client, &token_admin, &donor, &2);
+    client.donate(&campaign_id, &donor, &2, &None);
+
+    // 30% dari 2 = 0: milestone tetap bisa disetujui tanpa transfer
+    jump(&env, 100);
+    assert_eq!(client.approve_milestone(&campaign_id), 0);
+    assert_eq!(client.approve_milestone(&campaign_id), 0);
+    assert_eq!(client.approve_milestone(&campaign_id), 2);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Claimed);
+    assert_eq!(token_client.balance(&owner), 2);
+}
+
+#[test]
+fn test_milestone_errors() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let approver = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    // Campaign tanpa milestone
+    let plain_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    assert_eq!(
+        client.try_approve_milestone(&plain_id),
+        Err(Ok(CrowdfundingError::NoMilestones))
+    );
+    assert_eq!(
+        client.try_reject_milestone(&plain_id),
+        Err(Ok(CrowdfundingError::NoMilestones))
+    );
+    assert_eq!(client.try_get_escrow(&plain_id), Err(Ok(CrowdfundingError::NoMilestones)));
+    assert_eq!(
+        client.try_get_escrow(&99),
+        Err(Ok(CrowdfundingError::CampaignNotFound))
+    );
+
+    // Goal tidak tercapai: milestone tidak bisa diproses
+    let plan = milestone_plan(&env, &approver, deadline);
+    let campaign_id = client.create_campaign(
+        &owner,
+        &1000,
+        &deadline,
+        &token_address,
+        &default_config(),
+        &default_metadata(&env),
+        &Some(plan.clone()),
+        &Vec::new(&env),
+    );
+    assert_eq!(client.get_escrow(&campaign_id).milestones, plan.milestones);
+    jump(&env, 100);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Failed);
+    assert_eq!(
+        client.try_reject_milestone(&campaign_id),
+        Err(Ok(CrowdfundingError::MilestoneClosed))
+    );
+}
+
+#[test]
+fn test_create_campaign_invalid_milestones() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let approver = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    let try_create = |milestones: Vec<Milestone>| {
+        client.try_create_campaign(
+            &owner,
+            &1000,
+            &deadline,
+            &token_address,
+            &default_config(),
+            &default_metadata(&env),
+            &Some(MilestonePlan {
+                approval: Approval::Approver(approver.clone()),
+                milestones,
+            }),
+            &Vec::new(&env),
+        )
+    };
+    let milestone = |percentage: u32, due_date: u64| Milestone {
+        description: String::from_str(&env, "Tahap"),
+        percentage,
+        due_date,
+    };
+    let invalid = Err(Ok(CrowdfundingError::InvalidMilestones));
+
+    // Kosong
+    assert_eq!(try_create(Vec::new(&env)), invalid);
+    // Total bukan 100%
+    assert_eq!(
+        try_create(vec![&env, milestone(50, deadline + 1), milestone(40, deadline + 2)]),
+        invalid
+    );
+    // Persentase 0
+    assert_eq!(
+        try_create(vec![&env, milestone(100, deadline + 1), milestone(0, deadline + 2)]),
+        invalid
+    );
+    // Due date tidak setelah deadline
+    assert_eq!(try_create(vec![&env, milestone(100, deadline)]), invalid);
+    // Due date tidak urut naik
+    assert_eq!(
+        try_create(vec![&env, milestone(50, deadline + 2), milestone(50, deadline + 2)]),
+        invalid
+    );
+    // Lebih dari MAX_MILESTONES
+    let mut too_many = Vec::new(&env);
+    for i in 0..(MAX_MILESTONES + 1) {
+        too_many.push_back(milestone(if i == 0 { 90 } else { 1 }, deadline + 1 + i as u64));
+    }
+    assert_eq!(try_create(too_many), invalid);
+
+    assert_eq!(client.get_next_id(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_approve_milestone_requires_approver_auth() {
+    let (env, client, _token_client, _owner, _donor1, _donor2, campaign_id) =
+        setup_milestone_campaign();
+    jump(&env, 100);
+
+    // Hapus semua mock auth: approver tidak menandatangani
+    env.set_auths(&[]);
+    client.approve_milestone(&campaign_id);
+}
+
+
+// --- Donor Voting Tests ---
+
+// Helper: campaign ber-milestone dengan voting donatur (quorum 50%, threshold 60%,
+// voting 50 detik). Donasi: donor1 600, donor2 300, donor3 100. Deadline sudah lewat.
+fn setup_voting_campaign<'a>() -> (
+    Env,
+    CrowdfundingContractClient<'a>,
+    TokenContractClient<'a>,
+    Address,      // owner
+    [Address; 3], // donors
+    u64,          // campaign_id
+) {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+    let mut plan = milestone_plan(&env, &owner, deadline);
+    plan.approval = Approval::DonorVote(VotingConfig {
+        quorum_bps: 5_000,
+        threshold_bps: 6_000,
+        voting_period: 50,
+    });
+    let campaign_id = client.create_campaign(
+        &owner,
+        &1000,
+        &deadline,
+        &token_address,
+        &default_config(),
+        &default_metadata(&env),
+        &Some(plan),
+        &Vec::new(&env),
+    );
+
+    let donors = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for (donor, amount) in donors.iter().zip([600i128, 300, 100]) {
+        fund_donor(&env, &token_client, &token_admin, donor, &amount);
+        client.donate(&campaign_id, donor, &amount, &None);
+    }
+    jump(&env, 100);
+
+    (env, client, token_client, owner, donors, campaign_id)
+}
+
+#[test]
+fn test_vote_approves_milestone() {
+    let (env, client, token_client, owner, donors, campaign_id) = setup_voting_campaign();
+
+    let voting_end = client.propose_milestone(&campaign_id);
+    assert_eq!(voting_end, env.ledger().timestamp() + 50);
+
+    client.vote(&campaign_id, &donors[0], &true);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("voted"), campaign_id, donors[0].clone()).into_val(&env),
+                Map::<Symbol, Val>::from_array(
+                    &env,
+                    [
+                        (symbol_short!("approve"), true.into_val(&env)),
+                        (symbol_short!("weight"), 600i128.into_val(&env)),
+                    ],
+                )
+                .into_val(&env),
+            ),
+        ]
+    );
+    client.vote(&campaign_id, &donors[2], &false);
+
+    let proposal = client.get_proposal(&campaign_id);
+    assert_eq!((proposal.votes_for, proposal.votes_against), (600, 100));
+    assert_eq!(client.get_vote(&campaign_id, &0, &donors[0]), Some(true));
+    assert_eq!(client.get_vote(&campaign_id, &0, &donors[2]), Some(false));
+    assert_eq!(client.get_vote(&campaign_id, &0, &donors[1]), None);
+
+    jump(&env, 50);
+    // 700/1000 memenuhi quorum 50%, 600/700 memenuhi threshold 60%
+    assert!(client.finalize_proposal(&campaign_id));
+    assert_eq!(token_client.balance(&owner), 300);
+    assert_eq!(client.get_escrow(&campaign_id).next, 1);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Succeeded);
+    assert_eq!(
+        client.try_get_proposal(&campaign_id),
+        Err(Ok(CrowdfundingError::NoProposal))
+    );
+
+    // Milestone berikutnya butuh proposal & voting baru
+    client.propose_milestone(&campaign_id);
+    assert_eq!(client.get_proposal(&campaign_id).milestone, 1);
+    assert_eq!(client.get_vote(&campaign_id, &1, &donors[0]), None);
+    client.vote(&campaign_id, &donors[0], &true);
+}
+
+#[test]
+fn test_vote_rejects_milestone_below_threshold() {
+    let (env, client, token_client, _owner, donors, campaign_id) = setup_voting_campaign();
+
+    client.propose_milestone(&campaign_id);
+    client.vote(&campaign_id, &donors[0], &false);
+    client.vote(&campaign_id, &donors[1], &true);
+
+    jump(&env, 50);
+    // 300/900 setuju, di bawah threshold 60%
+    assert!(!client.finalize_proposal(&campaign_id));
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Failed);
+
+    // Belum ada yang dirilis, semua donatur mendapat refund penuh
+    assert_eq!(client.refund(&campaign_id, &donors[0]), 600);
+    assert_eq!(client.refund(&campaign_id, &donors[1]), 300);
+    assert_eq!(client.refund(&campaign_id, &donors[2]), 100);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_vote_rejects_milestone_without_quorum() {
+    let (env, client, _token_client, _owner, donors, campaign_id) = setup_voting_campaign();
+
+    client.propose_milestone(&campaign_id);
+    client.vote(&campaign_id, &donors[1], &true);
+
+    jump(&env, 50);
+    // 300/1000 memilih, di bawah quorum 50%
+    assert!(!client.finalize_proposal(&campaign_id));
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Failed);
+}
+
+#[test]
+fn test_open_proposal_prevents_missed_milestone() {
+    let (env, client, _token_client, _owner, donors, campaign_id) = setup_voting_campaign();
+
+    // Proposal diajukan 10 detik sebelum due date, voting berakhir setelahnya
+    jump(&env, 90);
+    client.propose_milestone(&campaign_id);
+    client.vote(&campaign_id, &donors[0], &true);
+    jump(&env, 20);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Succeeded);
+
+    jump(&env, 30);
+    assert!(client.finalize_proposal(&campaign_id));
+}
+
+#[test]
+fn test_voting_errors() {
+    let (env, client, _token_client, _owner, donors, campaign_id) = setup_voting_campaign();
+    let stranger = Address::generate(&env);
+
+    // Belum ada proposal
+    assert_eq!(
+        client.try_vote(&campaign_id, &donors[0], &true),
+        Err(Ok(CrowdfundingError::NoProposal))
+    );
+    assert_eq!(
+        client.try_finalize_proposal(&campaign_id),
+        Err(Ok(CrowdfundingError::NoProposal))
+    );
+    // Mode voting: approver tidak dipakai
+    assert_eq!(
+        client.try_approve_milestone(&campaign_id),
+        Err(Ok(CrowdfundingError::WrongApprovalMode))
+    );
+    assert_eq!(
+        client.try_reject_milestone(&campaign_id),
+        Err(Ok(CrowdfundingError::WrongApprovalMode))
+    );
+
+    client.propose_milestone(&campaign_id);
+    assert_eq!(
+        client.try_propose_milestone(&campaign_id),
+        Err(Ok(CrowdfundingError::ProposalOpen))
+    );
+    assert_eq!(
+        client.try_vote(&campaign_id, &stranger, &true),
+        Err(Ok(CrowdfundingError::NotDonor))
+    );
+    client.vote(&campaign_id, &donors[0], &true);
+    assert_eq!(
+        client.try_vote(&campaign_id, &donors[0], &false),
+        Err(Ok(CrowdfundingError::AlreadyVoted))
+    );
+    assert_eq!(
+        client.try_finalize_proposal(&campaign_id),
+        Err(Ok(CrowdfundingError::VotingNotEnded))
+    );
+
+    jump(&env, 50);
+    assert_eq!(
+        client.try_vote(&campaign_id, &donors[1], &true),
+        Err(Ok(CrowdfundingError::VotingClosed))
+    );
+}
+
+#[test]
+fn test_vote_after_cancellation() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &500);
+
+    let mut plan = milestone_plan(&env, &owner, deadline);
+    plan.approval = Approval::DonorVote(VotingConfig {
+        quorum_bps: 5_000,
+        threshold_bps: 6_000,
+        voting_period: 50,
+    });
+    let campaign_id = client.create_campaign(
+        &owner,
+        &1000,
+        &deadline,
+        &token_address,
+        &default_config(),
+        &default_metadata(&env),
+        &Some(plan),
+        &Vec::new(&env),
+    );
+    client.donate(&campaign_id, &donor, &500, &None);
+
+    // Campaign aktif belum bisa di-voting
+    assert_eq!(
+        client.try_vote(&campaign_id, &donor, &true),
+        Err(Ok(CrowdfundingError::CampaignNotEnded))
+    );
+
+    // Proposal yang tertinggal tidak boleh menerima suara setelah cancel
+    client.cancel_campaign(&campaign_id);
+    env.as_contract(&client.address, || {
+        save_proposal(
+            &env,
+            campaign_id,
+            &Proposal {
+                milestone: 0,
+                voting_end: deadline + 50,
+                votes_for: 0,
+                votes_against: 0,
+            },
+        );
+    });
+    assert_eq!(
+        client.try_vote(&campaign_id, &donor, &true),
+        Err(Ok(CrowdfundingError::CampaignCancelled))
+    );
+    assert_eq!(client.get_proposal(&campaign_id).votes_for, 0);
+}
+
+#[test]
+fn test_propose_milestone_requires_vote_mode() {
+    let (env, client, _token_client, _owner, _donor1, _donor2, campaign_id) =
+        setup_milestone_campaign();
+    jump(&env, 100);
+
+    assert_eq!(
+        client.try_propose_milestone(&campaign_id),
+        Err(Ok(CrowdfundingError::WrongApprovalMode))
+    );
+}
+
+#[test]
+fn test_create_campaign_invalid_voting_config() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    let invalid_configs = [
+        (10_001, 5_000, 50), // quorum > 100%
+        (5_000, 0, 50),      // threshold 0
+        (5_000, 10_001, 50), // threshold > 100%
+        (5_000, 5_000, 0),   // periode voting 0
+    ];
+    for (quorum_bps, threshold_bps, voting_period) in invalid_configs {
+        let mut plan = milestone_plan(&env, &owner, deadline);
+        plan.approval = Approval::DonorVote(VotingConfig {
+            quorum_bps,
+            threshold_bps,
+            voting_period,
+        });
+        assert_eq!(
+            client.try_create_campaign(
+                &owner,
+                &1000,
+                &deadline,
+                &token_address,
+                &default_config(),
+                &default_metadata(&env),
+                &Some(plan),
+                &Vec::new(&env),
+            ),
+            Err(Ok(CrowdfundingError::InvalidVotingConfig))
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_propose_milestone_requires_owner_auth() {
+    let (env, client, _token_client, _owner, _donors, campaign_id) = setup_voting_campaign();
+
+    // Hapus semua mock auth: owner tidak menandatangani
+    env.set_auths(&[]);
+    client.propose_milestone(&campaign_id);
+}
+
+
+// --- Reward Tier Tests ---
+
+// Helper: campaign dengan 2 tier: "Stiker" (min 100, 2 slot) & "Kaos" (min 500, 1 slot)
+fn create_tier_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    owner: &Address,
+    token: &Address,
+) -> u64 {
+    let tier = |description: &str, min_amount: i128, quantity: u32| RewardTier {
+        description: String::from_str(env, description),
+        min_amount,
+        quantity,
+    };
+    client.create_campaign(
+        owner,
+        &10_000,
+        &(env.ledger().timestamp() + 100),
+        token,
+        &default_config(),
+        &default_metadata(env),
+        &None,
+        &vec![env, tier("Stiker", 100, 2), tier("Kaos", 500, 1)],
+    )
+}
+
+#[test]
+fn test_donate_with_tier() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let donor3 = Address::generate(&env);
+    env.mock_all_auths();
+    for donor in [&donor1, &donor2, &donor3] {
+        fund_donor(&env, &token_client, &owner, donor, &1_000);
+    }
+    let campaign_id = create_tier_campaign(&env, &client, &owner, &token_address);
+
+    client.donate(&campaign_id, &donor1, &100, &Some(0));
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "tier_claimed"), campaign_id, donor1.clone()).into_val(&env),
+                0u32.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_donor_tier(&campaign_id, &donor1), Some(0));
+
+    // Donasi tanpa memilih tier tetap bisa
+    client.donate(&campaign_id, &donor2, &50, &None);
+    assert_eq!(client.get_donor_tier(&campaign_id, &donor2), None);
+
+    // Minimum dihitung dari total donasi donatur (50 + 50)
+    client.donate(&campaign_id, &donor2, &50, &Some(0));
+    assert_eq!(client.get_donor_tier(&campaign_id, &donor2), Some(0));
+
+    let tiers = client.get_tiers(&campaign_id);
+    assert_eq!(tiers.len(), 2);
+    assert_eq!(
+        tiers.get(0).unwrap(),
+        TierInfo {
+            description: String::from_str(&env, "Stiker"),
+            min_amount: 100,
+            quantity: 2,
+            remaining: 0,
+        }
+    );
+    assert_eq!(tiers.get(1).unwrap().remaining, 1);
+
+    // Kuota tier 0 habis
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor3, &100, &Some(0)),
+        Err(Ok(CrowdfundingError::TierSoldOut))
+    );
+    // Donasi ulang di tier yang sama tidak mengambil slot baru
+    client.donate(&campaign_id, &donor1, &10, &Some(0));
+    // ...dan tidak memancarkan tier_claimed lagi
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("donated"), campaign_id, donor1.clone()).into_val(&env),
+                Map::<Symbol, Val>::from_array(
+                    &env,
+                    [
+                        (symbol_short!("amount"), 10i128.into_val(&env)),
+                        (symbol_short!("raised"), 210i128.into_val(&env)),
+                    ],
+                )
+                .into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_tiers(&campaign_id).get(0).unwrap().remaining, 0);
+}
+
+#[test]
+fn test_donate_tier_errors() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+    let campaign_id = create_tier_campaign(&env, &client, &owner, &token_address);
+
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &99, &Some(0)),
+        Err(Ok(CrowdfundingError::TierBelowMinimum))
+    );
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &100, &Some(2)),
+        Err(Ok(CrowdfundingError::TierNotFound))
+    );
+    // Donasi yang gagal tidak mentransfer token
+    assert_eq!(token_client.balance(&donor), 1_000);
+    assert_eq!(client.get_total_raised(&campaign_id), 0);
+
+    // Campaign tanpa tier
+    let deadline = env.ledger().timestamp() + 100;
+    let plain_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    assert_eq!(client.get_tiers(&plain_id).len(), 0);
+    assert_eq!(
+        client.try_donate(&plain_id, &donor, &100, &Some(0)),
+        Err(Ok(CrowdfundingError::TierNotFound))
+    );
+    assert_eq!(client.try_get_tiers(&99), Err(Ok(CrowdfundingError::CampaignNotFound)));
+}
+
+#[test]
+fn test_donor_upgrades_tier() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+    let campaign_id = create_tier_campaign(&env, &client, &owner, &token_address);
+
+    client.donate(&campaign_id, &donor, &100, &Some(0));
+    client.donate(&campaign_id, &donor, &400, &Some(1));
+
+    assert_eq!(client.get_donor_tier(&campaign_id, &donor), Some(1));
+    let tiers = client.get_tiers(&campaign_id);
+    assert_eq!(tiers.get(0).unwrap().remaining, 2);
+    assert_eq!(tiers.get(1).unwrap().remaining, 0);
+}
+
+#[test]
+fn test_refund_releases_tier_slot() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+    let campaign_id = create_tier_campaign(&env, &client, &owner, &token_address);
+
+    client.donate(&campaign_id, &donor, &500, &Some(1));
+    client.cancel_campaign(&campaign_id);
+    assert_eq!(client.refund(&campaign_id, &donor), 500);
+
+    assert_eq!(client.get_donor_tier(&campaign_id, &donor), None);
+    assert_eq!(client.get_tiers(&campaign_id).get(1).unwrap().remaining, 1);
+}
+
+#[test]
+fn test_create_campaign_invalid_tiers() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    let tier = |description: &str, min_amount: i128, quantity: u32| RewardTier {
+        description: String::from_str(&env, description),
+        min_amount,
+        quantity,
+    };
+    let try_create = |tiers: Vec<RewardTier>| {
+        client.try_create_campaign(
+            &owner,
+            &1000,
+            &deadline,
+            &token_address,
+            &default_config(),
+            &default_metadata(&env),
+            &None,
+            &tiers,
+        )
+    };
+    let invalid = Err(Ok(CrowdfundingError::InvalidTiers));
+
+    assert_eq!(try_create(vec![&env, tier("", 100, 1)]), invalid);
+    assert_eq!(try_create(vec![&env, tier("Kaos", 0, 1)]), invalid);
+    assert_eq!(try_create(vec![&env, tier("Kaos", 100, 0)]), invalid);
+    let mut too_many = Vec::new(&env);
+    for _ in 0..(MAX_TIERS + 1) {
+        too_many.push_back(tier("Kaos", 100, 1));
+    }
+    assert_eq!(try_create(too_many), invalid);
+
+    assert_eq!(client.get_next_id(), 0);
+}
+
+
+// --- Funding Mode Tests ---
+
+// Helper: campaign dengan opsi tertentu (goal 1000, deadline +100)
+fn create_campaign_with_config(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    owner: &Address,
+    token: &Address,
+    config: &CampaignConfig,
+) -> u64 {
+    client.create_campaign(
+        owner,
+        &1000,
+        &(env.ledger().timestamp() + 100),
+        token,
+        config,
+        &default_metadata(env),
+        &None,
+        &Vec::new(env),
+    )
+}
+
+// Helper: campaign dengan mode pendanaan tertentu
+fn create_campaign_with_mode(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    owner: &Address,
+    token: &Address,
+    funding_mode: FundingMode,
+) -> u64 {
+    let config = CampaignConfig {
+        funding_mode,
+        ..default_config()
+    };
+    create_campaign_with_config(env, client, owner, token, &config)
+}
+
+#[test]
+fn test_keep_it_all_goal_missed_owner_withdraws() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000);
+
+    let campaign_id =
+        create_campaign_with_mode(&env, &client, &owner, &token_address, FundingMode::KeepItAll);
+    assert_eq!(client.get_campaign(&campaign_id).funding_mode, FundingMode::KeepItAll);
+    client.donate(&campaign_id, &donor, &300, &None);
+
+    jump(&env, 100);
+    assert!(!client.is_goal_reached(&campaign_id));
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Succeeded);
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor),
+        Err(Ok(CrowdfundingError::FundsKept))
+    );
+
+    assert_eq!(client.withdraw(&campaign_id), 300);
+    assert_eq!(token_client.balance(&owner), 300);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Claimed);
+}
+
+#[test]
+fn test_keep_it_all_goal_reached_and_no_donations() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &2_000);
+
+    let funded_id =
+        create_campaign_with_mode(&env, &client, &owner, &token_address, FundingMode::KeepItAll);
+    let empty_id =
+        create_campaign_with_mode(&env, &client, &owner, &token_address, FundingMode::KeepItAll);
+    client.donate(&funded_id, &donor, &1_500, &None);
+
+    jump(&env, 100);
+    assert_eq!(
+        client.try_refund(&funded_id, &donor),
+        Err(Ok(CrowdfundingError::GoalReached))
+    );
+    assert_eq!(client.withdraw(&funded_id), 1_500);
+
+    // Tanpa donasi tidak ada yang bisa ditarik
+    assert_eq!(client.get_status(&empty_id), CampaignStatus::Failed);
+    assert_eq!(
+        client.try_withdraw(&empty_id),
+        Err(Ok(CrowdfundingError::GoalNotReached))
+    );
+}
+
+#[test]
+fn test_keep_it_all_cancel_still_refunds() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000);
+
+    let campaign_id =
+        create_campaign_with_mode(&env, &client, &owner, &token_address, FundingMode::KeepItAll);
+    client.donate(&campaign_id, &donor, &300, &None);
+    client.cancel_campaign(&campaign_id);
+
+    assert_eq!(client.refund(&campaign_id, &donor), 300);
+}
+
+#[test]
+fn test_all_or_nothing_goal_missed_refunds() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000);
+
+    let campaign_id =
+        create_campaign_with_mode(&env, &client, &owner, &token_address, FundingMode::AllOrNothing);
+    assert_eq!(client.get_campaign(&campaign_id).funding_mode, FundingMode::AllOrNothing);
+    client.donate(&campaign_id, &donor, &300, &None);
+
+    jump(&env, 100);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Failed);
+    assert_eq!(
+        client.try_withdraw(&campaign_id),
+        Err(Ok(CrowdfundingError::GoalNotReached))
+    );
+    assert_eq!(client.refund(&campaign_id, &donor), 300);
+}
+
+
+// --- Donation Limit Tests ---
+
+#[test]
+fn test_min_donation_and_max_per_donor_reject() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &10_000);
+
+    let config = CampaignConfig {
+        min_donation: Some(50),
+        max_per_donor: Some(500),
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(
+        (campaign.min_donation, campaign.max_per_donor, campaign.hard_cap),
+        (Some(50), Some(500), None)
+    );
+
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &49, &None),
+        Err(Ok(CrowdfundingError::BelowMinDonation))
+    );
+    assert_eq!(client.donate(&campaign_id, &donor, &400, &None), 400);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &101, &None),
+        Err(Ok(CrowdfundingError::DonorLimitExceeded))
+    );
+    assert_eq!(client.donate(&campaign_id, &donor, &100, &None), 100);
+    assert_eq!(client.get_donation(&campaign_id, &donor), 500);
+    assert_eq!(token_client.balance(&donor), 9_500);
+}
+
+#[test]
+fn test_max_per_donor_partial_accept() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &10_000);
+
+    let config = CampaignConfig {
+        max_per_donor: Some(500),
+        over_limit: OverLimitPolicy::PartialAccept,
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+
+    client.donate(&campaign_id, &donor, &400, &None);
+    // Hanya 100 yang diterima & ditransfer
+    assert_eq!(client.donate(&campaign_id, &donor, &300, &None), 100);
+    assert_eq!(client.get_donation(&campaign_id, &donor), 500);
+    assert_eq!(token_client.balance(&donor), 9_500);
+    // Batas sudah penuh
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &1, &None),
+        Err(Ok(CrowdfundingError::DonorLimitExceeded))
+    );
+}
+
+#[test]
+fn test_hard_cap_reject_and_auto_close() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor1, &10_000);
+    fund_donor(&env, &token_client, &owner, &donor2, &10_000);
+
+    let config = CampaignConfig {
+        hard_cap: Some(1_500),
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+
+    client.donate(&campaign_id, &donor1, &1_000, &None);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor2, &600, &None),
+        Err(Ok(CrowdfundingError::HardCapExceeded))
+    );
+    client.donate(&campaign_id, &donor2, &500, &None);
+
+    // Hard cap tercapai: campaign langsung ditutup sebelum deadline
+    assert!(client.is_ended(&campaign_id));
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Succeeded);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor2, &1, &None),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+    assert_eq!(client.withdraw(&campaign_id), 1_500);
+}
+
+#[test]
+fn test_hard_cap_partial_accept() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &10_000);
+
+    let config = CampaignConfig {
+        hard_cap: Some(1_500),
+        over_limit: OverLimitPolicy::PartialAccept,
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+
+    client.donate(&campaign_id, &donor, &1_000, &None);
+    assert_eq!(client.donate(&campaign_id, &donor, &2_000, &None), 500);
+    assert_eq!(client.get_total_raised(&campaign_id), 1_500);
+    assert_eq!(token_client.balance(&donor), 8_500);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Succeeded);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &1, &None),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+}
+
+#[test]
+fn test_partial_accept_room_below_min_donation() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor1, &10_000);
+    fund_donor(&env, &token_client, &owner, &donor2, &10_000);
+
+    let config = CampaignConfig {
+        hard_cap: Some(1_500),
+        min_donation: Some(100),
+        max_per_donor: Some(1_000),
+        over_limit: OverLimitPolicy::PartialAccept,
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+
+    // Sisa batas per donatur (50) di bawah minimum: tidak boleh dipotong jadi 50
+    client.donate(&campaign_id, &donor1, &950, &None);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor1, &200, &None),
+        Err(Ok(CrowdfundingError::DonorLimitExceeded))
+    );
+
+    // Sisa hard cap (50) di bawah minimum
+    client.donate(&campaign_id, &donor2, &500, &None);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor2, &200, &None),
+        Err(Ok(CrowdfundingError::HardCapExceeded))
+    );
+    assert_eq!(client.get_total_raised(&campaign_id), 1_450);
+    assert_eq!(token_client.balance(&donor1), 9_050);
+    assert_eq!(token_client.balance(&donor2), 9_500);
+}
+
+#[test]
+fn test_create_campaign_invalid_limits() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    env.mock_all_auths();
+
+    let invalid_configs = [
+        // Hard cap di bawah goal (1000)
+        CampaignConfig {
+            hard_cap: Some(999),
+            ..default_config()
+        },
+        // Minimum donasi tidak positif
+        CampaignConfig {
+            min_donation: Some(0),
+            ..default_config()
+        },
+        // Batas per donatur di bawah minimum donasi
+        CampaignConfig {
+            min_donation: Some(100),
+            max_per_donor: Some(99),
+            ..default_config()
+        },
+        CampaignConfig {
+            max_per_donor: Some(0),
+            ..default_config()
+        },
+    ];
+    for config in invalid_configs.iter() {
+        assert_eq!(
+            client.try_create_campaign(
+                &owner,
+                &1000,
+                &(env.ledger().timestamp() + 100),
+                &token_address,
+                config,
+                &default_metadata(&env),
+                &None,
+                &Vec::new(&env),
+            ),
+            Err(Ok(CrowdfundingError::InvalidLimits))
+        );
+    }
+}
+
+
+// --- Start Time Tests ---
+
+#[test]
+fn test_campaign_pending_until_start_time() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+
+    let start_time = env.ledger().timestamp() + 50;
+    let config = CampaignConfig {
+        start_time: Some(start_time),
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+    assert_eq!(client.get_campaign(&campaign_id).start_time, Some(start_time));
+
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Pending);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &100, &None),
+        Err(Ok(CrowdfundingError::CampaignNotStarted))
+    );
+    assert_eq!(
+        client.try_refund(&campaign_id, &donor),
+        Err(Ok(CrowdfundingError::CampaignNotEnded))
+    );
+    assert_eq!(client.list_active_campaigns(&0, &10).campaigns.len(), 0);
+    // Metadata masih bisa diubah sebelum dibuka
+    client.update_metadata(&campaign_id, &default_metadata(&env));
+
+    // Satu detik sebelum dibuka
+    jump(&env, 49);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Pending);
+
+    // Tepat di start_time donasi dibuka
+    jump(&env, 1);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Active);
+    client.donate(&campaign_id, &donor, &100, &None);
+    assert_eq!(client.list_active_campaigns(&0, &10).campaigns.len(), 1);
+}
+
+#[test]
+fn test_pending_campaign_can_be_cancelled() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    env.mock_all_auths();
+
+    let config = CampaignConfig {
+        start_time: Some(env.ledger().timestamp() + 50),
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+
+    client.cancel_campaign(&campaign_id);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Cancelled);
+}
+
+#[test]
+fn test_create_campaign_start_time_not_before_deadline() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    env.mock_all_auths();
+
+    let deadline = env.ledger().timestamp() + 100;
+    for start_time in [deadline, deadline + 1] {
+        let config = CampaignConfig {
+            start_time: Some(start_time),
+            ..default_config()
+        };
+        assert_eq!(
+            client.try_create_campaign(
+                &owner,
+                &1000,
+                &deadline,
+                &token_address,
+                &config,
+                &default_metadata(&env),
+                &None,
+                &Vec::new(&env),
+            ),
+            Err(Ok(CrowdfundingError::InvalidStartTime))
+        );
+    }
+
+    // start_time di masa lalu: langsung Active
+    let config = CampaignConfig {
+        start_time: Some(env.ledger().timestamp() - 10),
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Active);
+}
+
+
+// --- Deadline Extension Tests ---
+
+#[test]
+fn test_extend_deadline_within_limit() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    env.mock_all_auths();
+
+    let config = CampaignConfig {
+        max_extension: 100,
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+    let deadline = client.get_deadline(&campaign_id);
+
+    client.extend_deadline(&campaign_id, &(deadline + 60));
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "deadline_extended"), campaign_id).into_val(&env),
+                Map::<Symbol, Val>::from_array(
+                    &env,
+                    [
+                        (Symbol::new(&env, "new_deadline"), (deadline + 60).into_val(&env)),
+                        (Symbol::new(&env, "old_deadline"), deadline.into_val(&env)),
+                    ],
+                )
+                .into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_deadline(&campaign_id), deadline + 60);
+    assert_eq!(client.get_campaign(&campaign_id).extended, 60);
+
+    // Sisa jatah perpanjangan 40 detik
+    assert_eq!(
+        client.try_extend_deadline(&campaign_id, &(deadline + 101)),
+        Err(Ok(CrowdfundingError::InvalidExtension))
+    );
+    assert_eq!(
+        client.try_extend_deadline(&campaign_id, &(deadline + 60)),
+        Err(Ok(CrowdfundingError::InvalidExtension))
+    );
+    client.extend_deadline(&campaign_id, &(deadline + 100));
+
+    // Campaign tetap Active melewati deadline lama
+    jump(&env, 150);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Active);
+    jump(&env, 50);
+    assert_eq!(client.get_status(&campaign_id), CampaignStatus::Failed);
+    assert_eq!(
+        client.try_extend_deadline(&campaign_id, &(deadline + 100)),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+}
+
+#[test]
+fn test_extend_deadline_disabled_by_default() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    assert_eq!(
+        client.try_extend_deadline(&campaign_id, &(deadline + 1)),
+        Err(Ok(CrowdfundingError::InvalidExtension))
+    );
+}
+
+#[test]
+fn test_extend_deadline_blocked_by_objections() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    let donor3 = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor1, &1_000);
+    fund_donor(&env, &token_client, &owner, &donor2, &1_000);
+    fund_donor(&env, &token_client, &owner, &donor3, &1_000);
+
+    // Diblokir jika >= 50% donatur keberatan
+    let config = CampaignConfig {
+        max_extension: 1_000,
+        objection_bps: Some(5_000),
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+    let deadline = client.get_deadline(&campaign_id);
+    client.donate(&campaign_id, &donor1, &900, &None);
+    client.donate(&campaign_id, &donor2, &50, &None);
+    client.donate(&campaign_id, &donor3, &50, &None);
+    // Donasi kedua tidak menambah jumlah donatur
+    client.donate(&campaign_id, &donor3, &10, &None);
+    assert_eq!(client.get_campaign(&campaign_id).donors, 3);
+
+    // 1 dari 3 donatur keberatan (walau 90% dana): masih boleh
+    client.object_extension(&campaign_id, &donor1);
+    assert_eq!(client.get_campaign(&campaign_id).objections, 1);
+    client.extend_deadline(&campaign_id, &(deadline + 10));
+
+    assert_eq!(
+        client.try_object_extension(&campaign_id, &donor1),
+        Err(Ok(CrowdfundingError::AlreadyObjected))
+    );
+    assert_eq!(
+        client.try_object_extension(&campaign_id, &stranger),
+        Err(Ok(CrowdfundingError::NotDonor))
+    );
+
+    // 2 dari 3 donatur keberatan (hanya 10% dana): diblokir
+    client.object_extension(&campaign_id, &donor2);
+    assert_eq!(
+        client.try_extend_deadline(&campaign_id, &(deadline + 20)),
+        Err(Ok(CrowdfundingError::ExtensionObjected))
+    );
+}
+
+#[test]
+fn test_object_extension_status_errors() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+    let config = CampaignConfig {
+        max_extension: 1_000,
+        objection_bps: Some(5_000),
+        ..default_config()
+    };
+
+    // Campaign yang belum dimulai
+    let pending_config = CampaignConfig {
+        start_time: Some(env.ledger().timestamp() + 50),
+        ..config.clone()
+    };
+    let pending_id =
+        create_campaign_with_config(&env, &client, &owner, &token_address, &pending_config);
+    assert_eq!(
+        client.try_object_extension(&pending_id, &donor),
+        Err(Ok(CrowdfundingError::CampaignNotStarted))
+    );
+
+    // Campaign yang dibatalkan
+    let cancelled_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+    client.donate(&cancelled_id, &donor, &100, &None);
+    client.cancel_campaign(&cancelled_id);
+    assert_eq!(
+        client.try_object_extension(&cancelled_id, &donor),
+        Err(Ok(CrowdfundingError::CampaignCancelled))
+    );
+
+    // Campaign yang sudah lewat deadline
+    let ended_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+    client.donate(&ended_id, &donor, &100, &None);
+    jump(&env, 100);
+    assert_eq!(
+        client.try_object_extension(&ended_id, &donor),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+}
+
+#[test]
+fn test_extend_deadline_respects_milestones_and_status() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    let config = CampaignConfig {
+        max_extension: 1_000,
+        ..default_config()
+    };
+    // Milestone pertama jatuh tempo di deadline + 100
+    let campaign_id = client.create_campaign(
+        &owner,
+        &1000,
+        &deadline,
+        &token_address,
+        &config,
+        &default_metadata(&env),
+        &Some(milestone_plan(&env, &owner, deadline)),
+        &Vec::new(&env),
+    );
+    assert_eq!(
+        client.try_extend_deadline(&campaign_id, &(deadline + 100)),
+        Err(Ok(CrowdfundingError::InvalidExtension))
+    );
+    client.extend_deadline(&campaign_id, &(deadline + 99));
+
+    client.cancel_campaign(&campaign_id);
+    assert_eq!(
+        client.try_extend_deadline(&campaign_id, &(deadline + 200)),
+        Err(Ok(CrowdfundingError::CampaignCancelled))
+    );
+
+    let invalid = CampaignConfig {
+        objection_bps: Some(0),
+        ..default_config()
+    };
+    assert_eq!(
+        client.try_create_campaign(
+            &owner,
+            &1000,
+            &deadline,
+            &token_address,
+            &invalid,
+            &default_metadata(&env),
+            &None,
+            &Vec::new(&env),
+        ),
+        Err(Ok(CrowdfundingError::InvalidExtension))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_extend_deadline_requires_owner_auth() {
+    let (env, client, _token_client, token_address, owner) = setup_test();
+    env.mock_all_auths();
+
+    let config = CampaignConfig {
+        max_extension: 100,
+        ..default_config()
+    };
+    let campaign_id = create_campaign_with_config(&env, &client, &owner, &token_address, &config);
+    let deadline = client.get_deadline(&campaign_id);
+
+    // Hapus semua mock auth: owner tidak menandatangani
+    env.set_auths(&[]);
+    client.extend_deadline(&campaign_id, &(deadline + 10));
+}
+
+
+// --- Pledge Withdrawal Tests ---
+
+// Helper: campaign (goal 1000, deadline +100) yang mengizinkan penarikan donasi
+fn create_pledge_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    owner: &Address,
+    token: &Address,
+    lock_on_goal: bool,
+    lock_window: u64,
+) -> u64 {
+    let config = CampaignConfig {
+        pledge_withdrawal: true,
+        lock_on_goal,
+        lock_window,
+        ..default_config()
+    };
+    create_campaign_with_config(env, client, owner, token, &config)
+}
+
+#[test]
+fn test_withdraw_pledge() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+    let campaign_id = create_pledge_campaign(&env, &client, &owner, &token_address, false, 0);
+
+    client.donate(&campaign_id, &donor, &600, &None);
+    assert_eq!(client.withdraw_pledge(&campaign_id, &donor, &200), 400);
+    assert_eq!(
+        env.events().all().last().unwrap().1,
+        (Symbol::new(&env, "pledge_withdrawn"), campaign_id, donor.clone()).into_val(&env)
+    );
+    assert_eq!(client.get_donation(&campaign_id, &donor), 400);
+    assert_eq!(client.get_total_raised(&campaign_id), 400);
+    assert_eq!(token_client.balance(&donor), 600);
+
+    assert_eq!(
+        client.try_withdraw_pledge(&campaign_id, &donor, &401),
+        Err(Ok(CrowdfundingError::AmountExceedsDonation))
+    );
+    assert_eq!(client.withdraw_pledge(&campaign_id, &donor, &400), 0);
+    assert_eq!(token_client.balance(&donor), 1_000);
+    assert_eq!(
+        client.try_withdraw_pledge(&campaign_id, &donor, &1),
+        Err(Ok(CrowdfundingError::NothingToRefund))
+    );
+
+    // Setelah deadline penarikan tidak bisa lagi, gunakan refund
+    client.donate(&campaign_id, &donor, &100, &None);
+    jump(&env, 100);
+    assert_eq!(
+        client.try_withdraw_pledge(&campaign_id, &donor, &100),
+        Err(Ok(CrowdfundingError::CampaignEnded))
+    );
+    assert_eq!(client.refund(&campaign_id, &donor), 100);
+}
+
+#[test]
+fn test_withdraw_pledge_disabled_by_default() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &600, &None);
+    assert_eq!(
+        client.try_withdraw_pledge(&campaign_id, &donor, &100),
+        Err(Ok(CrowdfundingError::PledgeWithdrawalDisabled))
+    );
+}
+
+#[test]
+fn test_withdraw_pledge_locks() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &2_000);
+
+    // Dikunci setelah goal tercapai
+    let goal_locked = create_pledge_campaign(&env, &client, &owner, &token_address, true, 0);
+    client.donate(&goal_locked, &donor, &1_000, &None);
+    assert_eq!(
+        client.try_withdraw_pledge(&goal_locked, &donor, &100),
+        Err(Ok(CrowdfundingError::PledgeLocked))
+    );
+
+    // Dikunci 20 detik terakhir sebelum deadline
+    let window_locked = create_pledge_campaign(&env, &client, &owner, &token_address, false, 20);
+    client.donate(&window_locked, &donor, &500, &None);
+    jump(&env, 79);
+    assert_eq!(client.withdraw_pledge(&window_locked, &donor, &100), 400);
+    jump(&env, 1);
+    assert_eq!(
+        client.try_withdraw_pledge(&window_locked, &donor, &100),
+        Err(Ok(CrowdfundingError::PledgeLocked))
+    );
+}
+
+#[test]
+fn test_withdraw_pledge_updates_tier_and_objections() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &1_000);
+
+    let config = CampaignConfig {
+        pledge_withdrawal: true,
+        max_extension: 100,
+        objection_bps: Some(5_000),
+        ..default_config()
+    };
+    let campaign_id = client.create_campaign(
+        &owner,
+        &10_000,
+        &(env.ledger().timestamp() + 100),
+        &token_address,
+        &config,
+        &default_metadata(&env),
+        &None,
+        &vec![
+            &env,
+            RewardTier {
+                description: String::from_str(&env, "Kaos"),
+                min_amount: 500,
+                quantity: 1,
+            },
+        ],
+    );
+
+    client.donate(&campaign_id, &donor, &800, &Some(0));
+    client.object_extension(&campaign_id, &donor);
+
+    // Masih di atas minimum tier
+    client.withdraw_pledge(&campaign_id, &donor, &300);
+    assert_eq!(client.get_donor_tier(&campaign_id, &donor), Some(0));
+    assert_eq!(client.get_campaign(&campaign_id).objections, 1);
+
+    // Di bawah minimum: slot tier dilepas
+    client.withdraw_pledge(&campaign_id, &donor, &100);
+    assert_eq!(client.get_donor_tier(&campaign_id, &donor), None);
+    assert_eq!(client.get_tiers(&campaign_id).get(0).unwrap().remaining, 1);
+    assert_eq!(client.get_campaign(&campaign_id).objections, 1);
+
+    // Tarik semua: donatur & keberatannya tidak dihitung lagi
+    client.withdraw_pledge(&campaign_id, &donor, &400);
+    let campaign = client.get_campaign(&campaign_id);
+    assert_eq!(campaign.donors, 0);
+    assert_eq!(campaign.objections, 0);
+}
+
+
+// --- Platform Fee Tests ---
+
+// Helper: (admin, treasury) platform yang diset constructor
+fn platform_roles(client: &CrowdfundingContractClient) -> (Address, Address) {
+    (client.get_admin(), client.get_fee_config().treasury)
+}
+
+#[test]
+fn test_withdraw_charges_platform_fee() {
+    let (env, client, token_client, token_address, token_admin) = setup_test_with_fee(500);
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+    let (_admin, treasury) = platform_roles(&client);
+    fund_donor(&env, &token_client, &token_admin, &donor, &2_000);
+
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    assert_eq!(client.get_campaign(&campaign_id).fee_bps, 500);
+    client.donate(&campaign_id, &donor, &2_000, &None);
+
+    jump(&env, 100);
+    assert_eq!(client.withdraw(&campaign_id), 1_900);
+    assert_eq!(token_client.balance(&owner), 1_900);
+    assert_eq!(token_client.balance(&treasury), 100);
+    assert_eq!(client.get_campaign(&campaign_id).fee_paid, 100);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_milestone_release_charges_platform_fee() {
+    let (env, client, token_client, token_address, token_admin) = setup_test_with_fee(500);
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+    let (_admin, treasury) = platform_roles(&client);
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000);
+
+    let campaign_id = client.create_campaign(
+        &owner,
+        &1000,
+        &deadline,
+        &token_address,
+        &default_config(),
+        &default_metadata(&env),
+        &Some(milestone_plan(&env, &owner, deadline)),
+        &Vec::new(&env),
+    );
+    client.donate(&campaign_id, &donor, &1_000, &None);
+
+    jump(&env, 100);
+    assert_eq!(client.approve_milestone(&campaign_id), 285); // 300 - 5%
+    assert_eq!(token_client.balance(&treasury), 15);
+
+    // Milestone kedua ditolak: refund pro rata dari sisa escrow tanpa fee
+    client.reject_milestone(&campaign_id);
+    assert_eq!(client.refund(&campaign_id, &donor), 700);
+    assert_eq!(token_client.balance(&treasury), 15);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_refunds_never_charged_fee() {
+    let (env, client, token_client, token_address, token_admin) = setup_test_with_fee(500);
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+    let (_admin, treasury) = platform_roles(&client);
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000);
+
+    // Campaign gagal
+    let failed_id = create_campaign(&client, &token_admin, &1000, &deadline, &token_address);
+    client.donate(&failed_id, &donor, &400, &None);
+    // Campaign dibatalkan
+    let cancelled_id = create_campaign(&client, &token_admin, &1000, &deadline, &token_address);
+    client.donate(&cancelled_id, &donor, &600, &None);
+    client.cancel_campaign(&cancelled_id);
+
+    assert_eq!(client.refund(&cancelled_id, &donor), 600);
+    jump(&env, 100);
+    assert_eq!(client.refund(&failed_id, &donor), 400);
+
+    assert_eq!(token_client.balance(&donor), 1_000);
+    assert_eq!(token_client.balance(&treasury), 0);
+    assert_eq!(client.get_campaign(&failed_id).fee_paid, 0);
+}
+
+#[test]
+fn test_set_fee_timelock() {
+    let (env, client, _token_client, token_address, owner) = setup_test_with_fee(500);
+    env.mock_all_auths();
+    let (_admin, treasury) = platform_roles(&client);
+
+    let effective_at = client.set_fee(&800);
+    assert_eq!(effective_at, env.ledger().timestamp() + FEE_TIMELOCK);
+    assert_eq!(
+        client.get_pending_fee(),
+        Some(PendingFee {
+            fee_bps: 800,
+            effective_at,
+        })
+    );
+
+    // Sebelum timelock lewat fee lama masih berlaku
+    jump(&env, FEE_TIMELOCK - 1);
+    assert_eq!(client.get_fee_config().fee_bps, 500);
+    let deadline = env.ledger().timestamp() + 100;
+    let before = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+
+    jump(&env, 1);
+    assert_eq!(
+        client.get_fee_config(),
+        FeeConfig {
+            treasury,
+            fee_bps: 800,
+            max_fee_bps: 1_000,
+        }
+    );
+    assert_eq!(client.get_pending_fee(), None);
+    let after = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    // Perubahan yang sudah berlaku dilipat ke FEE_CFG
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&FEE_NEXT));
+    });
+
+    // Fee tercatat per campaign saat dibuat
+    assert_eq!(client.get_campaign(&before).fee_bps, 500);
+    assert_eq!(client.get_campaign(&after).fee_bps, 800);
+
+    assert_eq!(client.try_set_fee(&1_001), Err(Ok(CrowdfundingError::InvalidFee)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #46)")] // InvalidFee
+fn test_constructor_fee_above_max() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.register(CrowdfundingContract, (&admin, &admin, 501u32, 500u32));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #46)")] // InvalidFee
+fn test_constructor_max_fee_above_bps() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.register(CrowdfundingContract, (&admin, &admin, 0u32, 10_001u32));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #46)")] // InvalidFee
+fn test_constructor_max_fee_full_bps() {
+    // Fee 100% membuat payout owner 0 dan dana terkunci
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    env.register(CrowdfundingContract, (&admin, &admin, 10_000u32, 10_000u32));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_set_fee_requires_admin_auth() {
+    let (env, client, _token_client, _token_address, _owner) = setup_test_with_fee(500);
+    env.mock_all_auths();
+
+    // Hapus semua mock auth: admin tidak menandatangani
+    env.set_auths(&[]);
+    client.set_fee(&100);
+}
+
+// --- Admin & Pause Tests ---
+
+#[test]
+fn test_instance_ttl_extended_on_donate() {
+    let (env, client, token_client, token_address, owner) = setup_test();
+    let donor = Address::generate(&env);
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &owner, &donor, &100);
+    let campaign_id = create_campaign(
+        &client,
+        &owner,
+        &1000,
+        &(env.ledger().timestamp() + 100),
+        &token_address,
+    );
+
+    // Maju sampai sisa TTL instance di bawah threshold
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 2 * DAY_IN_LEDGERS);
+    client.donate(&campaign_id, &donor, &100, &None);
+
+    // Admin, fee & NEXT_ID di instance storage ikut diperpanjang
+    env.as_contract(&client.address, || {
+        assert_eq!(env.storage().instance().get_ttl(), INSTANCE_BUMP_AMOUNT);
+    });
+}
+
+#[test]
+fn test_pause_blocks_create_and_donate() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+    let (admin, _treasury) = platform_roles(&client);
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000);
+
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &400, &None);
+
+    client.pause();
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "paused"), admin.clone()).into_val(&env),
+                Map::<Symbol, Val>::new(&env).into_val(&env),
+            ),
+        ]
+    );
+    assert!(client.is_paused());
+
+    assert_eq!(
+        client.try_create_campaign(
+            &owner,
+            &1000,
+            &deadline,
+            &token_address,
+            &default_config(),
+            &default_metadata(&env),
+            &None,
+            &Vec::new(&env),
+        ),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &100, &None),
+        Err(Ok(CrowdfundingError::ContractPaused))
+    );
+
+    client.unpause();
+    assert!(!client.is_paused());
+    client.donate(&campaign_id, &donor, &100, &None);
+    assert_eq!(client.get_total_raised(&campaign_id), 500);
+}
+
+#[test]
+fn test_pause_never_blocks_refund() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+    fund_donor(&env, &token_client, &token_admin, &donor, &1_000);
+
+    let campaign_id = create_campaign(&client, &token_admin, &1000, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &400, &None);
+
+    client.pause();
+    jump(&env, 100);
+    assert_eq!(client.refund(&campaign_id, &donor), 400);
+    assert_eq!(token_client.balance(&donor), 1_000);
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let (env, client, _token_client, _token_address, _owner) = setup_test();
+    let new_admin = Address::generate(&env);
+    env.mock_all_auths();
+    let (admin, _treasury) = platform_roles(&client);
+
+    client.transfer_admin(&new_admin);
+    // Admin lama tetap berlaku sampai diterima
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(
+        vec![&env, env.events().all().last().unwrap()],
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "admin_changed"), admin.clone(), new_admin.clone())
+                    .into_val(&env),
+                Map::<Symbol, Val>::new(&env).into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.try_accept_admin(), Err(Ok(CrowdfundingError::NoPendingAdmin)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_pause_requires_admin_auth() {
+    let (env, client, _token_client, _token_address, _owner) = setup_test();
+    env.mock_all_auths();
+
+    env.set_auths(&[]);
+    client.pause();
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_transfer_admin_requires_admin_auth() {
+    let (env, client, _token_client, _token_address, _owner) = setup_test();
+    let new_admin = Address::generate(&env);
+    env.mock_all_auths();
+
+    env.set_auths(&[]);
+    client.transfer_admin(&new_admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_accept_admin_requires_new_admin_auth() {
+    let (env, client, _token_client, _token_address, _owner) = setup_test();
+    let new_admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.transfer_admin(&new_admin);
+
+    // Calon admin tidak menandatangani
+    env.set_auths(&[]);
+    client.accept_admin();
+}
+
+// --- Upgrade & Migration Tests ---
+
+// Versi baru kontrak untuk test upgrade: build WASM crowdfunding dari repo
+// ini (lihat test_wasms/README.md)
+mod upgraded {
+    soroban_sdk::contractimport!(file = "../../test_wasms/crowdfunding.wasm");
+}
+
+#[test]
+fn test_upgrade_and_migrate_preserves_campaigns() {
+    let (env, client, token_client, token_address, token_admin) = setup_test_with_fee(250);
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let legacy_donor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+    token_client.transfer(&token_admin, &donor, &1_000);
+    let (admin, treasury) = platform_roles(&client);
+
+    // Campaign yang dibuat kode lama sebelum upgrade
+    let campaign_id = create_campaign(&client, &owner, &1000, &deadline, &token_address);
+    client.donate(&campaign_id, &donor, &300, &None);
+
+    // Plus satu campaign yang masih di map CAMPAIGNS (schema 1)
+    env.as_contract(&client.address, || {
+        let mut donations = Map::new(&env);
+        donations.set(legacy_donor.clone(), 500i128);
+        let mut campaigns: Map<u64, LegacyCampaign> = Map::new(&env);
+        campaigns.set(
+            1,
+            LegacyCampaign {
+                owner: owner.clone(),
+                goal: 2000,
+                deadline,
+                token: token_address.clone(),
+                raised: 500,
+                donations,
+            },
+        );
+        env.storage().instance().set(&CAMPAIGNS, &campaigns);
+        env.storage().instance().set(&NEXT_ID, &2u64);
+        env.storage().instance().remove(&VERSION);
+    });
+    token_client.transfer(&token_admin, &client.address, &500);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(upgraded::WASM);
+    client.upgrade(&wasm_hash);
+
+    // Kode baru yang menjawab, lalu migrasi layout lama
+    let new_client = upgraded::Client::new(&env, &client.address);
+    assert_eq!(new_client.get_schema_version(), 1);
+    assert_eq!(new_client.migrate(&10), 0);
+    assert_eq!(new_client.get_schema_version(), SCHEMA_VERSION);
+
+    // Konfigurasi platform tetap utuh
+    assert_eq!(new_client.get_admin(), admin);
+    let fee_config = new_client.get_fee_config();
+    assert_eq!(fee_config.treasury, treasury);
+    assert_eq!(fee_config.fee_bps, 250);
+
+    // Campaign lama & campaign hasil migrasi bisa dibaca kode baru
+    let campaign = new_client.get_campaign(&campaign_id);
+    assert_eq!(campaign.owner, owner);
+    assert_eq!(campaign.raised, 300);
+    assert_eq!(campaign.donors, 1);
+    assert_eq!(new_client.get_donation(&campaign_id, &donor), 300);
+    let migrated = new_client.get_campaign(&1);
+    assert_eq!(migrated.goal, 2000);
+    assert_eq!(migrated.raised, 500);
+    assert_eq!(migrated.donors, 1);
+    assert_eq!(new_client.get_donation(&1, &legacy_donor), 500);
+
+    // Donasi & refund lewat kode baru tetap jalan
+    new_client.donate(&campaign_id, &donor, &200, &None);
+    assert_eq!(new_client.get_campaign(&campaign_id).raised, 500);
+    jump(&env, 100);
+    assert_eq!(new_client.refund(&1, &legacy_donor), 500);
+    assert_eq!(token_client.balance(&legacy_donor), 500);
+    assert_eq!(new_client.refund(&campaign_id, &donor), 500);
+    assert_eq!(token_client.balance(&donor), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_upgrade_requires_admin_auth() {
+    let (env, client, _token_client, _token_address, _owner) = setup_test();
+    env.mock_all_auths();
+    let wasm_hash = env.deployer().upload_contract_wasm(upgraded::WASM);
+
+    env.set_auths(&[]);
+    client.upgrade(&wasm_hash);
+}
+
+#[test]
+fn test_migrate_legacy_campaigns() {
+    let (env, client, token_client, token_address, token_admin) = setup_test();
+    let owner = Address::generate(&env);
+    let donor = Address::generate(&env);
+    let refunded = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    env.mock_all_auths();
+
+    // Simulasikan layout versi 1: semua campaign di satu map CAMPAIGNS
+    env.as_contract(&client.address, || {
+        let mut campaigns: Map<u64, LegacyCampaign> = Map::new(&env);
+        for id in 0..3u64 {
+            let mut donations = Map::new(&env);
+            donations.set(donor.clone(), 400i128);
+            donations.set(refunded.clone(), 0i128);
+            campaigns.set(
+                id,
+                LegacyCampaign {
+                    owner: owner.clone(),
+                    goal: 1000,
+                    deadline,
+                    token: token_address.clone(),
+                    raised: 400,
+                    donations,
+                },
+            );
+        }
+        env.storage().instance().set(&CAMPAIGNS, &campaigns);
+        env.storage().instance().set(&NEXT_ID, &3u64);
+        // Kontrak versi 1 belum menyimpan versi
+        env.storage().instance().remove(&VERSION);
+    });
+    // Dana donasi lama ada di kontrak
+    token_client.transfer(&token_admin, &client.address, &1_200);
+    assert_eq!(client.get_schema_version(), 1);
+
+    // Migrasi bertahap
+    assert_eq!(client.migrate(&2), 1);
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.migrate(&10), 0);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&CAMPAIGNS));
+    });
+
+    let campaign = client.get_campaign(&2);
+    assert_eq!(campaign.raised, 400);
+    assert_eq!(campaign.funding_mode, FundingMode::AllOrNothing);
+    // Donatur yang sudah refund (0) tidak dihitung
+    assert_eq!(campaign.donors, 1);
+    assert_eq!(client.get_donation(&2, &donor), 400);
+    assert_eq!(client.get_donation(&2, &refunded), 0);
+    assert_eq!(
+        summary_ids(&env, &client.list_campaigns_by_owner(&owner, &0, &10)),
+        vec![&env, 0u64, 1, 2]
+    );
+    // Campaign baru melanjutkan ID lama
+    assert_eq!(create_campaign(&client, &owner, &1000, &deadline, &token_address), 3);
+
+    // Campaign yang dipindah tetap bisa di-refund
+    jump(&env, 100);
+    assert_eq!(client.refund(&0, &donor), 400);
+    assert_eq!(token_client.balance(&donor), 400);
+
+    // Tidak ada yang perlu dipindah lagi
+    assert_eq!(client.migrate(&10), 0);
+}
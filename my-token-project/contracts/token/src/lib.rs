@@ -1,122 +1,638 @@
 #![no_std]
-// 'contracttype' dihapus dari baris ini
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, symbol_short, Map}; 
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error,
+    symbol_short, token::TokenInterface, Address, BytesN, Env, Map, MuxedAddress, String, Symbol,
+    TryFromVal, Val,
+};
+
+// --- Error Codes ---
+// Kode error dibaca oleh binding TS & kontrak lain, jangan ubah nomor yang sudah ada
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    // (Tidak dipakai lagi sejak constructor) Token sudah diinisialisasi
+    AlreadyInitialized = 1,
+    // Token belum diinisialisasi
+    NotInitialized = 2,
+    // Total supply awal harus lebih dari 0
+    InvalidSupply = 3,
+    // Amount transfer/burn harus lebih dari 0
+    AmountNotPositive = 4,
+    // Amount approve tidak boleh negatif
+    NegativeAmount = 5,
+    // Saldo tidak cukup
+    InsufficientBalance = 6,
+    // Allowance tidak cukup
+    InsufficientAllowance = 7,
+    // Expiration ledger allowance sudah lewat
+    InvalidExpirationLedger = 8,
+    // Fungsi sudah tidak dipakai
+    Deprecated = 9,
+    // Mint melebihi max supply
+    MaxSupplyExceeded = 10,
+    // Max supply tidak valid (di bawah total supply / lebih besar dari cap lama)
+    InvalidMaxSupply = 11,
+    // Tidak ada transfer admin yang menunggu
+    NoPendingAdmin = 12,
+    // Akun dibekukan / belum diotorisasi admin
+    NotAuthorized = 13,
+    // Decimals melebihi MAX_DECIMALS
+    InvalidDecimals = 14,
+}
 
 // --- Storage Keys ---
-// Kunci untuk saldo per alamat (Address -> i128)
+// (Legacy) Map<Address, i128> di instance storage, diganti DataKey::Balance.
+// Hanya dibaca untuk migrasi saldo lama.
 const BALANCE: Symbol = symbol_short!("BALANCE"); 
 // Kunci untuk metadata
 const NAME: Symbol = symbol_short!("NAME");
 const SYMBOL: Symbol = symbol_short!("SYMBOL");
 const TOTAL: Symbol = symbol_short!("TOTAL");
 const DECIMAL: Symbol = symbol_short!("DECIMAL");
+// Batas atas decimals (cukup untuk mirror aset bridged 18 desimal)
+const MAX_DECIMALS: u32 = 18;
+// Kunci untuk admin & supply
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const PEND_ADM: Symbol = symbol_short!("PEND_ADM"); // Calon admin, menunggu accept_admin
+const MAX_SUP: Symbol = symbol_short!("MAX_SUP"); // Opsional, tidak ada = tanpa batas
+// Mode "auth required": holder baru harus diotorisasi admin dulu (bool)
+const AUTH_REQ: Symbol = symbol_short!("AUTH_REQ");
+// Versi layout storage (u32)
+const VERSION: Symbol = symbol_short!("VERSION");
+
+// --- Schema Version ---
+// 1: saldo dalam map BALANCE di instance storage
+// 2: saldo per alamat (DataKey::Balance)
+const SCHEMA_VERSION: u32 = 2;
+
+// Kunci storage yang membawa parameter (tidak bisa pakai Symbol)
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    // Allowance `spender` atas saldo `from` (temporary storage)
+    Allowance(AllowanceDataKey),
+    // Saldo per alamat (persistent storage)
+    Balance(Address),
+    // Status otorisasi yang diset admin (persistent storage).
+    // Tidak ada entry = ikut mode auth required.
+    Authorized(Address),
+}
+
+// --- TTL ---
+// Ledger ditutup kira-kira tiap 5 detik
+const DAY_IN_LEDGERS: u32 = 17280;
+// Entry saldo diperpanjang sampai ~30 hari setiap kali dibaca/ditulis,
+// tapi hanya jika sisa TTL-nya kurang dari ~29 hari.
+const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+// Instance storage (metadata & konfigurasi admin) juga ~30 hari
+const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+// Nilai allowance + ledger kapan allowance kadaluarsa
+#[contracttype]
+#[derive(Clone)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+// --- Events (SEP-41) ---
+
+// topics ["transfer", from, to], data amount
+#[contractevent(data_format = "single-value")]
+pub struct Transfer {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+// topics ["transfer", from, to], data { to_muxed_id, amount }
+// Dipakai jika penerima adalah muxed address
+#[contractevent(topics = ["transfer"])]
+pub struct TransferMuxed {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub to: Address,
+    pub to_muxed_id: u64,
+    pub amount: i128,
+}
+
+// topics ["approve", from, spender], data [amount, expiration_ledger]
+#[contractevent(data_format = "vec")]
+pub struct Approve {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+// topics ["mint", to], data amount
+#[contractevent(data_format = "single-value")]
+pub struct Mint {
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+// topics ["burn", from], data amount
+#[contractevent(data_format = "single-value")]
+pub struct Burn {
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+}
+
+// topics ["set_admin", admin], data new_admin
+#[contractevent(data_format = "single-value")]
+pub struct SetAdmin {
+    #[topic]
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+// topics ["set_authorized", id], data authorize
+#[contractevent(data_format = "single-value")]
+pub struct SetAuthorized {
+    #[topic]
+    pub id: Address,
+    pub authorize: bool,
+}
+
+// topics ["clawback", from], data amount
+#[contractevent(data_format = "single-value")]
+pub struct Clawback {
+    #[topic]
+    pub from: Address,
+    pub amount: i128,
+}
 
 #[contract]
 pub struct TokenContract;
 
 // Helper untuk membaca saldo
 fn get_balance(env: &Env, addr: &Address) -> i128 {
-    let balances: Map<Address, i128> = env.storage().instance().get(&BALANCE).unwrap_or(Map::new(env));
-    balances.get(addr.clone()).unwrap_or(0)
+    let key = DataKey::Balance(addr.clone());
+    if let Some(balance) = env.storage().persistent().get::<_, i128>(&key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        return balance;
+    }
+    // Belum dimigrasi: fallback ke map lama
+    read_legacy_balances(env)
+        .and_then(|balances| balances.get(addr.clone()))
+        .unwrap_or(0)
+}
+
+// Helper untuk memperpanjang TTL instance storage (metadata, admin, dll).
+// Dipanggil di setiap entrypoint yang mengubah state.
+fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 }
 
 // Helper untuk menulis saldo
 fn set_balance(env: &Env, addr: &Address, amount: &i128) {
-    let mut balances: Map<Address, i128> = env.storage().instance().get(&BALANCE).unwrap_or(Map::new(env));
-    balances.set(addr.clone(), amount.clone());
-    env.storage().instance().set(&BALANCE, &balances);
-    
-    // (Catatan: Kontrak token standar juga harus mem-publish event di sini)
+    write_balance_entry(env, addr, amount);
+
+    // Saldo sudah pindah ke entry sendiri, buang dari map lama
+    remove_legacy_balance(env, addr);
+}
+
+// Helper untuk menulis entry saldo persistent per alamat
+fn write_balance_entry(env: &Env, addr: &Address, amount: &i128) {
+    let key = DataKey::Balance(addr.clone());
+    env.storage().persistent().set(&key, amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// (Legacy) Helper untuk membaca map saldo lama, None jika sudah tidak ada
+fn read_legacy_balances(env: &Env) -> Option<Map<Address, i128>> {
+    env.storage().instance().get(&BALANCE)
+}
+
+// (Legacy) Helper untuk menghapus satu alamat dari map saldo lama.
+// Map dihapus seluruhnya begitu kosong.
+fn remove_legacy_balance(env: &Env, addr: &Address) {
+    let Some(mut balances) = read_legacy_balances(env) else {
+        return;
+    };
+    if !balances.contains_key(addr.clone()) {
+        return;
+    }
+    balances.remove(addr.clone());
+    if balances.is_empty() {
+        env.storage().instance().remove(&BALANCE);
+        env.storage().instance().set(&VERSION, &SCHEMA_VERSION);
+    } else {
+        env.storage().instance().set(&BALANCE, &balances);
+    }
+}
+
+// Helper: apakah alamat sudah pernah memegang token (punya entry saldo,
+// termasuk yang sudah habis, atau masih di map saldo lama)
+fn is_holder(env: &Env, addr: &Address) -> bool {
+    env.storage().persistent().has(&DataKey::Balance(addr.clone()))
+        || read_legacy_balances(env).is_some_and(|balances| balances.contains_key(addr.clone()))
+}
+
+// Helper untuk membaca status otorisasi. Tanpa keputusan admin, akun
+// diotorisasi kecuali mode auth required aktif dan akun adalah holder baru.
+fn is_authorized(env: &Env, addr: &Address) -> bool {
+    let key = DataKey::Authorized(addr.clone());
+    match env.storage().persistent().get::<_, bool>(&key) {
+        Some(authorized) => {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+            authorized
+        }
+        None => !env.storage().instance().get(&AUTH_REQ).unwrap_or(false) || is_holder(env, addr),
+    }
 }
 
+// Helper: panic jika akun dibekukan / belum diotorisasi
+fn check_authorized(env: &Env, addr: &Address) {
+    if !is_authorized(env, addr) {
+        panic_with_error!(env, TokenError::NotAuthorized);
+    }
+}
+
+// Helper untuk mengurangi saldo (panic jika tidak cukup / akun dibekukan)
+fn spend_balance(env: &Env, addr: &Address, amount: i128) {
+    check_authorized(env, addr);
+    let balance = get_balance(env, addr);
+    if balance < amount {
+        panic_with_error!(env, TokenError::InsufficientBalance);
+    }
+    set_balance(env, addr, &(balance - amount));
+}
+
+// Helper untuk menambah saldo (panic jika akun dibekukan)
+fn receive_balance(env: &Env, addr: &Address, amount: i128) {
+    check_authorized(env, addr);
+    let balance = get_balance(env, addr);
+    set_balance(env, addr, &(balance + amount));
+}
+
+// Helper validasi amount transfer/burn
+fn check_positive_amount(env: &Env, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(env, TokenError::AmountNotPositive);
+    }
+}
+
+// Helper untuk membaca metadata token (panic jika belum diinisialisasi)
+fn read_metadata<V: TryFromVal<Env, Val>>(env: &Env, key: &Symbol) -> V {
+    env.storage()
+        .instance()
+        .get(key)
+        .unwrap_or_else(|| panic_with_error!(env, TokenError::NotInitialized))
+}
+
+// Helper untuk membaca allowance. Allowance yang sudah kadaluarsa dianggap 0.
+fn read_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = DataKey::Allowance(AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    match env.storage().temporary().get::<_, AllowanceValue>(&key) {
+        Some(allowance) if allowance.expiration_ledger < env.ledger().sequence() => AllowanceValue {
+            amount: 0,
+            expiration_ledger: allowance.expiration_ledger,
+        },
+        Some(allowance) => allowance,
+        None => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+// Helper untuk menulis allowance ke temporary storage sampai expiration_ledger
+fn write_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        panic_with_error!(env, TokenError::InvalidExpirationLedger);
+    }
+
+    let key = DataKey::Allowance(AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    env.storage().temporary().set(
+        &key,
+        &AllowanceValue {
+            amount,
+            expiration_ledger,
+        },
+    );
+
+    if amount > 0 {
+        let live_for = expiration_ledger - env.ledger().sequence();
+        env.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
+
+// Helper untuk memakai allowance (panic jika tidak cukup)
+fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = read_allowance(env, from, spender);
+    if allowance.amount < amount {
+        panic_with_error!(env, TokenError::InsufficientAllowance);
+    }
+    write_allowance(
+        env,
+        from,
+        spender,
+        allowance.amount - amount,
+        allowance.expiration_ledger,
+    );
+}
+
+// Helper untuk membakar token dan mengurangi total supply
+fn burn_balance(env: &Env, from: &Address, amount: i128) {
+    spend_balance(env, from, amount);
+    let total: i128 = read_metadata(env, &TOTAL);
+    env.storage().instance().set(&TOTAL, &(total - amount));
+    Burn {
+        from: from.clone(),
+        amount,
+    }
+    .publish(env);
+}
+
+// Helper untuk mencetak token baru dan menambah total supply
+fn mint_balance(env: &Env, to: &Address, amount: i128) -> Result<(), TokenError> {
+    let total: i128 = env.storage().instance().get(&TOTAL).unwrap_or(0);
+    let new_total = total + amount;
+    if let Some(max_supply) = env.storage().instance().get::<_, i128>(&MAX_SUP) {
+        if new_total > max_supply {
+            return Err(TokenError::MaxSupplyExceeded);
+        }
+    }
+
+    receive_balance(env, to, amount);
+    env.storage().instance().set(&TOTAL, &new_total);
+    Mint {
+        to: to.clone(),
+        amount,
+    }
+    .publish(env);
+    Ok(())
+}
+
+// Helper untuk membaca admin & memastikan admin menandatangani transaksi
+fn require_admin(env: &Env) -> Result<Address, TokenError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&ADMIN)
+        .ok_or(TokenError::NotInitialized)?;
+    admin.require_auth();
+    Ok(admin)
+}
 
 #[contractimpl]
 impl TokenContract {
 
-    // Initialize token dengan nama, symbol, dan supply
-    pub fn initialize(
+    // Constructor: dijalankan atomik saat deploy, jadi tidak ada celah
+    // antara deploy & inisialisasi yang bisa di-front-run.
+    // Set nama, symbol, decimals (0..=18) & admin, lalu cetak supply awal ke admin.
+    pub fn __constructor(
         env: Env,
         admin: Address,
         name: String,
         symbol: String,
+        decimals: u32,
         total_supply: i128,
-    ) {
-        // Verify admin authorization
-        admin.require_auth();
-
+    ) -> Result<(), TokenError> {
         // Validasi input
         if total_supply <= 0 {
-            panic!("Total supply harus lebih dari 0");
+            return Err(TokenError::InvalidSupply);
         }
-        
-        // Pastikan belum diinisialisasi
-        if env.storage().instance().has(&NAME) {
-            panic!("Sudah diinisialisasi");
+        if decimals > MAX_DECIMALS {
+            return Err(TokenError::InvalidDecimals);
         }
 
         // Simpan token info
         env.storage().instance().set(&NAME, &name);
         env.storage().instance().set(&SYMBOL, &symbol);
-        env.storage().instance().set(&TOTAL, &total_supply);
-        env.storage().instance().set(&DECIMAL, &7u32); // Standar token butuh ini
+        env.storage().instance().set(&DECIMAL, &decimals);
+        env.storage().instance().set(&ADMIN, &admin);
+        env.storage().instance().set(&VERSION, &SCHEMA_VERSION);
 
-        // Set balance admin = total supply
-        let mut balances: Map<Address, i128> = Map::new(&env);
-        balances.set(admin.clone(), total_supply);
-        env.storage().instance().set(&BALANCE, &balances);
+        // Supply awal dicetak ke admin
+        mint_balance(&env, &admin, total_supply)
     }
 
-    // --- Implementasi Standar Token ---
+    // --- Admin ---
 
-    // Get nama token
-    pub fn name(env: Env) -> String {
-        env.storage().instance().get(&NAME).unwrap()
-    }
+    // Admin mencetak token baru ke `to` (dibatasi max supply jika ada)
+    pub fn mint(env: Env, to: Address, amount: i128) -> Result<(), TokenError> {
+        extend_instance(&env);
+        require_admin(&env)?;
 
-    // Get symbol token
-    pub fn symbol(env: Env) -> String {
-        env.storage().instance().get(&SYMBOL).unwrap()
+        if amount <= 0 {
+            return Err(TokenError::AmountNotPositive);
+        }
+        mint_balance(&env, &to, amount)
     }
-    
-    // Get decimals
-    pub fn decimals(env: Env) -> u32 {
-        env.storage().instance().get(&DECIMAL).unwrap()
+
+    // Admin memasang / menurunkan batas supply. Batas tidak bisa dinaikkan
+    // dan tidak boleh di bawah total supply saat ini.
+    pub fn set_max_supply(env: Env, max_supply: i128) -> Result<(), TokenError> {
+        extend_instance(&env);
+        require_admin(&env)?;
+
+        let total: i128 = read_metadata(&env, &TOTAL);
+        let current: Option<i128> = env.storage().instance().get(&MAX_SUP);
+        if max_supply < total || current.is_some_and(|cap| max_supply > cap) {
+            return Err(TokenError::InvalidMaxSupply);
+        }
+        env.storage().instance().set(&MAX_SUP, &max_supply);
+        Ok(())
     }
 
-    // Get total supply
-    pub fn total_supply(env: Env) -> i128 {
-        env.storage().instance().get(&TOTAL).unwrap()
+    // Admin membekukan (`false`) atau mengotorisasi (`true`) akun.
+    // Akun yang dibekukan tidak bisa mengirim maupun menerima token.
+    pub fn set_authorized(env: Env, id: Address, authorize: bool) -> Result<(), TokenError> {
+        extend_instance(&env);
+        require_admin(&env)?;
+
+        let key = DataKey::Authorized(id.clone());
+        env.storage().persistent().set(&key, &authorize);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+
+        SetAuthorized { id, authorize }.publish(&env);
+        Ok(())
     }
 
-    // Get balance untuk alamat spesifik
-    pub fn balance(env: Env, id: Address) -> i128 {
-        get_balance(&env, &id)
+    // Admin menyalakan / mematikan mode auth required. Hanya berlaku untuk
+    // holder baru yang belum pernah diset lewat `set_authorized`; holder
+    // lama (termasuk admin & kontrak escrow) tetap bisa bertransaksi.
+    pub fn set_auth_required(env: Env, required: bool) -> Result<(), TokenError> {
+        extend_instance(&env);
+        require_admin(&env)?;
+        env.storage().instance().set(&AUTH_REQ, &required);
+        Ok(())
     }
 
-    // Transfer token
-    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
-        // Verify authorization
-        from.require_auth();
+    // Admin menarik paksa token dari `from` (juga dari akun yang dibekukan).
+    // Token yang ditarik dibakar sehingga total supply berkurang.
+    pub fn clawback(env: Env, from: Address, amount: i128) -> Result<(), TokenError> {
+        extend_instance(&env);
+        require_admin(&env)?;
 
-        // Validasi amount
         if amount <= 0 {
-            panic!("Amount harus lebih dari 0");
+            return Err(TokenError::AmountNotPositive);
+        }
+        let balance = get_balance(&env, &from);
+        if balance < amount {
+            return Err(TokenError::InsufficientBalance);
         }
+        set_balance(&env, &from, &(balance - amount));
+        let total: i128 = read_metadata(&env, &TOTAL);
+        env.storage().instance().set(&TOTAL, &(total - amount));
+
+        Clawback { from, amount }.publish(&env);
+        Ok(())
+    }
+
+    // Cek apakah akun boleh mengirim / menerima token
+    pub fn authorized(env: Env, id: Address) -> bool {
+        is_authorized(&env, &id)
+    }
+
+    // Cek apakah mode auth required aktif
+    pub fn auth_required(env: Env) -> bool {
+        env.storage().instance().get(&AUTH_REQ).unwrap_or(false)
+    }
+
+    // Get max supply (None = tanpa batas)
+    pub fn max_supply(env: Env) -> Option<i128> {
+        env.storage().instance().get(&MAX_SUP)
+    }
+
+    // Langkah 1 ganti admin: admin menunjuk calon admin baru.
+    // Admin lama tetap berlaku sampai calon memanggil `accept_admin`.
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), TokenError> {
+        extend_instance(&env);
+        require_admin(&env)?;
+        env.storage().instance().set(&PEND_ADM, &new_admin);
+        Ok(())
+    }
 
-        // Get current balances
-        let from_balance = get_balance(&env, &from);
-        let to_balance = get_balance(&env, &to);
+    // Langkah 2 ganti admin: calon admin menerima perannya
+    pub fn accept_admin(env: Env) -> Result<(), TokenError> {
+        extend_instance(&env);
+        let new_admin: Address = env
+            .storage()
+            .instance()
+            .get(&PEND_ADM)
+            .ok_or(TokenError::NoPendingAdmin)?;
+        new_admin.require_auth();
+
+        let admin: Address = read_metadata(&env, &ADMIN);
+        env.storage().instance().set(&ADMIN, &new_admin);
+        env.storage().instance().remove(&PEND_ADM);
+
+        SetAdmin { admin, new_admin }.publish(&env);
+        Ok(())
+    }
 
-        // Check sufficient balance
-        if from_balance < amount {
-            panic!("Balance tidak cukup");
+    // Get admin token
+    pub fn admin(env: Env) -> Result<Address, TokenError> {
+        env.storage()
+            .instance()
+            .get(&ADMIN)
+            .ok_or(TokenError::NotInitialized)
+    }
+
+    // Get calon admin (None jika tidak ada)
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&PEND_ADM)
+    }
+
+    // Memindahkan maksimal `limit` saldo dari map lama (instance storage,
+    // schema 1) ke entry persistent per alamat. Boleh dipanggil siapa saja
+    // karena nilai saldo tidak berubah; panggil berulang sampai hasilnya 0.
+    // Mengembalikan jumlah alamat yang saldonya belum dipindah.
+    pub fn migrate(env: Env, limit: u32) -> u32 {
+        extend_instance(&env);
+        let Some(mut balances) = read_legacy_balances(&env) else {
+            env.storage().instance().set(&VERSION, &SCHEMA_VERSION);
+            return 0;
+        };
+
+        for holder in balances.keys().iter().take(limit as usize) {
+            // Entry baru (kalau ada) selalu lebih baru dari map lama
+            if !env.storage().persistent().has(&DataKey::Balance(holder.clone())) {
+                let balance = balances.get(holder.clone()).unwrap();
+                write_balance_entry(&env, &holder, &balance);
+            }
+            balances.remove(holder);
         }
 
-        // Update balances
-        set_balance(&env, &from, &(from_balance - amount));
-        set_balance(&env, &to, &(to_balance + amount));
+        if balances.is_empty() {
+            env.storage().instance().remove(&BALANCE);
+            env.storage().instance().set(&VERSION, &SCHEMA_VERSION);
+        } else {
+            env.storage().instance().set(&BALANCE, &balances);
+        }
+        balances.len()
     }
-    
+
+    // Admin mengganti kode kontrak dengan WASM yang sudah di-upload.
+    // Admin ditetapkan oleh constructor saat deploy, jadi tidak bisa
+    // direbut. Storage tetap; jalankan `migrate` jika layout berubah.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), TokenError> {
+        extend_instance(&env);
+        require_admin(&env)?;
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    // Get versi layout storage. Token lama tanpa versi tersimpan = versi 1.
+    pub fn schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&VERSION).unwrap_or(1)
+    }
+
+    // Get jumlah alamat yang saldonya masih di map lama
+    pub fn legacy_holder_count(env: Env) -> u32 {
+        read_legacy_balances(&env).map_or(0, |balances| balances.len())
+    }
+
+    // --- Fungsi Tambahan (di luar SEP-41) ---
+
+    // Get total supply
+    pub fn total_supply(env: Env) -> i128 {
+        read_metadata(&env, &TOTAL)
+    }
+
     // --- Fungsi Getter Lama (Untuk kompatibilitas) ---
     
     pub fn get_name(env: Env) -> String {
@@ -131,9 +647,126 @@ impl TokenContract {
         Self::total_supply(env)
     }
     
+    // Sudah tidak dipakai, gunakan balance(Address)
     // 'env' diubah menjadi '_env' untuk menghilangkan warning
-    pub fn get_balance(_env: Env) -> i128 {
-        panic!("Fungsi get_balance() sudah tidak dipakai, gunakan balance(Address)");
+    pub fn get_balance(_env: Env) -> Result<i128, TokenError> {
+        Err(TokenError::Deprecated)
+    }
+}
+
+// --- Implementasi Standar Token (SEP-41) ---
+// Memakai trait dari SDK supaya compiler mengecek kesesuaian interface
+#[contractimpl]
+impl TokenInterface for TokenContract {
+    // Get sisa allowance `spender` atas saldo `from`
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        read_allowance(&env, &from, &spender).amount
+    }
+
+    // Set allowance `spender` atas saldo `from` sampai `expiration_ledger`
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        extend_instance(&env);
+        from.require_auth();
+
+        if amount < 0 {
+            panic_with_error!(&env, TokenError::NegativeAmount);
+        }
+
+        write_allowance(&env, &from, &spender, amount, expiration_ledger);
+
+        Approve {
+            from,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(&env);
+    }
+
+    // Get balance untuk alamat spesifik
+    fn balance(env: Env, id: Address) -> i128 {
+        get_balance(&env, &id)
+    }
+
+    // Transfer token
+    fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128) {
+        extend_instance(&env);
+        // Verify authorization
+        from.require_auth();
+
+        // Validasi amount
+        check_positive_amount(&env, amount);
+
+        // Update balances
+        let to_address = to.address();
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to_address, amount);
+
+        match to.id() {
+            Some(to_muxed_id) => TransferMuxed {
+                from,
+                to: to_address,
+                to_muxed_id,
+                amount,
+            }
+            .publish(&env),
+            None => Transfer {
+                from,
+                to: to_address,
+                amount,
+            }
+            .publish(&env),
+        }
+    }
+
+    // Transfer token dari `from` oleh `spender` memakai allowance
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        extend_instance(&env);
+        spender.require_auth();
+
+        check_positive_amount(&env, amount);
+
+        spend_allowance(&env, &from, &spender, amount);
+        spend_balance(&env, &from, amount);
+        receive_balance(&env, &to, amount);
+
+        Transfer { from, to, amount }.publish(&env);
+    }
+
+    // Burn token milik `from`
+    fn burn(env: Env, from: Address, amount: i128) {
+        extend_instance(&env);
+        from.require_auth();
+
+        check_positive_amount(&env, amount);
+
+        burn_balance(&env, &from, amount);
+    }
+
+    // Burn token milik `from` oleh `spender` memakai allowance
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        extend_instance(&env);
+        spender.require_auth();
+
+        check_positive_amount(&env, amount);
+
+        spend_allowance(&env, &from, &spender, amount);
+        burn_balance(&env, &from, amount);
+    }
+
+    // Get decimals
+    fn decimals(env: Env) -> u32 {
+        read_metadata(&env, &DECIMAL)
+    }
+
+    // Get nama token
+    fn name(env: Env) -> String {
+        read_metadata(&env, &NAME)
+    }
+
+    // Get symbol token
+    fn symbol(env: Env) -> String {
+        read_metadata(&env, &SYMBOL)
     }
 }
 
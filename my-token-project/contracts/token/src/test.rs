@@ -1,33 +1,56 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Events, Ledger,
+    },
+    vec, Address, Env, IntoVal, Map, String,
+};
+
+// Helper: register token lewat constructor, admin memegang seluruh supply
+fn setup_token<'a>(env: &Env, supply: i128) -> (TokenContractClient<'a>, Address) {
+    let admin = Address::generate(env);
+    let contract_id = env.register(
+        TokenContract,
+        (
+            &admin,
+            String::from_str(env, "Test Token"),
+            String::from_str(env, "TST"),
+            7u32,
+            supply,
+        ),
+    );
+    let client = TokenContractClient::new(env, &contract_id);
+
+    env.mock_all_auths();
+    (client, admin)
+}
 
 #[test]
 fn test_initialize_token() {
     // Setup environment
     let env = Env::default();
-    let contract_id = env.register(TokenContract, ());
-    let client = TokenContractClient::new(&env, &contract_id);
 
     // Create admin address
     let admin = Address::generate(&env);
 
-    // Mock authorization
-    env.mock_all_auths();
-
-    // Initialize token
+    // Deploy token (constructor langsung menginisialisasi)
     let name = String::from_str(&env, "Indonesian Rupiah");
     let symbol = String::from_str(&env, "IDR");
     let supply = 1_000_000_000i128; // 1 miliar
 
-    client.initialize(&admin, &name, &symbol, &supply);
+    let contract_id = env.register(TokenContract, (&admin, &name, &symbol, 7u32, supply));
+    let client = TokenContractClient::new(&env, &contract_id);
 
     // Verify token info (menggunakan nama fungsi standar)
     assert_eq!(client.name(), name);
     assert_eq!(client.symbol(), symbol);
     assert_eq!(client.total_supply(), supply);
     assert_eq!(client.decimals(), 7);
+    assert_eq!(client.admin(), admin);
     // Cek saldo admin
     assert_eq!(client.balance(&admin), supply);
     // Cek saldo user lain (harus 0)
@@ -38,17 +61,14 @@ fn test_initialize_token() {
 #[test]
 fn test_get_token_info() {
     let env = Env::default();
-    let contract_id = env.register(TokenContract, ());
-    let client = TokenContractClient::new(&env, &contract_id);
-
     let admin = Address::generate(&env);
-    env.mock_all_auths();
 
     let name = String::from_str(&env, "Workshop Token");
     let symbol = String::from_str(&env, "WST");
     let supply = 5_000_000i128;
 
-    client.initialize(&admin, &name, &symbol, &supply);
+    let contract_id = env.register(TokenContract, (&admin, &name, &symbol, 7u32, supply));
+    let client = TokenContractClient::new(&env, &contract_id);
 
     // Test getter functions (gunakan nama standar)
     assert_eq!(client.name(), name);
@@ -57,39 +77,60 @@ fn test_get_token_info() {
 }
 
 #[test]
-#[should_panic(expected = "Total supply harus lebih dari 0")]
+#[should_panic(expected = "Error(Contract, #3)")] // InvalidSupply
 fn test_initialize_invalid_supply() {
     let env = Env::default();
-    let contract_id = env.register(TokenContract, ());
-    let client = TokenContractClient::new(&env, &contract_id);
-
     let admin = Address::generate(&env);
-    env.mock_all_auths();
 
     let name = String::from_str(&env, "Bad Token");
     let symbol = String::from_str(&env, "BAD");
     let supply = 0i128; // Invalid!
 
-    // Should panic
-    client.initialize(&admin, &name, &symbol, &supply);
+    env.register(TokenContract, (&admin, &name, &symbol, 7u32, supply));
 }
 
 #[test]
-fn test_transfer() {
+fn test_initialize_decimals() {
     let env = Env::default();
-    let contract_id = env.register(TokenContract, ());
-    let client = TokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    let name = String::from_str(&env, "Voucher");
+    let symbol = String::from_str(&env, "VCH");
+
+    // Batas bawah & atas diterima
+    for decimals in [0u32, 18] {
+        let contract_id =
+            env.register(TokenContract, (&admin, &name, &symbol, decimals, 1_000i128));
+        let client = TokenContractClient::new(&env, &contract_id);
+        assert_eq!(client.decimals(), decimals);
+    }
+}
 
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")] // InvalidDecimals
+fn test_initialize_decimals_too_large() {
+    let env = Env::default();
     let admin = Address::generate(&env);
-    let user = Address::generate(&env);
 
-    env.mock_all_auths();
+    let name = String::from_str(&env, "Voucher");
+    let symbol = String::from_str(&env, "VCH");
+    env.register(TokenContract, (&admin, &name, &symbol, 19u32, 1_000i128));
+}
 
-    let name = String::from_str(&env, "Test Token");
-    let symbol = String::from_str(&env, "TST");
-    let supply = 1_000_000i128;
+#[test]
+fn test_deprecated_get_balance() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env, 1_000_000);
+
+    assert_eq!(client.try_get_balance(), Err(Ok(TokenError::Deprecated)));
+}
 
-    client.initialize(&admin, &name, &symbol, &supply);
+#[test]
+fn test_transfer() {
+    let env = Env::default();
+    let supply = 1_000_000i128;
+    let (client, admin) = setup_token(&env, supply);
+    let user = Address::generate(&env);
 
     // Cek saldo awal
     assert_eq!(client.balance(&admin), supply);
@@ -102,4 +143,704 @@ fn test_transfer() {
     // Cek saldo akhir
     assert_eq!(client.balance(&admin), supply - transfer_amount);
     assert_eq!(client.balance(&user), transfer_amount);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_approve_and_allowance() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let spender = Address::generate(&env);
+
+    assert_eq!(client.allowance(&admin, &spender), 0);
+
+    let expiration = env.ledger().sequence() + 100;
+    client.approve(&admin, &spender, &5_000, &expiration);
+    assert_eq!(client.allowance(&admin, &spender), 5_000);
+
+    // Approve ulang menimpa nilai lama
+    client.approve(&admin, &spender, &2_000, &expiration);
+    assert_eq!(client.allowance(&admin, &spender), 2_000);
+}
+
+#[test]
+fn test_allowance_expires() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let spender = Address::generate(&env);
+
+    let expiration = env.ledger().sequence() + 10;
+    client.approve(&admin, &spender, &5_000, &expiration);
+
+    env.ledger().set_sequence_number(expiration);
+    assert_eq!(client.allowance(&admin, &spender), 5_000);
+
+    env.ledger().set_sequence_number(expiration + 1);
+    assert_eq!(client.allowance(&admin, &spender), 0);
+}
+
+#[test]
+fn test_approve_past_expiration() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let spender = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    assert_eq!(
+        client.try_approve(&admin, &spender, &5_000, &99),
+        Err(Ok(TokenError::InvalidExpirationLedger.into()))
+    );
+}
+
+#[test]
+fn test_approve_negative_amount() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let spender = Address::generate(&env);
+
+    assert_eq!(
+        client.try_approve(&admin, &spender, &-1, &(env.ledger().sequence() + 100)),
+        Err(Ok(TokenError::NegativeAmount.into()))
+    );
+}
+
+#[test]
+fn test_transfer_from() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.approve(&admin, &spender, &5_000, &(env.ledger().sequence() + 100));
+    client.transfer_from(&spender, &admin, &receiver, &3_000);
+
+    assert_eq!(client.balance(&admin), 1_000_000 - 3_000);
+    assert_eq!(client.balance(&receiver), 3_000);
+    assert_eq!(client.balance(&spender), 0);
+    assert_eq!(client.allowance(&admin, &spender), 2_000);
+}
+
+#[test]
+fn test_transfer_from_insufficient_allowance() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.approve(&admin, &spender, &1_000, &(env.ledger().sequence() + 100));
+    assert_eq!(
+        client.try_transfer_from(&spender, &admin, &receiver, &1_001),
+        Err(Ok(TokenError::InsufficientAllowance.into()))
+    );
+}
+
+#[test]
+fn test_transfer_insufficient_balance() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_transfer(&admin, &user, &1_001),
+        Err(Ok(TokenError::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_transfer_zero_amount() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_transfer(&admin, &user, &0),
+        Err(Ok(TokenError::AmountNotPositive.into()))
+    );
+}
+
+#[test]
+fn test_burn() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+
+    client.burn(&admin, &400_000);
+
+    assert_eq!(client.balance(&admin), 600_000);
+    assert_eq!(client.total_supply(), 600_000);
+}
+
+#[test]
+fn test_burn_from() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let spender = Address::generate(&env);
+
+    client.approve(&admin, &spender, &500, &(env.ledger().sequence() + 100));
+    client.burn_from(&spender, &admin, &200);
+
+    assert_eq!(client.balance(&admin), 1_000_000 - 200);
+    assert_eq!(client.total_supply(), 1_000_000 - 200);
+    assert_eq!(client.allowance(&admin, &spender), 300);
+}
+
+#[test]
+fn test_burn_insufficient_balance() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_burn(&user, &1),
+        Err(Ok(TokenError::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_balance_stored_per_address() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+
+    client.transfer(&admin, &user, &1_000);
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        assert_eq!(
+            storage.get::<_, i128>(&DataKey::Balance(user.clone())),
+            Some(1_000)
+        );
+        assert_eq!(
+            storage.get::<_, i128>(&DataKey::Balance(admin.clone())),
+            Some(1_000_000 - 1_000)
+        );
+        assert_eq!(storage.get_ttl(&DataKey::Balance(user.clone())), BALANCE_BUMP_AMOUNT);
+        // Map lama tidak dipakai lagi
+        assert!(!env.storage().instance().has(&BALANCE));
+    });
+}
+
+#[test]
+fn test_balance_ttl_extended_on_read() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+
+    // Maju sampai sisa TTL di bawah threshold
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 2 * DAY_IN_LEDGERS);
+    assert_eq!(client.balance(&admin), 1_000_000);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            env.storage()
+                .persistent()
+                .get_ttl(&DataKey::Balance(admin.clone())),
+            BALANCE_BUMP_AMOUNT
+        );
+    });
+}
+
+#[test]
+fn test_instance_ttl_extended_on_transfer() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+
+    client.transfer(&admin, &user, &1_000);
+
+    // Metadata & admin di instance storage ikut diperpanjang
+    env.as_contract(&client.address, || {
+        assert_eq!(env.storage().instance().get_ttl(), INSTANCE_BUMP_AMOUNT);
+    });
+}
+
+#[test]
+fn test_migrate_legacy_balances() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let holder1 = Address::generate(&env);
+    let holder2 = Address::generate(&env);
+    let holder3 = Address::generate(&env);
+
+    // Simulasikan layout lama: saldo di Map pada instance storage
+    env.as_contract(&client.address, || {
+        let mut balances: Map<Address, i128> = Map::new(&env);
+        balances.set(holder1.clone(), 100);
+        balances.set(holder2.clone(), 200);
+        balances.set(holder3.clone(), 300);
+        env.storage().instance().set(&BALANCE, &balances);
+        // Token lama belum menyimpan versi
+        env.storage().instance().remove(&VERSION);
+    });
+    assert_eq!(client.legacy_holder_count(), 3);
+    assert_eq!(client.schema_version(), 1);
+
+    // Saldo lama tetap terbaca sebelum migrasi
+    assert_eq!(client.balance(&holder1), 100);
+    assert_eq!(client.balance(&holder2), 200);
+
+    // Transfer dari holder lama memindahkan saldonya secara otomatis
+    client.transfer(&holder3, &admin, &50);
+    assert_eq!(client.balance(&holder3), 250);
+    assert_eq!(client.legacy_holder_count(), 2);
+
+    // Migrasi bertahap sisanya
+    assert_eq!(client.migrate(&1), 1);
+    assert_eq!(client.schema_version(), 1);
+    assert_eq!(client.migrate(&10), 0);
+    assert_eq!(client.legacy_holder_count(), 0);
+    assert_eq!(client.balance(&holder1), 100);
+    assert_eq!(client.balance(&holder2), 200);
+    assert_eq!(client.balance(&holder3), 250);
+
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&BALANCE));
+    });
+    assert_eq!(client.schema_version(), SCHEMA_VERSION);
+}
+
+// --- Event Tests (topics mengikuti SEP-41) ---
+
+#[test]
+fn test_initialize_emits_mint_event() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("mint"), admin.clone()).into_val(&env),
+                1_000_000i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_transfer_emits_event() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+
+    client.transfer(&admin, &user, &1_000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), admin.clone(), user.clone()).into_val(&env),
+                1_000i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_transfer_from_emits_event() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let spender = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.approve(&admin, &spender, &500, &(env.ledger().sequence() + 100));
+    client.transfer_from(&spender, &admin, &user, &200);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), admin.clone(), user.clone()).into_val(&env),
+                200i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_approve_emits_event() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let spender = Address::generate(&env);
+    let expiration = env.ledger().sequence() + 100;
+
+    client.approve(&admin, &spender, &500, &expiration);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("approve"), admin.clone(), spender.clone()).into_val(&env),
+                (500i128, expiration).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_burn_emits_event() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+
+    client.burn(&admin, &300);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("burn"), admin.clone()).into_val(&env),
+                300i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+// --- Admin & Supply Tests ---
+
+#[test]
+fn test_mint_increases_supply() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+
+    client.mint(&user, &500);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("mint"), user.clone()).into_val(&env),
+                500i128.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.balance(&user), 500);
+    assert_eq!(client.total_supply(), 1_000_500);
+
+    // Total supply tetap akurat setelah burn
+    client.burn(&admin, &200_000);
+    assert_eq!(client.total_supply(), 800_500);
+    assert_eq!(
+        client.try_mint(&user, &0),
+        Err(Ok(TokenError::AmountNotPositive))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_mint_requires_admin_auth() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+
+    // Admin tidak menandatangani
+    env.set_auths(&[]);
+    client.mint(&user, &500);
+}
+
+#[test]
+fn test_max_supply_cap() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000);
+    assert_eq!(client.max_supply(), None);
+
+    // Batas tidak boleh di bawah total supply
+    assert_eq!(
+        client.try_set_max_supply(&999),
+        Err(Ok(TokenError::InvalidMaxSupply))
+    );
+    client.set_max_supply(&1_500);
+    assert_eq!(client.max_supply(), Some(1_500));
+    // Batas tidak bisa dinaikkan
+    assert_eq!(
+        client.try_set_max_supply(&1_501),
+        Err(Ok(TokenError::InvalidMaxSupply))
+    );
+
+    client.mint(&admin, &500);
+    assert_eq!(
+        client.try_mint(&admin, &1),
+        Err(Ok(TokenError::MaxSupplyExceeded))
+    );
+
+    // Burn membuka ruang untuk mint lagi
+    client.burn(&admin, &100);
+    client.mint(&admin, &100);
+    assert_eq!(client.total_supply(), 1_500);
+}
+
+#[test]
+fn test_two_step_set_admin() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000);
+    let new_admin = Address::generate(&env);
+    assert_eq!(client.admin(), admin);
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(TokenError::NoPendingAdmin))
+    );
+
+    client.set_admin(&new_admin);
+    // Admin lama tetap berlaku sampai diterima
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "set_admin"), admin.clone()).into_val(&env),
+                new_admin.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.admin(), new_admin);
+    assert_eq!(client.pending_admin(), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_accept_admin_requires_new_admin_auth() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env, 1_000);
+    let new_admin = Address::generate(&env);
+    client.set_admin(&new_admin);
+
+    // Calon admin tidak menandatangani
+    env.set_auths(&[]);
+    client.accept_admin();
+}
+
+// --- Authorization & Clawback Tests ---
+
+#[test]
+fn test_frozen_account_cannot_transfer() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.transfer(&admin, &user, &1_000);
+
+    client.set_authorized(&user, &false);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "set_authorized"), user.clone()).into_val(&env),
+                false.into_val(&env),
+            ),
+        ]
+    );
+    assert!(!client.authorized(&user));
+
+    // Tidak bisa mengirim maupun menerima
+    assert_eq!(
+        client.try_transfer(&user, &other, &100),
+        Err(Ok(TokenError::NotAuthorized.into()))
+    );
+    assert_eq!(
+        client.try_transfer(&admin, &user, &100),
+        Err(Ok(TokenError::NotAuthorized.into()))
+    );
+    client.approve(&user, &other, &500, &(env.ledger().sequence() + 100));
+    assert_eq!(
+        client.try_transfer_from(&other, &user, &other, &100),
+        Err(Ok(TokenError::NotAuthorized.into()))
+    );
+
+    client.set_authorized(&user, &true);
+    client.transfer(&user, &other, &100);
+    assert_eq!(client.balance(&other), 100);
+}
+
+#[test]
+fn test_auth_required_mode() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+
+    client.set_auth_required(&true);
+    assert!(client.auth_required());
+    assert!(!client.authorized(&user));
+
+    assert_eq!(
+        client.try_transfer(&admin, &user, &100),
+        Err(Ok(TokenError::NotAuthorized.into()))
+    );
+    assert_eq!(
+        client.try_mint(&user, &100),
+        Err(Ok(TokenError::NotAuthorized))
+    );
+
+    client.set_authorized(&user, &true);
+    client.transfer(&admin, &user, &100);
+    assert_eq!(client.balance(&user), 100);
+}
+
+#[test]
+fn test_auth_required_keeps_existing_holders() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let holder = Address::generate(&env);
+    let emptied = Address::generate(&env);
+    let newcomer = Address::generate(&env);
+    client.transfer(&admin, &holder, &1_000);
+    client.transfer(&admin, &emptied, &100);
+    client.transfer(&emptied, &admin, &100);
+
+    client.set_auth_required(&true);
+
+    // Holder lama (juga yang saldonya sudah habis) tetap bisa bertransaksi
+    assert!(client.authorized(&holder));
+    assert!(client.authorized(&emptied));
+    client.transfer(&holder, &admin, &300);
+    client.transfer(&admin, &emptied, &50);
+    assert_eq!(client.balance(&holder), 700);
+    assert_eq!(client.balance(&emptied), 50);
+
+    // Holder baru tetap harus diotorisasi
+    assert!(!client.authorized(&newcomer));
+    assert_eq!(
+        client.try_transfer(&holder, &newcomer, &100),
+        Err(Ok(TokenError::NotAuthorized.into()))
+    );
+
+    // Keputusan admin tetap menang
+    client.set_authorized(&holder, &false);
+    assert_eq!(
+        client.try_transfer(&holder, &admin, &100),
+        Err(Ok(TokenError::NotAuthorized.into()))
+    );
+}
+
+#[test]
+fn test_clawback_reduces_supply() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+    client.transfer(&admin, &user, &1_000);
+
+    // Clawback tetap bisa dari akun yang dibekukan
+    client.set_authorized(&user, &false);
+    client.clawback(&user, &400);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("clawback"), user.clone()).into_val(&env),
+                400i128.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.balance(&user), 600);
+    assert_eq!(client.total_supply(), 1_000_000 - 400);
+
+    assert_eq!(
+        client.try_clawback(&user, &601),
+        Err(Ok(TokenError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_clawback(&user, &0),
+        Err(Ok(TokenError::AmountNotPositive))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_set_authorized_requires_admin_auth() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env, 1_000_000);
+    let user = Address::generate(&env);
+
+    env.set_auths(&[]);
+    client.set_authorized(&user, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_clawback_requires_admin_auth() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+
+    env.set_auths(&[]);
+    client.clawback(&admin, &1);
+}
+
+// --- Upgrade Tests ---
+
+// Versi baru kontrak untuk test upgrade: build WASM token dari repo ini
+// (lihat test_wasms/README.md)
+mod upgraded {
+    soroban_sdk::contractimport!(file = "../../test_wasms/token.wasm");
+}
+
+#[test]
+fn test_upgrade_and_migrate_preserves_balances() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env, 1_000_000);
+    let holder1 = Address::generate(&env);
+    let holder2 = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.approve(&admin, &spender, &5_000, &1_000);
+
+    // Simulasikan deployment lama: saldo holder di map, versi belum disimpan
+    env.as_contract(&client.address, || {
+        let mut balances: Map<Address, i128> = Map::new(&env);
+        balances.set(holder1.clone(), 100);
+        balances.set(holder2.clone(), 200);
+        env.storage().instance().set(&BALANCE, &balances);
+        env.storage().instance().remove(&VERSION);
+    });
+
+    let wasm_hash = env.deployer().upload_contract_wasm(upgraded::WASM);
+    client.upgrade(&wasm_hash);
+
+    // Kode baru yang menjawab, lalu migrasi layout lama
+    let new_client = upgraded::Client::new(&env, &client.address);
+    assert_eq!(new_client.schema_version(), 1);
+    assert_eq!(new_client.legacy_holder_count(), 2);
+    assert_eq!(new_client.migrate(&10), 0);
+    assert_eq!(new_client.schema_version(), SCHEMA_VERSION);
+    assert_eq!(new_client.legacy_holder_count(), 0);
+
+    // Metadata, saldo, allowance & admin tetap utuh
+    assert_eq!(new_client.symbol(), String::from_str(&env, "TST"));
+    assert_eq!(new_client.total_supply(), 1_000_000);
+    assert_eq!(new_client.balance(&admin), 1_000_000);
+    assert_eq!(new_client.balance(&holder1), 100);
+    assert_eq!(new_client.balance(&holder2), 200);
+    assert_eq!(new_client.allowance(&admin, &spender), 5_000);
+    assert_eq!(new_client.admin(), admin);
+
+    new_client.transfer(&holder1, &holder2, &40);
+    assert_eq!(new_client.balance(&holder1), 60);
+    assert_eq!(new_client.balance(&holder2), 240);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_upgrade_requires_admin_auth() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env, 1_000_000);
+    let wasm_hash = env.deployer().upload_contract_wasm(upgraded::WASM);
+
+    env.set_auths(&[]);
+    client.upgrade(&wasm_hash);
+}